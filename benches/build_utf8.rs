@@ -0,0 +1,44 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::{MutableUtf8Array, Utf8Array};
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let values = (0..size)
+            .map(|x| if x % 10 == 0 { None } else { Some("aaa") })
+            .collect::<Vec<_>>();
+
+        c.bench_function(&format!("push 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let mut array = MutableUtf8Array::<i32>::new();
+                values.iter().for_each(|x| array.push(*x));
+                let array: Utf8Array<i32> = array.into();
+                assert_eq!(array.len(), size);
+            })
+        });
+
+        c.bench_function(&format!("reserve + push 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let mut array = MutableUtf8Array::<i32>::new();
+                array.reserve(size, size * 3);
+                values.iter().for_each(|x| array.push(*x));
+                let array: Utf8Array<i32> = array.into();
+                assert_eq!(array.len(), size);
+            })
+        });
+
+        c.bench_function(&format!("extend_trusted_len 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let mut array = MutableUtf8Array::<i32>::new();
+                array.reserve(size, size * 3);
+                array.extend_trusted_len(values.iter().copied());
+                let array: Utf8Array<i32> = array.into();
+                assert_eq!(array.len(), size);
+            })
+        });
+    })
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -1,6 +1,18 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use arrow2::compute::comparison::{eq, eq_scalar};
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::{Bitmap, MutableBitmap};
+use arrow2::compute::boolean::and;
+use arrow2::compute::comparison::{
+    eq, eq_and_validity, eq_scalar, filter_gt_scalar, gt_scalar, lt, lt_scalar, neq_scalar,
+    primitive, utf8,
+};
+use arrow2::compute::filter::filter;
+use arrow2::compute::length::length;
+use arrow2::compute::take::take;
+use arrow2::compute::utf8::utf8_apply;
 use arrow2::scalar::*;
 use arrow2::util::bench_util::*;
 
@@ -18,6 +30,44 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| eq_scalar(&arr_a, &PrimitiveScalar::<f32>::from(Some(0.5))))
         });
 
+        c.bench_function(&format!("f32 between scalar 2^{}", log2_size), |b| {
+            b.iter(|| primitive::between_scalar(&arr_a, 0.25, 0.75))
+        });
+        c.bench_function(
+            &format!("f32 gt_eq + lt_eq + and scalar 2^{}", log2_size),
+            |b| {
+                b.iter(|| {
+                    let low = primitive::gt_eq_scalar(&arr_a, 0.25);
+                    let high = primitive::lt_eq_scalar(&arr_a, 0.75);
+                    and(&low, &high)
+                })
+            },
+        );
+
+        // validities sliced at an unaligned offset vs unsliced
+        let arr_a = create_primitive_array_with_seed::<i32>(size, 0.1, 42);
+        let arr_b = create_primitive_array_with_seed::<i32>(size, 0.1, 43);
+        c.bench_function(&format!("i32 eq_and_validity 2^{}", log2_size), |b| {
+            b.iter(|| eq_and_validity(&arr_a, &arr_b))
+        });
+        let arr_a = create_primitive_array_with_seed::<i32>(size + 3, 0.1, 42).slice(3, size);
+        let arr_b = create_primitive_array_with_seed::<i32>(size + 3, 0.1, 43).slice(3, size);
+        c.bench_function(
+            &format!("i32 eq_and_validity sliced 2^{}", log2_size),
+            |b| b.iter(|| eq_and_validity(&arr_a, &arr_b)),
+        );
+
+        // nullable-typed inputs whose validities have no nulls
+        let all_valid: Bitmap = MutableBitmap::from_len_set(size).into();
+        let arr_a = create_primitive_array_with_seed::<i32>(size, 0.0, 42)
+            .with_validity(Some(all_valid.clone()));
+        let arr_b =
+            create_primitive_array_with_seed::<i32>(size, 0.0, 43).with_validity(Some(all_valid));
+        c.bench_function(
+            &format!("i32 eq_and_validity all valid 2^{}", log2_size),
+            |b| b.iter(|| eq_and_validity(&arr_a, &arr_b)),
+        );
+
         let arr_a = create_boolean_array(size, 0.0, 0.1);
         let arr_b = create_boolean_array(size, 0.0, 0.2);
 
@@ -37,8 +87,103 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("utf8 2^{}", log2_size), |b| {
             b.iter(|| eq_scalar(&arr_a, &Utf8Scalar::<i32>::from(Some("abc"))))
         });
+
+        c.bench_function(&format!("utf8 eq_ignore_case 2^{}", log2_size), |b| {
+            b.iter(|| utf8::eq_ignore_case(&arr_a, &arr_b))
+        });
+        // the naive approach: lowercase both arrays, then compare them
+        c.bench_function(&format!("utf8 lowercase + eq 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let lhs = utf8_apply(str::to_ascii_lowercase, &arr_a);
+                let rhs = utf8_apply(str::to_ascii_lowercase, &arr_b);
+                utf8::eq(&lhs, &rhs)
+            })
+        });
+
+        c.bench_function(&format!("utf8 len_gt_scalar 2^{}", log2_size), |b| {
+            b.iter(|| utf8::len_gt_scalar(&arr_a, 10))
+        });
+        // the two-step approach: compute the lengths, then compare them
+        c.bench_function(&format!("utf8 length + gt_scalar 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let lengths = length(&arr_a).unwrap();
+                gt_scalar(lengths.as_ref(), &PrimitiveScalar::<i32>::from(Some(10)))
+            })
+        });
+
+        // a dictionary whose values are all distinct (high cardinality)
+        let values = create_string_array::<i32>(size, 16, 0.0, 42);
+        let keys = Int32Array::from_vec((0..size).map(|i| (i * 7 % size) as i32).collect());
+        let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+        let scalar = new_scalar(&array, 1);
+        c.bench_function(&format!("dictionary eq scalar 2^{}", log2_size), |b| {
+            b.iter(|| eq_scalar(&array, scalar.as_ref()))
+        });
+        // comparing every value and taking the result by the keys
+        let value = new_scalar(array.values().as_ref(), 7);
+        c.bench_function(&format!("dictionary eq scalar take 2^{}", log2_size), |b| {
+            b.iter(|| {
+                take(
+                    &eq_scalar(array.values().as_ref(), value.as_ref()),
+                    array.keys(),
+                )
+            })
+        });
+
+        // the same dictionary with sorted values, compared by its keys when ordered
+        let values = (0..size).map(|i| format!("{:016}", i)).collect::<Vec<_>>();
+        let values = Utf8Array::<i32>::from_slice(values);
+        let unordered = DictionaryArray::<i32>::from_data(array.keys().clone(), Arc::new(values));
+        let mut ordered = unordered.clone();
+        ordered.set_ordered(true);
+        let scalar = new_scalar(&unordered, size / 2);
+        c.bench_function(&format!("dictionary lt scalar 2^{}", log2_size), |b| {
+            b.iter(|| lt_scalar(&unordered, scalar.as_ref()))
+        });
+        let scalar = new_scalar(&ordered, size / 2);
+        c.bench_function(
+            &format!("ordered dictionary lt scalar 2^{}", log2_size),
+            |b| b.iter(|| lt_scalar(&ordered, scalar.as_ref())),
+        );
     })
 }
 
-criterion_group!(benches, add_benchmark);
+fn add_large_benchmark(c: &mut Criterion) {
+    // a large comparison, dominated by writing (and allocating) its output
+    let size = 10_000_000;
+    let arr_a = create_primitive_array_with_seed::<i32>(size, 0.0, 42);
+    let arr_b = create_primitive_array_with_seed::<i32>(size, 0.0, 43);
+    c.bench_function("i32 lt 10M", |b| b.iter(|| lt(&arr_a, &arr_b)));
+    c.bench_function("i32 lt scalar 10M", |b| {
+        b.iter(|| lt_scalar(&arr_a, &PrimitiveScalar::<i32>::from(Some(0))))
+    });
+
+    // the identity (`true`) or a bitwise not (`false`) of the values
+    let arr_a = create_boolean_array(size, 0.1, 0.5);
+    c.bench_function("bool eq scalar true 10M", |b| {
+        b.iter(|| eq_scalar(&arr_a, &BooleanScalar::from(Some(true))))
+    });
+    c.bench_function("bool eq scalar false 10M", |b| {
+        b.iter(|| eq_scalar(&arr_a, &BooleanScalar::from(Some(false))))
+    });
+    c.bench_function("bool neq scalar false 10M", |b| {
+        b.iter(|| neq_scalar(&arr_a, &BooleanScalar::from(Some(false))))
+    });
+
+    // values uniformly distributed in `[0, 1)`, so that `> k` selects `1 - k` of them
+    let arr_a = create_primitive_array_with_seed::<f32>(size, 0.1, 42);
+    for (selectivity, k) in [("1%", 0.99f32), ("50%", 0.5)] {
+        let scalar = PrimitiveScalar::<f32>::from(Some(k));
+        c.bench_function(&format!("f32 filter_gt_scalar {} 10M", selectivity), |b| {
+            b.iter(|| filter_gt_scalar(&arr_a, &scalar).unwrap())
+        });
+        // the two-step approach: compare, then filter by the result
+        c.bench_function(
+            &format!("f32 gt_scalar + filter {} 10M", selectivity),
+            |b| b.iter(|| filter(&arr_a, &gt_scalar(&arr_a, &scalar)).unwrap()),
+        );
+    }
+}
+
+criterion_group!(benches, add_benchmark, add_large_benchmark);
 criterion_main!(benches);
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::util::bench_util::create_primitive_array;
+
+fn add_benchmark(c: &mut Criterion) {
+    (0..=6).step_by(2).for_each(|log2_fields| {
+        let num_fields = 2usize.pow(log2_fields);
+        let size = 1024;
+
+        let fields = (0..num_fields)
+            .map(|i| Field::new(format!("c{}", i), DataType::Int32, true))
+            .collect::<Vec<_>>();
+        let data_type = DataType::Struct(fields);
+        let values = (0..num_fields)
+            .map(|_| Arc::new(create_primitive_array::<i32>(size, 0.1)) as Arc<dyn Array>)
+            .collect::<Vec<_>>();
+        let validity = Some(Bitmap::from_iter((0..size).map(|i| i % 10 != 0)));
+
+        // the arguments are moved into the constructors, so cloning them is not measured
+        let arguments = || (data_type.clone(), values.clone(), validity.clone());
+
+        c.bench_function(&format!("struct try_new 2^{} fields", log2_fields), |b| {
+            b.iter_batched(
+                arguments,
+                |(data_type, values, validity)| {
+                    StructArray::try_new(data_type, values, validity).unwrap()
+                },
+                BatchSize::SmallInput,
+            )
+        });
+
+        c.bench_function(
+            &format!("struct new_unchecked 2^{} fields", log2_fields),
+            |b| {
+                b.iter_batched(
+                    arguments,
+                    |(data_type, values, validity)| unsafe {
+                        StructArray::new_unchecked(data_type, values, validity)
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    })
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
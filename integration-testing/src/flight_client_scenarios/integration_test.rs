@@ -277,8 +277,15 @@ async fn receive_batch_flight_data(
         .expect("Header to be present")
     {
         let mut reader = std::io::Cursor::new(&data.data_body);
-        read::read_dictionary(batch, fields, ipc_schema, dictionaries, &mut reader, 0)
-            .expect("Error reading dictionary");
+        read::read_dictionary(
+            batch,
+            fields,
+            ipc_schema,
+            dictionaries,
+            &mut reader,
+            0,
+        )
+        .expect("Error reading dictionary");
 
         data = resp.next().await?.ok()?;
         message = ipc::MessageRef::read_as_root(&data.data_header).expect("Error parsing message");
@@ -308,8 +308,14 @@ async fn dictionary_from_message(
 ) -> Result<(), Status> {
     let mut reader = std::io::Cursor::new(data_body);
 
-    let dictionary_batch_result =
-        ipc::read::read_dictionary(dict_batch, fields, ipc_schema, dictionaries, &mut reader, 0);
+    let dictionary_batch_result = ipc::read::read_dictionary(
+        dict_batch,
+        fields,
+        ipc_schema,
+        dictionaries,
+        &mut reader,
+        0,
+    );
     dictionary_batch_result
         .map_err(|e| Status::internal(format!("Could not convert to Dictionary: {:?}", e)))
 }
@@ -1,26 +1,140 @@
 use std::fmt::{Debug, Formatter, Result, Write};
 
-use super::super::fmt::write_vec;
+use super::super::fmt::{debug_max_len, write_vec};
 use super::super::Offset;
 use super::BinaryArray;
 
-pub fn write_value<O: Offset, W: Write>(array: &BinaryArray<O>, index: usize, f: &mut W) -> Result {
+/// How the values of a [`BinaryArray`] are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryFormat {
+    /// As an hexadecimal string, e.g. `0x48650a`
+    Hex,
+    /// As an escaped byte string where non-printable bytes are escaped, e.g. `b"He\n"`
+    Escaped,
+}
+
+impl Default for BinaryFormat {
+    fn default() -> Self {
+        Self::Hex
+    }
+}
+
+/// The number of bytes of a value above which it is summarized by its length and its first
+/// [`VALUE_PREFIX_LEN`] bytes, e.g. `<5000 bytes: 48656c6c6f...>`.
+const MAX_VALUE_LEN: usize = 64;
+
+/// The number of bytes written of a value longer than [`MAX_VALUE_LEN`].
+const VALUE_PREFIX_LEN: usize = 16;
+
+fn write_hex<W: Write>(bytes: &[u8], f: &mut W) -> Result {
+    bytes.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+}
+
+fn write_escaped<W: Write>(bytes: &[u8], f: &mut W) -> Result {
+    f.write_str("b\"")?;
+    bytes
+        .iter()
+        .flat_map(|byte| std::ascii::escape_default(*byte))
+        .try_for_each(|c| f.write_char(c as char))?;
+    f.write_char('"')
+}
+
+/// Writes the value at `index` of `array` to `f`, formatted according to `format`.
+///
+/// Values longer than 64 bytes are summarized as their length followed by their first 16
+/// bytes, e.g. `<5000 bytes: 48656c6c6f...>` (or `<5000 bytes: b"Hello"...>` when escaped).
+pub fn write_value_as<O: Offset, W: Write>(
+    array: &BinaryArray<O>,
+    index: usize,
+    format: BinaryFormat,
+    f: &mut W,
+) -> Result {
     let bytes = array.value(index);
-    let writer = |f: &mut W, index| write!(f, "{}", bytes[index]);
+    if bytes.len() > MAX_VALUE_LEN {
+        write!(f, "<{} bytes: ", bytes.len())?;
+        let prefix = &bytes[..VALUE_PREFIX_LEN];
+        match format {
+            BinaryFormat::Hex => write_hex(prefix, f)?,
+            BinaryFormat::Escaped => write_escaped(prefix, f)?,
+        }
+        return f.write_str("...>");
+    }
+    match format {
+        BinaryFormat::Hex => {
+            f.write_str("0x")?;
+            write_hex(bytes, f)
+        }
+        BinaryFormat::Escaped => write_escaped(bytes, f),
+    }
+}
 
-    write_vec(f, writer, None, bytes.len(), "None", false)
+pub fn write_value<O: Offset, W: Write>(array: &BinaryArray<O>, index: usize, f: &mut W) -> Result {
+    write_value_as(array, index, BinaryFormat::default(), f)
+}
+
+fn write_array<O: Offset>(
+    array: &BinaryArray<O>,
+    format: BinaryFormat,
+    f: &mut Formatter<'_>,
+) -> Result {
+    let writer = |f: &mut Formatter, index| write_value_as(array, index, format, f);
+
+    let head = if O::is_large() {
+        "LargeBinaryArray"
+    } else {
+        "BinaryArray"
+    };
+    write!(f, "{}", head)?;
+    let max_len = debug_max_len(f);
+    write_vec(
+        f,
+        writer,
+        array.validity(),
+        array.len(),
+        "None",
+        false,
+        max_len,
+    )
 }
 
 impl<O: Offset> Debug for BinaryArray<O> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        let writer = |f: &mut Formatter, index| write_value(self, index, f);
-
-        let head = if O::is_large() {
-            "LargeBinaryArray"
-        } else {
-            "BinaryArray"
-        };
-        write!(f, "{}", head)?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        write_array(self, BinaryFormat::default(), f)
+    }
+}
+
+/// A [`BinaryArray`] whose [`Debug`] formats its values according to a [`BinaryFormat`],
+/// returned by [`BinaryArray::debug_as`].
+#[derive(Clone, Copy)]
+pub struct BinaryDebug<'a, O: Offset> {
+    array: &'a BinaryArray<O>,
+    format: BinaryFormat,
+}
+
+impl<O: Offset> Debug for BinaryDebug<'_, O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_array(self.array, self.format, f)
+    }
+}
+
+impl<O: Offset> BinaryArray<O> {
+    /// Returns this array with a [`Debug`] that formats its values according to `format`
+    /// instead of the default ([`BinaryFormat::Hex`]).
+    /// # Example
+    /// ```
+    /// use arrow2::array::{BinaryArray, BinaryFormat};
+    ///
+    /// let array = BinaryArray::<i32>::from([Some(b"a\n".as_ref()), None]);
+    /// assert_eq!(format!("{:?}", array), "BinaryArray[0x610a, None]");
+    /// assert_eq!(
+    ///     format!("{:?}", array.debug_as(BinaryFormat::Escaped)),
+    ///     r#"BinaryArray[b"a\n", None]"#
+    /// );
+    /// ```
+    pub fn debug_as(&self, format: BinaryFormat) -> BinaryDebug<'_, O> {
+        BinaryDebug {
+            array: self,
+            format,
+        }
     }
 }
@@ -12,6 +12,7 @@ use super::{
 
 mod ffi;
 pub(super) mod fmt;
+pub use fmt::{BinaryDebug, BinaryFormat};
 mod iterator;
 pub use iterator::*;
 mod from;
@@ -12,6 +12,6 @@ impl Debug for BooleanArray {
         let writer = |f: &mut Formatter, index| write_value(self, index, f);
 
         write!(f, "BooleanArray")?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        write_vec(f, writer, self.validity(), self.len(), "None", false, None)
     }
 }
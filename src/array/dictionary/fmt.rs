@@ -27,6 +27,6 @@ impl<K: DictionaryKey> Debug for DictionaryArray<K> {
         let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
 
         write!(f, "DictionaryArray")?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        write_vec(f, writer, self.validity(), self.len(), "None", false, None)
     }
 }
@@ -89,6 +89,18 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
+    /// Sets whether the ordering of this [`DictionaryArray`]'s keys is semantically meaningful.
+    ///
+    /// The values of an ordered dictionary are expected to be sorted in ascending order, so that
+    /// comparing its keys is equivalent to comparing its values. The ordering comparisons with a
+    /// scalar (e.g. `compute::comparison::lt_scalar`) then compare its keys instead of gathering
+    /// the results of its values, and fall back to the latter when its values are not sorted.
+    pub fn set_ordered(&mut self, is_ordered: bool) {
+        if let DataType::Dictionary(_, _, ordered) = &mut self.data_type {
+            *ordered = is_ordered
+        }
+    }
+
     /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
     /// # Panics
     /// iff `offset + length > self.len()`.
@@ -7,7 +7,7 @@ pub fn write_value<W: Write>(array: &FixedSizeBinaryArray, index: usize, f: &mut
     let values = array.value(index);
     let writer = |f: &mut W, index| write!(f, "{}", values[index]);
 
-    write_vec(f, writer, None, values.len(), "None", false)
+    write_vec(f, writer, None, values.len(), "None", false, None)
 }
 
 impl Debug for FixedSizeBinaryArray {
@@ -15,6 +15,6 @@ impl Debug for FixedSizeBinaryArray {
         let writer = |f: &mut Formatter, index| write_value(self, index, f);
 
         write!(f, "{:?}", self.data_type)?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        write_vec(f, writer, self.validity(), self.len(), "None", false, None)
     }
 }
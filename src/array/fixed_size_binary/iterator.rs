@@ -1,4 +1,5 @@
 use crate::bitmap::utils::{zip_validity, ZipValidity};
+use crate::trusted_len::TrustedLen;
 
 use super::super::MutableArray;
 use super::{FixedSizeBinaryArray, FixedSizeBinaryValues, MutableFixedSizeBinaryArray};
@@ -46,6 +47,8 @@ impl<'a, T: FixedSizeBinaryValues> Iterator for FixedSizeBinaryValuesIter<'a, T>
     }
 }
 
+unsafe impl<'a, T: FixedSizeBinaryValues> TrustedLen for FixedSizeBinaryValuesIter<'a, T> {}
+
 impl<'a> IntoIterator for &'a FixedSizeBinaryArray {
     type Item = Option<&'a [u8]>;
     type IntoIter = ZipValidity<'a, &'a [u8], FixedSizeBinaryValuesIter<'a, FixedSizeBinaryArray>>;
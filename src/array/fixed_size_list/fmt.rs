@@ -11,7 +11,7 @@ pub fn write_value<W: Write>(
 ) -> Result {
     let values = array.value(index);
     let writer = |f: &mut W, index| get_display(values.as_ref(), null)(f, index);
-    write_vec(f, writer, None, values.len(), null, false)
+    write_vec(f, writer, None, values.len(), null, false, None)
 }
 
 impl Debug for FixedSizeListArray {
@@ -19,6 +19,6 @@ impl Debug for FixedSizeListArray {
         let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
 
         write!(f, "FixedSizeListArray")?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        write_vec(f, writer, self.validity(), self.len(), "None", false, None)
     }
 }
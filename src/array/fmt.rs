@@ -1,4 +1,4 @@
-use std::fmt::{Result, Write};
+use std::fmt::{Formatter, Result, Write};
 
 use crate::bitmap::Bitmap;
 
@@ -113,6 +113,20 @@ pub fn get_display<'a, F: Write + 'a>(
     })
 }
 
+/// The maximum number of elements written by the [`Debug`](std::fmt::Debug) of an array,
+/// after which they are summarized as `... (N more)`.
+const DEBUG_MAX_LEN: usize = 100;
+
+/// Returns the maximum number of elements that the [`Debug`](std::fmt::Debug) of an array
+/// writes to `f`: all of them for the alternate flag (`{:#?}`), [`DEBUG_MAX_LEN`] otherwise.
+pub fn debug_max_len(f: &Formatter<'_>) -> Option<usize> {
+    if f.alternate() {
+        None
+    } else {
+        Some(DEBUG_MAX_LEN)
+    }
+}
+
 pub fn write_vec<D, F>(
     f: &mut F,
     d: D,
@@ -120,13 +134,14 @@ pub fn write_vec<D, F>(
     len: usize,
     null: &'static str,
     new_lines: bool,
+    max_len: Option<usize>,
 ) -> Result
 where
     D: Fn(&mut F, usize) -> Result,
     F: Write,
 {
     f.write_char('[')?;
-    write_list(f, d, validity, len, null, new_lines)?;
+    write_list(f, d, validity, len, null, new_lines, max_len)?;
     f.write_char(']')?;
     Ok(())
 }
@@ -138,15 +153,20 @@ fn write_list<D, F>(
     len: usize,
     null: &'static str,
     new_lines: bool,
+    max_len: Option<usize>,
 ) -> Result
 where
     D: Fn(&mut F, usize) -> Result,
     F: Write,
 {
-    for index in 0..len {
+    let separator = |f: &mut F| {
+        f.write_char(',')?;
+        f.write_char(if new_lines { '\n' } else { ' ' })
+    };
+    let written = max_len.map_or(len, |max_len| max_len.min(len));
+    for index in 0..written {
         if index != 0 {
-            f.write_char(',')?;
-            f.write_char(if new_lines { '\n' } else { ' ' })?;
+            separator(f)?;
         }
         if let Some(val) = validity {
             if val.get_bit(index) {
@@ -158,6 +178,12 @@ where
             d(f, index)
         }?;
     }
+    if written < len {
+        if written != 0 {
+            separator(f)?;
+        }
+        write!(f, "... ({} more)", len - written)?;
+    }
     Ok(())
 }
 
@@ -168,13 +194,14 @@ pub fn write_map<D, F>(
     len: usize,
     null: &'static str,
     new_lines: bool,
+    max_len: Option<usize>,
 ) -> Result
 where
     D: Fn(&mut F, usize) -> Result,
     F: Write,
 {
     f.write_char('{')?;
-    write_list(f, d, validity, len, null, new_lines)?;
+    write_list(f, d, validity, len, null, new_lines, max_len)?;
     f.write_char('}')?;
     Ok(())
 }
@@ -13,7 +13,7 @@ pub fn write_value<O: Offset, W: Write>(
 ) -> Result {
     let values = array.value(index);
     let writer = |f: &mut W, index| get_display(values.as_ref(), null)(f, index);
-    write_vec(f, writer, None, values.len(), null, false)
+    write_vec(f, writer, None, values.len(), null, false, None)
 }
 
 impl<O: Offset> Debug for ListArray<O> {
@@ -26,6 +26,6 @@ impl<O: Offset> Debug for ListArray<O> {
             "ListArray"
         };
         write!(f, "{}", head)?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        write_vec(f, writer, self.validity(), self.len(), "None", false, None)
     }
 }
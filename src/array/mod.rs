@@ -373,7 +373,7 @@ pub use equal::equal;
 pub use fmt::{get_display, get_value_display};
 
 pub use crate::types::Offset;
-pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray};
+pub use binary::{BinaryArray, BinaryDebug, BinaryFormat, BinaryValueIter, MutableBinaryArray};
 pub use boolean::{BooleanArray, MutableBooleanArray};
 pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
@@ -382,7 +382,7 @@ pub use list::{ListArray, ListValuesIter, MutableListArray};
 pub use map::MapArray;
 pub use null::NullArray;
 pub use primitive::*;
-pub use struct_::StructArray;
+pub use struct_::{MutableStructArray, StructArray, StructRow};
 pub use union::UnionArray;
 pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 
@@ -6,7 +6,7 @@ use crate::types::{days_ms, months_days_ns};
 
 use super::super::super::temporal_conversions;
 use super::super::super::types::NativeType;
-use super::super::fmt::write_vec;
+use super::super::fmt::{debug_max_len, write_vec};
 use super::PrimitiveArray;
 
 macro_rules! dyn_primitive {
@@ -123,6 +123,15 @@ impl<T: NativeType> Debug for PrimitiveArray<T> {
         let writer = get_write_value(self);
 
         write!(f, "{:?}", self.data_type())?;
-        write_vec(f, &*writer, self.validity(), self.len(), "None", false)
+        let max_len = debug_max_len(f);
+        write_vec(
+            f,
+            &*writer,
+            self.validity(),
+            self.len(),
+            "None",
+            false,
+            max_len,
+        )
     }
 }
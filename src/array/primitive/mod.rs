@@ -247,6 +247,24 @@ impl<T: NativeType> PrimitiveArray<T> {
             }
         }
     }
+
+    /// Consumes this [`PrimitiveArray`], returning its values as a [`Vec`] and its validity.
+    /// This is the inverse of [`PrimitiveArray::from_vec`].
+    /// # Implementation
+    /// This operation is `O(1)` when the values' [`Buffer`] is not shared
+    /// (see [`Buffer::into_mut`]), and `O(N)` (a memcopy) otherwise.
+    pub fn into_values(self) -> (Vec<T>, Option<Bitmap>) {
+        let length = self.values.len();
+        let values = match self.values.into_mut() {
+            Either::Right(mut values) => {
+                // a slice starting at zero still owns the whole region
+                values.truncate(length);
+                values
+            }
+            Either::Left(values) => values.as_slice().to_vec(),
+        };
+        (values, self.validity)
+    }
 }
 
 impl<T: NativeType> Array for PrimitiveArray<T> {
@@ -21,7 +21,7 @@ pub fn write_value<W: Write>(
         Ok(())
     };
 
-    write_map(f, writer, None, 1, null, false)
+    write_map(f, writer, None, 1, null, false, None)
 }
 
 impl Debug for StructArray {
@@ -29,6 +29,6 @@ impl Debug for StructArray {
         let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
 
         write!(f, "StructArray")?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        write_vec(f, writer, self.validity(), self.len(), "None", false, None)
     }
 }
@@ -6,6 +6,56 @@ use crate::{
 
 use super::StructArray;
 
+/// A lazy view over a row (slot) of a [`StructArray`], whose fields are only materialized
+/// into [`Scalar`]s when accessed.
+#[derive(Debug, Clone, Copy)]
+pub struct StructRow<'a> {
+    array: &'a StructArray,
+    index: usize,
+}
+
+impl<'a> StructRow<'a> {
+    /// Returns the index of this row in its [`StructArray`].
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of fields of this row.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.array.values().len()
+    }
+
+    /// Returns whether this row has no fields.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value of the field at `field_index` of this row.
+    /// # Panics
+    /// iff `field_index >= self.len()`
+    #[inline]
+    pub fn get(&self, field_index: usize) -> Box<dyn Scalar> {
+        new_scalar(self.array.values()[field_index].as_ref(), self.index)
+    }
+
+    /// Returns an iterator over the values of the fields of this row.
+    pub fn iter(&self) -> impl Iterator<Item = Box<dyn Scalar>> + 'a {
+        let index = self.index;
+        self.array
+            .values()
+            .iter()
+            .map(move |v| new_scalar(v.as_ref(), index))
+    }
+
+    /// Returns the values of the fields of this row.
+    pub fn to_vec(&self) -> Vec<Box<dyn Scalar>> {
+        self.iter().collect()
+    }
+}
+
 pub struct StructValueIter<'a> {
     array: &'a StructArray,
     index: usize,
@@ -24,7 +74,7 @@ impl<'a> StructValueIter<'a> {
 }
 
 impl<'a> Iterator for StructValueIter<'a> {
-    type Item = Vec<Box<dyn Scalar>>;
+    type Item = StructRow<'a>;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
@@ -36,13 +86,10 @@ impl<'a> Iterator for StructValueIter<'a> {
 
         // Safety:
         // self.end is maximized by the length of the array
-        Some(
-            self.array
-                .values()
-                .iter()
-                .map(|v| new_scalar(v.as_ref(), old))
-                .collect(),
-        )
+        Some(StructRow {
+            array: self.array,
+            index: old,
+        })
     }
 
     #[inline]
@@ -63,22 +110,19 @@ impl<'a> DoubleEndedIterator for StructValueIter<'a> {
 
             // Safety:
             // self.end is maximized by the length of the array
-            Some(
-                self.array
-                    .values()
-                    .iter()
-                    .map(|v| new_scalar(v.as_ref(), self.end))
-                    .collect(),
-            )
+            Some(StructRow {
+                array: self.array,
+                index: self.end,
+            })
         }
     }
 }
 
 type ValuesIter<'a> = StructValueIter<'a>;
-type ZipIter<'a> = ZipValidity<'a, Vec<Box<dyn Scalar>>, ValuesIter<'a>>;
+type ZipIter<'a> = ZipValidity<'a, StructRow<'a>, ValuesIter<'a>>;
 
 impl<'a> IntoIterator for &'a StructArray {
-    type Item = Option<Vec<Box<dyn Scalar>>>;
+    type Item = Option<StructRow<'a>>;
     type IntoIter = ZipIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -87,7 +131,7 @@ impl<'a> IntoIterator for &'a StructArray {
 }
 
 impl<'a> StructArray {
-    /// Returns an iterator of `Option<Box<dyn Array>>`
+    /// Returns an iterator of `Option<StructRow>`, which is `None` for null slots
     pub fn iter(&'a self) -> ZipIter<'a> {
         zip_validity(
             StructValueIter::new(self),
@@ -95,7 +139,7 @@ impl<'a> StructArray {
         )
     }
 
-    /// Returns an iterator of `Box<dyn Array>`
+    /// Returns an iterator of [`StructRow`], ignoring the validity
     pub fn values_iter(&'a self) -> ValuesIter<'a> {
         StructValueIter::new(self)
     }
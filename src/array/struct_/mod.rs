@@ -1,16 +1,22 @@
 use std::sync::Arc;
 
+use either::Either;
+
 use crate::{
-    bitmap::Bitmap,
-    datatypes::{DataType, Field},
+    bitmap::{Bitmap, MutableBitmap},
+    chunk::Chunk,
+    datatypes::{DataType, Field, PhysicalType},
     error::ArrowError,
 };
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{new_empty_array, new_null_array, Array, MapArray};
 
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
+mod mutable;
+pub use iterator::StructRow;
+pub use mutable::*;
 
 /// A [`StructArray`] is a nested [`Array`] with an optional validity representing
 /// multiple [`Array`] with the same number of rows.
@@ -51,7 +57,22 @@ impl StructArray {
         values: Vec<Arc<dyn Array>>,
         validity: Option<Bitmap>,
     ) -> Result<Self, ArrowError> {
-        let fields = Self::try_get_fields(&data_type)?;
+        Self::check(&data_type, &values, validity.as_ref())?;
+
+        Ok(Self {
+            data_type,
+            values,
+            validity,
+        })
+    }
+
+    /// Checks the invariants of a [`StructArray`], as documented in [`StructArray::try_new`].
+    fn check(
+        data_type: &DataType,
+        values: &[Arc<dyn Array>],
+        validity: Option<&Bitmap>,
+    ) -> Result<(), ArrowError> {
+        let fields = Self::try_get_fields(data_type)?;
         if fields.is_empty() {
             return Err(ArrowError::oos(
                 "A StructArray must contain at least one field",
@@ -94,20 +115,40 @@ impl StructArray {
                 }
             })?;
 
-        if validity
-            .as_ref()
-            .map_or(false, |validity| validity.len() != len)
-        {
+        if matches!(validity, Some(validity) if validity.len() != len) {
             return Err(ArrowError::oos(
                 "The validity length of a StructArray must match its number of elements",
             ));
         }
+        Ok(())
+    }
 
-        Ok(Self {
-            data_type,
-            values,
-            validity,
-        })
+    /// Returns a new [`StructArray`] whose fields have unique names, so that looking them up by
+    /// name is not ambiguous.
+    ///
+    /// Contrarily to [`StructArray::try_new`], which follows the Arrow specification in allowing
+    /// duplicate names, this function is meant for schemas whose field names are controlled.
+    /// # Errors
+    /// This function errors iff either:
+    /// * any of the errors of [`StructArray::try_new`]
+    /// * two fields of `data_type` have the same name
+    pub fn try_new_strict(
+        data_type: DataType,
+        values: Vec<Arc<dyn Array>>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, ArrowError> {
+        let fields = Self::try_get_fields(&data_type)?;
+        let mut names = std::collections::HashSet::with_capacity(fields.len());
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !names.insert(field.name.as_str()))
+        {
+            return Err(ArrowError::oos(format!(
+                "The fields of a strict StructArray must have unique names, but \"{}\" is duplicated",
+                field.name
+            )));
+        }
+        Self::try_new(data_type, values, validity)
     }
 
     /// Returns a new [`StructArray`]
@@ -123,6 +164,39 @@ impl StructArray {
         Self::try_new(data_type, values, validity).unwrap()
     }
 
+    /// Returns a new [`StructArray`] without checking its invariants, e.g. when a trusted
+    /// producer already upholds them.
+    ///
+    /// The invariants are still checked in debug builds, which panic when they do not hold.
+    /// # Safety
+    /// The caller must ensure that:
+    /// * `data_type`'s physical type is [`crate::datatypes::PhysicalType::Struct`]
+    /// * the children of `data_type` are not empty
+    /// * the values's len is equal to the children's length
+    /// * each of the values's data type is equal to its corresponding children' data type
+    /// * every element of values has the same length as the first element
+    /// * the validity's length, if any, is equal to the length of the first element
+    ///
+    /// Otherwise, the unchecked accessors of this array and of its values may read out of bounds.
+    /// # Implementation
+    /// This function is `O(1)` in release builds.
+    pub unsafe fn new_unchecked(
+        data_type: DataType,
+        values: Vec<Arc<dyn Array>>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        #[cfg(debug_assertions)]
+        if let Err(error) = Self::check(&data_type, &values, validity.as_ref()) {
+            panic!("{}", error)
+        }
+
+        Self {
+            data_type,
+            values,
+            validity,
+        }
+    }
+
     /// Alias for `new`
     pub fn from_data(
         data_type: DataType,
@@ -132,6 +206,33 @@ impl StructArray {
         Self::new(data_type, values, validity)
     }
 
+    /// Returns a new [`StructArray`] without validity from pairs of [`Field`] and child,
+    /// deriving its [`DataType::Struct`] from the fields.
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow2::array::*;
+    /// use arrow2::datatypes::*;
+    /// let boolean = Arc::new(BooleanArray::from_slice(&[false, false, true, true])) as Arc<dyn Array>;
+    /// let int = Arc::new(Int32Array::from_slice(&[42, 28, 19, 31])) as Arc<dyn Array>;
+    ///
+    /// let array = StructArray::try_from_fields(vec![
+    ///     (Field::new("b", DataType::Boolean, false), boolean),
+    ///     (Field::new("c", DataType::Int32, false), int),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(array.fields()[1].name, "c");
+    /// ```
+    /// # Errors
+    /// This function errors iff:
+    /// * `fields` is empty
+    /// * any of the values's data type is different from its corresponding field's data type
+    /// * any of the values has a different length than the first value
+    pub fn try_from_fields(fields: Vec<(Field, Arc<dyn Array>)>) -> Result<Self, ArrowError> {
+        let (fields, values): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+        Self::try_new(DataType::Struct(fields), values, None)
+    }
+
     /// Creates an empty [`StructArray`].
     pub fn new_empty(data_type: DataType) -> Self {
         if let DataType::Struct(fields) = &data_type {
@@ -177,17 +278,49 @@ impl StructArray {
         (fields, values, validity)
     }
 
+    /// Creates a new [`StructArray`] without validity from the columns of `chunk`, whose fields
+    /// are `fields`. The columns are shared, not copied.
+    /// # Errors
+    /// This function errors iff the columns of `chunk` do not match `fields`
+    /// (see [`StructArray::try_new`]).
+    pub fn from_chunk(
+        chunk: Chunk<Arc<dyn Array>>,
+        fields: Vec<Field>,
+    ) -> Result<Self, ArrowError> {
+        Self::try_new(DataType::Struct(fields), chunk.into_arrays(), None)
+    }
+
+    /// Converts this [`StructArray`] into a [`Chunk`] of its children, which are shared,
+    /// not copied. Use [`StructArray::fields`] beforehand to keep their fields.
+    /// # Errors
+    /// This function errors iff `self` has null slots: a [`Chunk`] has no validity, and the values
+    /// of the children in null slots are undefined. A validity without nulls is dropped.
+    pub fn into_chunk(self) -> Result<Chunk<Arc<dyn Array>>, ArrowError> {
+        if self.null_count() > 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "A StructArray with null slots can't be converted into a Chunk".to_string(),
+            ));
+        }
+        Chunk::try_new(self.values)
+    }
+
     /// Creates a new [`StructArray`] that is a slice of `self`.
+    ///
+    /// The [`DataType`] of `self` is kept as is, including the metadata of its fields.
     /// # Panics
-    /// * `offset + length` must be smaller than `self.len()`.
+    /// iff `offset + length > self.len()`.
     /// # Implementation
-    /// This operation is `O(F)` where `F` is the number of fields.
+    /// This operation is `O(F)` where `F` is the number of fields, or `O(1)` (a clone) when the
+    /// slice is the whole array.
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
             "offset + length may not exceed length of array"
         );
+        if offset == 0 && length == self.len() {
+            return self.clone();
+        }
         unsafe { self.slice_unchecked(offset, length) }
     }
 
@@ -213,6 +346,190 @@ impl StructArray {
         }
     }
 
+    /// Returns a new [`StructArray`] with only the fields named `names`, in that order.
+    /// The children are shared with `self` and the validity of `self` is preserved.
+    /// # Errors
+    /// This function errors iff:
+    /// * any of `names` is not a field of `self`
+    /// * `names` is empty
+    /// # Implementation
+    /// This operation is `O(F * N)` where `F` is the number of fields and `N` the number of names.
+    pub fn project(&self, names: &[&str]) -> Result<Self, ArrowError> {
+        let (fields, values) = names
+            .iter()
+            .map(|name| {
+                self.index_of(name)
+                    .map(|index| (self.fields()[index].clone(), self.values[index].clone()))
+                    .ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "The struct has no field named \"{}\"",
+                            name
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        Self::try_new(DataType::Struct(fields), values, self.validity.clone())
+    }
+
+    /// Returns a new [`StructArray`] with the fields of `self` in the order of `field_order`,
+    /// e.g. to align arrays whose fields are the same but listed in different orders.
+    /// The children are shared with `self` and the validity of `self` is preserved.
+    ///
+    /// Contrarily to [`StructArray::project`], all fields of `self` must be named.
+    /// Fields sharing a name are matched by position: the `n`-th occurrence of a name in
+    /// `field_order` selects the `n`-th field of `self` with that name.
+    /// # Errors
+    /// This function errors iff `field_order` is not a permutation of the field names of `self`,
+    /// i.e. iff any of its names is not a field of `self`, is repeated more times than the
+    /// fields with that name or a field is missing.
+    /// # Implementation
+    /// This operation is `O(F^2)` where `F` is the number of fields.
+    pub fn reorder_to(&self, field_order: &[&str]) -> Result<Self, ArrowError> {
+        if field_order.len() != self.values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The struct has {} fields, but {} names were given to reorder them",
+                self.values.len(),
+                field_order.len()
+            )));
+        }
+        let mut seen = vec![false; self.values.len()];
+        let (fields, values) = field_order
+            .iter()
+            .map(|name| {
+                let mut indices = self
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, field)| field.name == *name)
+                    .map(|(index, _)| index)
+                    .peekable();
+                if indices.peek().is_none() {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "The struct has no field named \"{}\"",
+                        name
+                    )));
+                }
+                // the first field with this name that was not reordered yet
+                let index = indices.find(|index| !seen[*index]).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The field \"{}\" is given more times than the struct has fields with this name",
+                        name
+                    ))
+                })?;
+                seen[index] = true;
+                Ok((self.fields()[index].clone(), self.values[index].clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        Self::try_new(DataType::Struct(fields), values, self.validity.clone())
+    }
+
+    /// Returns a new [`StructArray`] with `field` and its `values` appended to the fields
+    /// of `self`. The existing children and the validity of `self` are preserved.
+    /// # Errors
+    /// This function errors iff:
+    /// * `values`'s length is different from `self.len()`
+    /// * `values`'s data type is different from `field`'s data type
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields.
+    pub fn with_field(&self, field: Field, values: Arc<dyn Array>) -> Result<Self, ArrowError> {
+        let mut fields = self.fields().to_vec();
+        fields.push(field);
+        let mut children = self.values.clone();
+        children.push(values);
+
+        Self::try_new(DataType::Struct(fields), children, self.validity.clone())
+    }
+
+    /// Returns a new [`StructArray`] without the first field named `name`.
+    /// The remaining children and the validity of `self` are preserved.
+    /// # Errors
+    /// This function errors iff:
+    /// * `self` has no field named `name`
+    /// * `name` is the only field of `self`
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields.
+    pub fn without_field(&self, name: &str) -> Result<Self, ArrowError> {
+        let index = self.index_of(name).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!("The struct has no field named \"{}\"", name))
+        })?;
+        let mut fields = self.fields().to_vec();
+        fields.remove(index);
+        let mut children = self.values.clone();
+        children.remove(index);
+
+        Self::try_new(DataType::Struct(fields), children, self.validity.clone())
+    }
+
+    /// Returns a new [`StructArray`] whose child of the first field named `name` is replaced
+    /// by `values`. The remaining children, the fields and the validity of `self` are preserved.
+    /// # Errors
+    /// This function errors iff:
+    /// * `self` has no field named `name`
+    /// * `values`'s length is different from `self.len()`
+    /// * `values`'s data type is different from the field's data type
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields.
+    pub fn replace_field(&self, name: &str, values: Arc<dyn Array>) -> Result<Self, ArrowError> {
+        let index = self.index_of(name).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!("The struct has no field named \"{}\"", name))
+        })?;
+        let mut children = self.values.clone();
+        children[index] = values;
+
+        Self::try_new(self.data_type.clone(), children, self.validity.clone())
+    }
+
+    /// Returns a new [`StructArray`] whose children that are themselves structs are recursively
+    /// replaced by their own children, so that the result has no struct children.
+    ///
+    /// A flattened child is named after the names of its ancestors and its own name separated
+    /// by a dot (e.g. the child `city` of the child `address` is named `address.city`).
+    /// A null slot of a nested struct is null in all its flattened descendants,
+    /// and the validity of `self` is preserved. Non-struct children are kept unchanged.
+    /// # Errors
+    /// This function errors iff a union is a descendant of a nullable nested struct, since
+    /// unions have no validity to carry the nulls of the struct.
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow2::array::*;
+    /// use arrow2::datatypes::*;
+    ///
+    /// let city = Arc::new(Utf8Array::<i32>::from_slice(["Lisbon", "Porto"])) as Arc<dyn Array>;
+    /// let address = StructArray::new(
+    ///     DataType::Struct(vec![Field::new("city", DataType::Utf8, false)]),
+    ///     vec![city],
+    ///     None,
+    /// );
+    /// let array = StructArray::new(
+    ///     DataType::Struct(vec![Field::new("address", address.data_type().clone(), false)]),
+    ///     vec![Arc::new(address)],
+    ///     None,
+    /// );
+    /// let flattened = array.flatten().unwrap();
+    /// assert_eq!(flattened.fields()[0].name, "address.city");
+    /// ```
+    pub fn flatten(&self) -> Result<Self, ArrowError> {
+        let mut fields = vec![];
+        let mut values = vec![];
+        flatten_into(
+            None,
+            self.fields(),
+            &self.values,
+            None,
+            false,
+            &mut fields,
+            &mut values,
+        )?;
+        Self::try_new(DataType::Struct(fields), values, self.validity.clone())
+    }
+
     /// Sets the validity bitmap on this [`StructArray`].
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
@@ -225,6 +542,35 @@ impl StructArray {
         arr.validity = validity;
         arr
     }
+
+    /// Applies `f` to the validity of this [`StructArray`] as a [`MutableBitmap`], e.g. to set
+    /// the struct-level null of some rows in place. When `self` has no validity, `f` is applied
+    /// to an all-valid bitmap.
+    ///
+    /// The validity is mutated in place when it is not shared (e.g. with a clone of this array,
+    /// or with the array it was sliced from), and is copied otherwise, so that no other array
+    /// is affected.
+    /// # Panic
+    /// This function panics iff `f` changes the length of the validity.
+    pub fn validity_mut<F: FnOnce(&mut MutableBitmap)>(&mut self, f: F) {
+        let length = self.len();
+        let mut validity = match self.validity.take().map(|validity| validity.into_mut()) {
+            Some(Either::Right(validity)) => validity,
+            Some(Either::Left(shared)) => {
+                let mut validity = MutableBitmap::with_capacity(length);
+                validity.extend_from_bitmap(&shared);
+                validity
+            }
+            None => MutableBitmap::from_len_set(length),
+        };
+        f(&mut validity);
+        assert_eq!(
+            validity.len(),
+            length,
+            "the validity must have the same length as the array"
+        );
+        self.validity = validity.into();
+    }
 }
 
 // Accessors
@@ -240,6 +586,23 @@ impl StructArray {
         self.validity.as_ref()
     }
 
+    /// Returns the validity of this [`StructArray`] or, when it has none,
+    /// a new [`Bitmap`] whose all slots are set (i.e. all rows are valid).
+    pub fn validity_or_default(&self) -> Bitmap {
+        self.validity
+            .clone()
+            .unwrap_or_else(|| MutableBitmap::from_len_set(self.len()).into())
+    }
+
+    /// Returns a [`StructArray`] equal to this one that is guaranteed to have a validity.
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields when `self` has a validity,
+    /// and allocates a new [`Bitmap`] otherwise.
+    #[must_use]
+    pub fn ensure_validity(&self) -> Self {
+        self.with_validity(Some(self.validity_or_default()))
+    }
+
     /// Returns the values of this [`StructArray`].
     pub fn values(&self) -> &[Arc<dyn Array>] {
         &self.values
@@ -249,6 +612,20 @@ impl StructArray {
     pub fn fields(&self) -> &[Field] {
         Self::get_fields(&self.data_type)
     }
+
+    /// Returns the index of the first field named `name`, if any.
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields (a linear scan).
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.fields().iter().position(|field| field.name == name)
+    }
+
+    /// Returns the child of the first field named `name`, if any.
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields (a linear scan).
+    pub fn column_by_name(&self, name: &str) -> Option<&Arc<dyn Array>> {
+        self.index_of(name).map(|index| &self.values[index])
+    }
 }
 
 impl StructArray {
@@ -268,6 +645,90 @@ impl StructArray {
     }
 }
 
+/// Pushes the recursively flattened `array_fields` and `array_values` of a struct to
+/// `fields` and `values`. `validity` and `is_nullable` are those of the struct's ancestors,
+/// excluding the root.
+fn flatten_into(
+    prefix: Option<&str>,
+    array_fields: &[Field],
+    array_values: &[Arc<dyn Array>],
+    validity: Option<&Bitmap>,
+    is_nullable: bool,
+    fields: &mut Vec<Field>,
+    values: &mut Vec<Arc<dyn Array>>,
+) -> Result<(), ArrowError> {
+    for (field, value) in array_fields.iter().zip(array_values.iter()) {
+        let name = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, field.name),
+            None => field.name.clone(),
+        };
+        let is_nullable = is_nullable || field.is_nullable;
+
+        if value.data_type().to_physical_type() == PhysicalType::Struct {
+            let child = value.as_any().downcast_ref::<StructArray>().unwrap();
+            let validity = and_validities(validity, child.validity());
+            flatten_into(
+                Some(&name),
+                child.fields(),
+                child.values(),
+                validity.as_ref(),
+                is_nullable,
+                fields,
+                values,
+            )?;
+        } else {
+            let value = match validity {
+                Some(validity) => with_ancestors_validity(value, validity, &name)?,
+                None => value.clone(),
+            };
+            let field = Field::new(name, field.data_type.clone(), is_nullable)
+                .with_metadata(field.metadata.clone());
+            fields.push(field);
+            values.push(value);
+        }
+    }
+    Ok(())
+}
+
+/// Returns `value` whose slots are also null where `validity` (of its ancestors) is null.
+fn with_ancestors_validity(
+    value: &Arc<dyn Array>,
+    validity: &Bitmap,
+    name: &str,
+) -> Result<Arc<dyn Array>, ArrowError> {
+    Ok(match value.data_type().to_physical_type() {
+        // all slots of a `NullArray` are already null
+        PhysicalType::Null => value.clone(),
+        // `MapArray` does not implement `Array::with_validity`
+        PhysicalType::Map => {
+            let map = value.as_any().downcast_ref::<MapArray>().unwrap();
+            Arc::new(MapArray::new(
+                map.data_type().clone(),
+                map.offsets().clone(),
+                map.field().clone(),
+                and_validities(Some(validity), map.validity()),
+            ))
+        }
+        PhysicalType::Union => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Flattening the union \"{}\" of a nullable struct is not yet supported, as unions have no validity",
+                name
+            )))
+        }
+        _ => value
+            .with_validity(and_validities(Some(validity), value.validity()))
+            .into(),
+    })
+}
+
+fn and_validities(lhs: Option<&Bitmap>, rhs: Option<&Bitmap>) -> Option<Bitmap> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(lhs & rhs),
+        (Some(validity), None) | (None, Some(validity)) => Some(validity.clone()),
+        (None, None) => None,
+    }
+}
+
 impl Array for StructArray {
     #[inline]
     fn as_any(&self) -> &dyn std::any::Any {
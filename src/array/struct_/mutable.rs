@@ -0,0 +1,286 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        Array, FixedSizeBinaryArray, MutableArray, MutableBinaryArray, MutableBooleanArray,
+        MutableFixedSizeBinaryArray, MutablePrimitiveArray, MutableUtf8Array, Offset,
+    },
+    bitmap::MutableBitmap,
+    datatypes::{DataType, PhysicalType, Schema},
+    error::{ArrowError, Result},
+};
+
+use super::StructArray;
+
+/// The mutable version of [`StructArray`].
+/// Values are pushed to each child (see [`MutableStructArray::value`]) followed by
+/// [`MutableStructArray::push_valid`], or to all children at once via [`MutableArray::push_null`].
+#[derive(Debug)]
+pub struct MutableStructArray {
+    data_type: DataType,
+    values: Vec<Box<dyn MutableArray>>,
+    validity: Option<MutableBitmap>,
+}
+
+impl From<MutableStructArray> for StructArray {
+    fn from(mut other: MutableStructArray) -> Self {
+        other.take_into()
+    }
+}
+
+impl MutableStructArray {
+    /// Creates a new [`MutableStructArray`] from its children builders.
+    /// # Panics
+    /// This function panics iff [`MutableStructArray::try_new`] errors.
+    pub fn new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Self {
+        Self::try_new(data_type, values, None).unwrap()
+    }
+
+    /// Creates a new [`MutableStructArray`] from its children builders and validity.
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Struct`].
+    /// * the children of `data_type` are empty
+    /// * the values's len is different from children's length
+    /// * any of the values's data type is different from its corresponding children' data type
+    /// * any element of values has a different length than the first element
+    /// * the validity's length is not equal to the length of the first element
+    pub fn try_new(
+        data_type: DataType,
+        values: Vec<Box<dyn MutableArray>>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self> {
+        let fields = StructArray::try_get_fields(&data_type)?;
+        if fields.is_empty() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must contain at least one field",
+            ));
+        }
+        if fields.len() != values.len() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must have a number of fields in its DataType equal to the number of child values",
+            ));
+        }
+        if let Some(index) = fields
+            .iter()
+            .zip(values.iter())
+            .position(|(field, value)| field.data_type() != value.data_type())
+        {
+            return Err(ArrowError::oos(format!(
+                "The children DataTypes of a MutableStructArray must equal the children data types. However, the field {index} has data type {:?} but the value has data type {:?}",
+                fields[index].data_type(),
+                values[index].data_type()
+            )));
+        }
+        let len = values[0].len();
+        if values.iter().any(|value| value.len() != len) {
+            return Err(ArrowError::oos(
+                "All children of a MutableStructArray must have the same length",
+            ));
+        }
+        if validity
+            .as_ref()
+            .map_or(false, |validity| validity.len() != len)
+        {
+            return Err(ArrowError::oos(
+                "The validity length of a MutableStructArray must match its number of elements",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            values,
+            validity,
+        })
+    }
+
+    /// Creates a new, empty [`MutableStructArray`] whose fields are the fields of `schema`,
+    /// with children builders pre-allocated for `capacity` slots.
+    /// # Errors
+    /// This function errors iff:
+    /// * `schema` has no fields
+    /// * the data type of any field (recursively) has no supported mutable builder, e.g. lists
+    pub fn from_schema(schema: &Schema, capacity: usize) -> Result<Self> {
+        let data_type = DataType::Struct(schema.fields.clone());
+        Self::with_capacity(data_type, capacity)
+    }
+
+    /// Creates a new, empty [`MutableStructArray`] of `data_type` with children builders
+    /// pre-allocated for `capacity` slots.
+    fn with_capacity(data_type: DataType, capacity: usize) -> Result<Self> {
+        let values = StructArray::try_get_fields(&data_type)?
+            .iter()
+            .map(|field| make_mutable(field.data_type(), capacity))
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(data_type, values, None)
+    }
+
+    /// The children builders of this [`MutableStructArray`].
+    pub fn values(&self) -> &[Box<dyn MutableArray>] {
+        &self.values
+    }
+
+    /// The children builders of this [`MutableStructArray`] as mutable references.
+    pub fn mut_values(&mut self) -> &mut [Box<dyn MutableArray>] {
+        &mut self.values
+    }
+
+    /// Returns the child builder at position `i` downcasted to `A`, or `None` if it is not an `A`.
+    /// # Panics
+    /// Panics iff `i` is larger or equal to the number of fields.
+    pub fn value<A: MutableArray + 'static>(&mut self, i: usize) -> Option<&mut A> {
+        self.values[i].as_mut_any().downcast_mut::<A>()
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.len();
+
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Needs to be called after a valid value was pushed to every child of this array.
+    #[inline]
+    pub fn push_valid(&mut self) {
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.values.iter_mut().for_each(|value| value.push_null());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableStructArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values
+            .iter_mut()
+            .for_each(|value| value.shrink_to_fit());
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    /// # Panics
+    /// Panics iff the children do not have the same length.
+    fn take_into(&mut self) -> StructArray {
+        StructArray::new(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|value| value.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+/// Returns an empty [`MutableArray`] of `data_type` with a pre-allocated capacity of `capacity` slots.
+fn make_mutable(data_type: &DataType, capacity: usize) -> Result<Box<dyn MutableArray>> {
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Boolean => Box::new(MutableBooleanArray::from_data(
+            data_type.clone(),
+            MutableBitmap::with_capacity(capacity),
+            None,
+        )) as Box<dyn MutableArray>,
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Box::new(MutablePrimitiveArray::<$T>::with_capacity_from(capacity, data_type.clone()))
+                as Box<dyn MutableArray>
+        }),
+        PhysicalType::Binary => Box::new(MutableBinaryArray::<i32>::from_data(
+            data_type.clone(),
+            offsets_with_capacity(capacity),
+            vec![],
+            None,
+        )),
+        PhysicalType::LargeBinary => Box::new(MutableBinaryArray::<i64>::from_data(
+            data_type.clone(),
+            offsets_with_capacity(capacity),
+            vec![],
+            None,
+        )),
+        PhysicalType::Utf8 => Box::new(MutableUtf8Array::<i32>::from_data(
+            data_type.clone(),
+            offsets_with_capacity(capacity),
+            vec![],
+            None,
+        )),
+        PhysicalType::LargeUtf8 => Box::new(MutableUtf8Array::<i64>::from_data(
+            data_type.clone(),
+            offsets_with_capacity(capacity),
+            vec![],
+            None,
+        )),
+        PhysicalType::FixedSizeBinary => {
+            let size = FixedSizeBinaryArray::get_size(data_type);
+            Box::new(MutableFixedSizeBinaryArray::from_data(
+                data_type.clone(),
+                Vec::with_capacity(capacity * size),
+                None,
+            ))
+        }
+        PhysicalType::Struct => Box::new(MutableStructArray::with_capacity(
+            data_type.clone(),
+            capacity,
+        )?),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "A MutableStructArray can not yet be created with a child of physical type {:?}",
+                other
+            )))
+        }
+    })
+}
+
+fn offsets_with_capacity<O: Offset>(capacity: usize) -> Vec<O> {
+    let mut offsets = Vec::with_capacity(capacity + 1);
+    offsets.push(O::default());
+    offsets
+}
+
+impl MutableArray for MutableStructArray {
+    fn len(&self) -> usize {
+        self.values[0].len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    /// # Panics
+    /// Panics iff the children do not have the same length.
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take_into())
+    }
+
+    /// # Panics
+    /// Panics iff the children do not have the same length.
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take_into())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
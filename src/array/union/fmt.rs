@@ -19,6 +19,6 @@ impl Debug for UnionArray {
         let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
 
         write!(f, "UnionArray")?;
-        write_vec(f, writer, None, self.len(), "None", false)
+        write_vec(f, writer, None, self.len(), "None", false, None)
     }
 }
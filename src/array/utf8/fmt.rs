@@ -1,6 +1,6 @@
 use std::fmt::{Debug, Formatter, Result, Write};
 
-use super::super::fmt::write_vec;
+use super::super::fmt::{debug_max_len, write_vec};
 use super::super::Offset;
 use super::Utf8Array;
 
@@ -18,6 +18,15 @@ impl<O: Offset> Debug for Utf8Array<O> {
             "Utf8Array"
         };
         write!(f, "{}", head)?;
-        write_vec(f, writer, self.validity(), self.len(), "None", false)
+        let max_len = debug_max_len(f);
+        write_vec(
+            f,
+            writer,
+            self.validity(),
+            self.len(),
+            "None",
+            false,
+            max_len,
+        )
     }
 }
@@ -142,7 +142,9 @@ impl<O: Offset> MutableUtf8Array<O> {
         }
     }
 
-    /// Reserves `additional` elements and `additional_values` on the values buffer.
+    /// Reserves `additional` elements (on the offsets and validity) and `additional_values`
+    /// bytes on the values buffer, so that extending it by up to that many slots and bytes
+    /// does not re-allocate.
     pub fn reserve(&mut self, additional: usize, additional_values: usize) {
         self.offsets.reserve(additional);
         if let Some(x) = self.validity.as_mut() {
@@ -1,6 +1,8 @@
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::chunk::Chunk;
 use crate::datatypes::PhysicalType;
+use crate::types::Offset;
 
 fn validity_size(validity: Option<&Bitmap>) -> usize {
     validity.as_ref().map(|b| b.as_slice().0.len()).unwrap_or(0)
@@ -61,7 +63,7 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
                 + validity_size(array.validity())
         }
         FixedSizeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             estimated_bytes_size(array.values().as_ref()) + validity_size(array.validity())
         }
         LargeList => {
@@ -110,3 +112,102 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         }
     }
 }
+
+/// Returns the window `[start, end)` of the values of a variable-sized array
+fn offsets_window<O: Offset>(offsets: &[O]) -> (usize, usize) {
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    (start, end - start)
+}
+
+macro_rules! window_binary {
+    ($array:expr, $ty:ty, $o:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        let (_, length) = offsets_window(array.offsets());
+
+        length + array.offsets().len() * std::mem::size_of::<$o>() + validity_size(array.validity())
+    }};
+}
+
+macro_rules! window_list {
+    ($array:expr, $ty:ty, $o:ty, $values:ident) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        let (start, length) = offsets_window(array.offsets());
+
+        window_bytes_size(array.$values().slice(start, length).as_ref())
+            + array.offsets().len() * std::mem::size_of::<$o>()
+            + validity_size(array.validity())
+    }};
+}
+
+/// Same as [`estimated_bytes_size`] but only considering the values that are reachable
+/// from the slots of `array`, recursing into its children.
+fn window_bytes_size(array: &dyn Array) -> usize {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Binary => window_binary!(array, BinaryArray<i32>, i32),
+        LargeBinary => window_binary!(array, BinaryArray<i64>, i64),
+        Utf8 => window_binary!(array, Utf8Array<i32>, i32),
+        LargeUtf8 => window_binary!(array, Utf8Array<i64>, i64),
+        List => window_list!(array, ListArray<i32>, i32, values),
+        LargeList => window_list!(array, ListArray<i64>, i64, values),
+        Map => window_list!(array, MapArray, i32, field),
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            window_bytes_size(array.values().as_ref()) + validity_size(array.validity())
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| x.as_ref())
+                .map(window_bytes_size)
+                .sum::<usize>()
+                + validity_size(array.validity())
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            // the dictionary is written in full regardless of which keys are used
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            estimated_bytes_size(array.keys()) + estimated_bytes_size(array.values().as_ref())
+        }),
+        // the buffers of these arrays are sliced together with the array
+        // (or, for unions, an upper bound suffices).
+        Null | Boolean | Primitive(_) | FixedSizeBinary | Union => estimated_bytes_size(array),
+    }
+}
+
+/// Returns the estimated size in bytes of the values, offsets and validity of the rows
+/// `[offset, offset + length)` of `array`, including nested arrays.
+/// # Implementation
+/// Contrarily to [`estimated_bytes_size`], this only accounts for the values reachable from
+/// the rows in the range, also when `array` (or any of its children) is itself sliced.
+/// The values of dictionary arrays are always included in full.
+/// This is an estimation of the size of the data before encoding and compression.
+/// # Panic
+/// This function panics iff `offset + length > array.len()`.
+pub fn estimated_bytes_for_range(array: &dyn Array, offset: usize, length: usize) -> usize {
+    window_bytes_size(array.slice(offset, length).as_ref())
+}
+
+/// Returns the [`estimated_bytes_for_range`] of each column of `columns` for the rows
+/// `[offset, offset + length)`.
+///
+/// This can be used to decide row group and page boundaries (e.g. when writing to parquet)
+/// without encoding the data.
+/// # Panic
+/// This function panics iff `offset + length > columns.len()`.
+pub fn estimated_bytes_for_row_group<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    offset: usize,
+    length: usize,
+) -> Vec<usize> {
+    columns
+        .arrays()
+        .iter()
+        .map(|array| estimated_bytes_for_range(array.as_ref(), offset, length))
+        .collect()
+}
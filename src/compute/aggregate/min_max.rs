@@ -1,3 +1,5 @@
+use num_traits::Float;
+
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{ArrowError, Result};
@@ -9,6 +11,15 @@ use crate::{
     bitmap::Bitmap,
 };
 
+/// Options that define how [`min_with_options`] and [`max_with_options`] should behave
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MinMaxOptions {
+    /// Whether NaN values of floating point arrays are considered.
+    /// When `false` (the default), NaN values are ignored, like nulls.
+    /// When `true`, NaN is considered to be greater than any other non-null value.
+    pub include_nan: bool,
+}
+
 /// Trait describing a type describing multiple lanes with an order relationship
 /// consistent with the same order of `T`.
 pub trait SimdOrd<T> {
@@ -234,8 +245,21 @@ where
     reduced.max_element()
 }
 
+/// Returns `None` iff `value` is NaN. Only floating point types have values that
+/// are not equal to themselves.
+#[inline]
+#[allow(clippy::eq_op)]
+fn none_if_nan<T: NativeType>(value: T) -> Option<T> {
+    if value == value {
+        Some(value)
+    } else {
+        None
+    }
+}
+
 /// Returns the minimum value in the array, according to the natural order.
-/// For floating point arrays any NaN values are considered to be greater than any other non-null value
+/// For floating point arrays any NaN values are ignored, like nulls; use [`min_float`]
+/// to consider them instead.
 pub fn min_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
 where
     T: NativeType + Simd,
@@ -249,7 +273,8 @@ where
     }
     let values = array.values();
 
-    Some(if let Some(validity) = array.validity() {
+    // the reduction only results in NaN when all non-null values are NaN
+    none_if_nan(if let Some(validity) = array.validity() {
         null_min_primitive(values, validity)
     } else {
         nonnull_min_primitive(values)
@@ -257,7 +282,8 @@ where
 }
 
 /// Returns the maximum value in the array, according to the natural order.
-/// For floating point arrays any NaN values are considered to be greater than any other non-null value
+/// For floating point arrays any NaN values are ignored, like nulls; use [`max_float`]
+/// to consider them instead.
 pub fn max_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
 where
     T: NativeType + Simd,
@@ -271,13 +297,54 @@ where
     }
     let values = array.values();
 
-    Some(if let Some(validity) = array.validity() {
+    // the reduction only results in NaN when all non-null values are NaN
+    none_if_nan(if let Some(validity) = array.validity() {
         null_max_primitive(values, validity)
     } else {
         nonnull_max_primitive(values)
     })
 }
 
+/// Returns whether `array` has a non-null NaN value.
+fn has_nan<T: NativeType + Float>(array: &PrimitiveArray<T>) -> bool {
+    if let Some(validity) = array.validity() {
+        array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .any(|(x, is_valid)| is_valid && x.is_nan())
+    } else {
+        array.values().iter().any(|x| x.is_nan())
+    }
+}
+
+/// Returns the minimum value of a floating point array, according to [`MinMaxOptions`].
+/// When NaN values are included, the result is NaN iff all non-null values are NaN.
+pub fn min_float<T>(array: &PrimitiveArray<T>, options: MinMaxOptions) -> Option<T>
+where
+    T: NativeType + Simd + Float,
+    T::Simd: SimdOrd<T>,
+{
+    min_primitive(array).or_else(|| {
+        // `None` with non-null values implies that all of them are NaN
+        (options.include_nan && array.null_count() != array.len()).then(T::nan)
+    })
+}
+
+/// Returns the maximum value of a floating point array, according to [`MinMaxOptions`].
+/// When NaN values are included, the result is NaN iff any non-null value is NaN.
+pub fn max_float<T>(array: &PrimitiveArray<T>, options: MinMaxOptions) -> Option<T>
+where
+    T: NativeType + Simd + Float,
+    T::Simd: SimdOrd<T>,
+{
+    if options.include_nan && has_nan(array) {
+        Some(T::nan())
+    } else {
+        max_primitive(array)
+    }
+}
+
 /// Returns the maximum value in the binary array, according to the natural order.
 pub fn max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
     min_max_binary(array, |a, b| a < b)
@@ -379,12 +446,38 @@ macro_rules! with_match_primitive_type {(
     }
 })}
 
+macro_rules! dyn_float {
+    ($array:expr, $options:expr, $ty:ty, $f:ident) => {{
+        let data_type = $array.data_type().clone();
+        let array = $array.as_any().downcast_ref().unwrap();
+        Box::new(PrimitiveScalar::<$ty>::new(
+            data_type,
+            $f::<$ty>(array, $options),
+        ))
+    }};
+}
+
 /// Returns the maximum of [`Array`]. The scalar is null when all elements are null.
+/// NaN values of floating point arrays are ignored; see [`max_with_options`].
 /// # Error
 /// Errors iff the type does not support this operation.
 pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    max_with_options(array, MinMaxOptions::default())
+}
+
+/// Returns the maximum of [`Array`] according to [`MinMaxOptions`].
+/// The scalar is null when all elements are null.
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn max_with_options(array: &dyn Array, options: MinMaxOptions) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, max_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float32) => {
+            dyn_float!(array, options, f32, max_float)
+        }
+        PhysicalType::Primitive(PrimitiveType::Float64) => {
+            dyn_float!(array, options, f64, max_float)
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -408,11 +501,26 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
 }
 
 /// Returns the minimum of [`Array`]. The scalar is null when all elements are null.
+/// NaN values of floating point arrays are ignored; see [`min_with_options`].
 /// # Error
 /// Errors iff the type does not support this operation.
 pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    min_with_options(array, MinMaxOptions::default())
+}
+
+/// Returns the minimum of [`Array`] according to [`MinMaxOptions`].
+/// The scalar is null when all elements are null.
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn min_with_options(array: &dyn Array, options: MinMaxOptions) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, min_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float32) => {
+            dyn_float!(array, options, f32, min_float)
+        }
+        PhysicalType::Primitive(PrimitiveType::Float64) => {
+            dyn_float!(array, options, f64, min_float)
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -105,6 +105,12 @@ pub fn is_null(input: &dyn Array) -> BooleanArray {
     let len = input.len();
 
     let values = match input.validity() {
+        // a `NullArray` has no validity but all its slots are null
+        None if input.data_type() == &DataType::Null => {
+            let mut mutable = MutableBitmap::new();
+            mutable.extend_constant(len, true);
+            mutable.into()
+        }
         None => MutableBitmap::from_len_zeroed(len).into(),
         Some(buffer) => !buffer,
     };
@@ -125,6 +131,9 @@ pub fn is_null(input: &dyn Array) -> BooleanArray {
 /// ```
 pub fn is_not_null(input: &dyn Array) -> BooleanArray {
     let values = match input.validity() {
+        None if input.data_type() == &DataType::Null => {
+            MutableBitmap::from_len_zeroed(input.len()).into()
+        }
         None => {
             let mut mutable = MutableBitmap::new();
             mutable.extend_constant(input.len(), true);
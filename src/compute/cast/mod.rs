@@ -89,6 +89,13 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
             Null,
         ) => true,
+        (Struct(from_fields), Struct(to_fields)) => {
+            from_fields.len() == to_fields.len()
+                && from_fields
+                    .iter()
+                    .zip(to_fields.iter())
+                    .all(|(from, to)| can_cast_types(&from.data_type, &to.data_type))
+        }
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (List(list_from), List(list_to)) => {
@@ -295,18 +302,31 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     }
 }
 
+/// Adds the name of the (`nested`, e.g. list or struct) child field to the message of `error`,
+/// so that errors of nested casts name the path of fields that originated them.
+fn child_error(error: ArrowError, nested: &str, name: &str) -> ArrowError {
+    let context = |message: String| format!("casting {} field \"{}\": {}", nested, name, message);
+    match error {
+        ArrowError::NotYetImplemented(message) => ArrowError::NotYetImplemented(context(message)),
+        ArrowError::InvalidArgumentError(message) => {
+            ArrowError::InvalidArgumentError(context(message))
+        }
+        ArrowError::ExternalFormat(message) => ArrowError::ExternalFormat(context(message)),
+        ArrowError::OutOfSpec(message) => ArrowError::OutOfSpec(context(message)),
+        other => other,
+    }
+}
+
 fn cast_list<O: Offset>(
     array: &ListArray<O>,
     to_type: &DataType,
     options: CastOptions,
 ) -> Result<ListArray<O>> {
     let values = array.values();
-    let new_values = cast(
-        values.as_ref(),
-        ListArray::<O>::get_child_type(to_type),
-        options,
-    )?
-    .into();
+    let child = ListArray::<O>::get_child_field(to_type);
+    let new_values = cast(values.as_ref(), &child.data_type, options)
+        .map_err(|error| child_error(error, "list", &child.name))?
+        .into();
 
     Ok(ListArray::<O>::new(
         to_type.clone(),
@@ -316,6 +336,34 @@ fn cast_list<O: Offset>(
     ))
 }
 
+/// Casts each child of `array` to the (positionally) corresponding field of `to_type`.
+fn cast_struct(
+    array: &StructArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<StructArray> {
+    let fields = StructArray::get_fields(to_type);
+    if fields.len() != array.values().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Casting a struct with {} fields to a struct with {} fields is not supported",
+            array.values().len(),
+            fields.len()
+        )));
+    }
+    let values = array
+        .values()
+        .iter()
+        .zip(fields.iter())
+        .map(|(value, field)| {
+            cast(value.as_ref(), &field.data_type, options)
+                .map(|x| x.into())
+                .map_err(|error| child_error(error, "struct", &field.name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    StructArray::try_new(to_type.clone(), values, array.validity().cloned())
+}
+
 fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListArray<i64> {
     let offets = array
         .offsets()
@@ -358,13 +406,14 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
+/// * Struct to Struct: each child is cast to the field of the same position, whose name is kept
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, other than from a `StructArray` with the same number of fields
 /// * List to primitive
 /// * Utf8 to boolean
 /// * Interval and duration
@@ -389,6 +438,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
             Null,
         ) => Ok(new_null_array(to_type.clone(), array.len())),
+        (Struct(_), Struct(_)) => {
+            cast_struct(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
         (Struct(_), _) => Err(ArrowError::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
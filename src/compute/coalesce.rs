@@ -0,0 +1,70 @@
+//! Contains the coalesce kernel
+//!
+//! Example:
+//!
+//! ```
+//! use arrow2::array::{Array, Int32Array};
+//! use arrow2::compute::coalesce::coalesce;
+//!
+//! let a = Int32Array::from(&[Some(1), None, None]);
+//! let b = Int32Array::from(&[Some(10), Some(20), None]);
+//! let result = coalesce(&[&a, &b]).unwrap();
+//! assert_eq!(
+//!     result.as_ref(),
+//!     &Int32Array::from(&[Some(1), Some(20), None]) as &dyn Array
+//! );
+//! ```
+use std::sync::Arc;
+
+use crate::array::{clone, growable::make_growable, Array};
+use crate::error::{ArrowError, Result};
+
+/// Returns, for every row, the value of the first array in `arrays` that is valid at that row,
+/// or null if all of them are null.
+/// # Errors
+/// This function errors iff:
+/// * `arrays` is empty
+/// * the arrays do not have the same [`DataType`](crate::datatypes::DataType) or length
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Arc<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "coalesce requires input of at least one array".to_string(),
+        )
+    })?;
+    if arrays
+        .iter()
+        .any(|array| array.data_type() != first.data_type())
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to coalesce arrays of different data types.".to_string(),
+        ));
+    }
+    if arrays.iter().any(|array| array.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to coalesce arrays of different lengths.".to_string(),
+        ));
+    }
+    if first.null_count() == 0 {
+        return Ok(clone(*first).into());
+    }
+    let len = first.len();
+
+    // the index of the first array valid at `row`, or `None` if all of them are null
+    let source = |row: usize| arrays.iter().position(|array| array.is_valid(row));
+
+    let mut growable = make_growable(arrays, true, len);
+    let mut start = 0;
+    while start < len {
+        // extend from contiguous rows of the same source at once
+        let current = source(start);
+        let end = (start + 1..len)
+            .find(|row| source(*row) != current)
+            .unwrap_or(len);
+        match current {
+            Some(index) => growable.extend(index, start, end - start),
+            None => growable.extend_validity(end - start),
+        }
+        start = end;
+    }
+    Ok(growable.as_arc())
+}
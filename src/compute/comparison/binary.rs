@@ -1,9 +1,11 @@
 //! Comparison functions for [`BinaryArray`]
+use std::collections::HashSet;
+
 use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
 use crate::{
-    array::{BinaryArray, BooleanArray, Offset},
+    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray},
     bitmap::Bitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
 };
 
 use super::super::utils::combine_validities;
@@ -151,6 +153,223 @@ pub fn gt_eq_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray
     compare_op_scalar(lhs, rhs, |a, b| a >= b)
 }
 
+/// Perform `array >= low AND array <= high` operation on three [`BinaryArray`]s in a single pass.
+/// A null slot in any of the arrays results in a null slot.
+/// # Panics
+/// iff the arrays do not have the same length.
+pub fn between<O: Offset>(
+    array: &BinaryArray<O>,
+    low: &BinaryArray<O>,
+    high: &BinaryArray<O>,
+) -> BooleanArray {
+    assert_eq!(array.len(), low.len());
+    assert_eq!(array.len(), high.len());
+    let validity = combine_validities(array.validity(), low.validity());
+    let validity = combine_validities(validity.as_ref(), high.validity());
+
+    let values = array
+        .values_iter()
+        .zip(low.values_iter())
+        .zip(high.values_iter())
+        .map(|((a, low), high)| low <= a && a <= high);
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `array >= low AND array <= high` operation on [`BinaryArray`] and two scalars in a single
+/// pass.
+pub fn between_scalar<O: Offset>(array: &BinaryArray<O>, low: &[u8], high: &[u8]) -> BooleanArray {
+    compare_op_scalar(array, low, |a, low| low <= a && a <= high)
+}
+
+/// Returns whether each element of `array` is one of the (non-null) elements of `values`.
+/// Null elements of `array` are null in the result.
+pub fn is_in<O: Offset>(array: &BinaryArray<O>, values: &BinaryArray<O>) -> BooleanArray {
+    let values = if values.len() <= super::IS_IN_MAX_LINEAR_SCAN {
+        let values = values.iter().flatten().collect::<Vec<_>>();
+        Bitmap::from_trusted_len_iter(array.values_iter().map(|x| values.contains(&x)))
+    } else {
+        let values = values.iter().flatten().collect::<HashSet<_>>();
+        Bitmap::from_trusted_len_iter(array.values_iter().map(|x| values.contains(x)))
+    };
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns whether `needle` is a contiguous subsequence of `haystack`, searching for the first
+/// byte of `needle` before comparing the rest of it.
+fn contains_bytes(haystack: &[u8], needle: &[u8]) -> bool {
+    let (first, rest) = match needle.split_first() {
+        Some(split) => split,
+        None => return true,
+    };
+    if haystack.len() < needle.len() {
+        return false;
+    }
+    haystack[..haystack.len() - rest.len()]
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| *x == first)
+        .any(|(i, _)| &haystack[i + 1..i + needle.len()] == rest)
+}
+
+/// Returns whether each element of `array` starts with `prefix`, e.g. to detect magic bytes.
+/// Null elements of `array` are null in the result.
+pub fn starts_with<O: Offset>(array: &BinaryArray<O>, prefix: &[u8]) -> BooleanArray {
+    compare_op_scalar(array, prefix, |x, prefix| x.starts_with(prefix))
+}
+
+/// Returns whether each element of `lhs` starts with the corresponding element of `rhs`.
+/// A null slot in either `lhs` or `rhs` results in a null slot.
+/// # Panic
+/// iff the arrays do not have the same length.
+pub fn starts_with_array<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |lhs, rhs| lhs.starts_with(rhs))
+}
+
+/// Returns whether each element of `array` ends with `suffix`.
+/// Null elements of `array` are null in the result.
+pub fn ends_with<O: Offset>(array: &BinaryArray<O>, suffix: &[u8]) -> BooleanArray {
+    compare_op_scalar(array, suffix, |x, suffix| x.ends_with(suffix))
+}
+
+/// Returns whether each element of `lhs` ends with the corresponding element of `rhs`.
+/// A null slot in either `lhs` or `rhs` results in a null slot.
+/// # Panic
+/// iff the arrays do not have the same length.
+pub fn ends_with_array<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |lhs, rhs| lhs.ends_with(rhs))
+}
+
+/// Returns whether each element of `array` contains `needle` as a contiguous subsequence.
+/// Null elements of `array` are null in the result.
+pub fn contains<O: Offset>(array: &BinaryArray<O>, needle: &[u8]) -> BooleanArray {
+    compare_op_scalar(array, needle, contains_bytes)
+}
+
+/// Returns whether each element of `lhs` contains the corresponding element of `rhs` as a
+/// contiguous subsequence.
+/// A null slot in either `lhs` or `rhs` results in a null slot.
+/// # Panic
+/// iff the arrays do not have the same length.
+pub fn contains_array<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
+    compare_op(lhs, rhs, contains_bytes)
+}
+
+fn is_binary(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Binary | PhysicalType::LargeBinary
+    )
+}
+
+/// Returns whether [`starts_with`] and [`starts_with_array`] support arrays of `data_type`.
+pub fn can_starts_with(data_type: &DataType) -> bool {
+    is_binary(data_type)
+}
+
+/// Returns whether [`ends_with`] and [`ends_with_array`] support arrays of `data_type`.
+pub fn can_ends_with(data_type: &DataType) -> bool {
+    is_binary(data_type)
+}
+
+/// Returns whether [`contains`] and [`contains_array`] support arrays of `data_type`.
+pub fn can_contains(data_type: &DataType) -> bool {
+    is_binary(data_type)
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each pair of elements of `lhs` and `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn compare<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> PrimitiveArray<i8> {
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| lhs.cmp(rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), validity)
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each element of `lhs` with `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// Null elements of `lhs` are null in the result.
+pub fn compare_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> PrimitiveArray<i8> {
+    let values = lhs
+        .values_iter()
+        .map(|lhs| lhs.cmp(rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), lhs.validity().cloned())
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the smallest of the two.
+/// Ties are resolved in favour of `lhs`.
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn min<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BinaryArray<O> {
+    select_op(lhs, rhs, |lhs, rhs| lhs <= rhs)
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the largest of the two.
+/// Ties are resolved in favour of `lhs`.
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn max<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BinaryArray<O> {
+    select_op(lhs, rhs, |lhs, rhs| lhs >= rhs)
+}
+
+/// Returns, for each element of `lhs`, the smallest of it and `rhs`.
+/// Null elements of `lhs` are null in the result.
+pub fn min_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BinaryArray<O> {
+    select_op_scalar(lhs, rhs, |lhs, rhs| lhs <= rhs)
+}
+
+/// Returns, for each element of `lhs`, the largest of it and `rhs`.
+/// Null elements of `lhs` are null in the result.
+pub fn max_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BinaryArray<O> {
+    select_op_scalar(lhs, rhs, |lhs, rhs| lhs >= rhs)
+}
+
+/// Selects the element of `lhs` when `op(lhs, rhs)` is true and of `rhs` otherwise.
+fn select_op<O, F>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>, op: F) -> BinaryArray<O>
+where
+    O: Offset,
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| if op(lhs, rhs) { lhs } else { rhs });
+    let array = BinaryArray::<O>::from_trusted_len_values_iter(values);
+    BinaryArray::<O>::new(
+        lhs.data_type().clone(),
+        array.offsets().clone(),
+        array.values().clone(),
+        validity,
+    )
+}
+
+/// Selects the element of `lhs` when `op(lhs, rhs)` is true and `rhs` otherwise.
+fn select_op_scalar<O, F>(lhs: &BinaryArray<O>, rhs: &[u8], op: F) -> BinaryArray<O>
+where
+    O: Offset,
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    let values = lhs
+        .values_iter()
+        .map(|lhs| if op(lhs, rhs) { lhs } else { rhs });
+    let array = BinaryArray::<O>::from_trusted_len_values_iter(values);
+    BinaryArray::<O>::new(
+        lhs.data_type().clone(),
+        array.offsets().clone(),
+        array.values().clone(),
+        lhs.validity().cloned(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +397,71 @@ mod tests {
         assert_eq!(op(&lhs, rhs), expected);
     }
 
+    #[test]
+    fn test_starts_with() {
+        let array = BinaryArray::<i32>::from([
+            Some(&b"\x89PNG\r\n"[..]),
+            Some(b"GIF89a"),
+            None,
+            Some(b"\x89P"),
+            Some(b""),
+        ]);
+        let expected =
+            BooleanArray::from([Some(true), Some(false), None, Some(false), Some(false)]);
+        assert_eq!(starts_with(&array, b"\x89PNG"), expected);
+
+        // every element starts with the empty prefix
+        let expected = BooleanArray::from([Some(true), Some(true), None, Some(true), Some(true)]);
+        assert_eq!(starts_with(&array, b""), expected);
+
+        test_generic::<i64, _>(
+            vec![b"arrow", b"arrow", b"ar"],
+            vec![b"ar", b"row", b"arrow"],
+            starts_with_array,
+            vec![true, false, false],
+        );
+    }
+
+    #[test]
+    fn test_ends_with() {
+        test_generic_scalar::<i32, _>(
+            vec![b"arrow", b"row", b"ow", b"rows"],
+            b"row",
+            ends_with,
+            vec![true, true, false, false],
+        );
+        test_generic::<i64, _>(
+            vec![b"arrow", b"arrow", b"ow"],
+            vec![b"ow", b"arr", b"arrow"],
+            ends_with_array,
+            vec![true, false, false],
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        test_generic_scalar::<i32, _>(
+            vec![b"arrow", b"rrrow", b"arro", b"", b"rr"],
+            b"rro",
+            contains,
+            vec![true, true, true, false, false],
+        );
+        test_generic_scalar::<i32, _>(vec![b"arrow", b""], b"", contains, vec![true, true]);
+
+        let lhs = BinaryArray::<i64>::from([Some(&b"\x00\x01\x02"[..]), Some(b"\x00\x01"), None]);
+        let rhs = BinaryArray::<i64>::from([Some(&b"\x01\x02"[..]), None, Some(b"")]);
+        let expected = BooleanArray::from([Some(true), None, None]);
+        assert_eq!(contains_array(&lhs, &rhs), expected);
+    }
+
+    #[test]
+    fn test_can_starts_with() {
+        assert!(can_starts_with(&DataType::Binary));
+        assert!(can_ends_with(&DataType::LargeBinary));
+        assert!(can_contains(&DataType::Binary));
+        assert!(!can_contains(&DataType::Utf8));
+    }
+
     #[test]
     fn test_gt_eq() {
         test_generic::<i32, _>(
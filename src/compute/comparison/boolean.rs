@@ -1,8 +1,8 @@
 //! Comparison functions for [`BooleanArray`]
 use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
 use crate::{
-    array::BooleanArray,
-    bitmap::{binary, unary, Bitmap},
+    array::{BooleanArray, PrimitiveArray},
+    bitmap::{binary, ternary, unary, Bitmap},
     datatypes::DataType,
 };
 
@@ -51,11 +51,14 @@ pub fn eq_and_validity(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
 }
 
 /// Perform `lhs == rhs` operation on a [`BooleanArray`] and a scalar value.
+///
+/// This is `lhs` itself when `rhs` is `true` and its bitwise negation (a single pass over
+/// the words of its values) when `rhs` is `false`. The validity of `lhs` is kept.
 pub fn eq_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
     if rhs {
         lhs.clone()
     } else {
-        compare_op_scalar(lhs, rhs, |a, _| !a)
+        BooleanArray::new(DataType::Boolean, !lhs.values(), lhs.validity().cloned())
     }
 }
 
@@ -63,15 +66,8 @@ pub fn eq_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
 pub fn eq_scalar_and_validity(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
     let validity = lhs.validity().cloned();
     let lhs = lhs.with_validity(None);
-    if rhs {
-        finish_eq_validities(lhs, validity, None)
-    } else {
-        let lhs = lhs.with_validity(None);
-
-        let out = compare_op_scalar(&lhs, rhs, |a, _| !a);
-
-        finish_eq_validities(out, validity, None)
-    }
+    let out = eq_scalar(&lhs, rhs);
+    finish_eq_validities(out, validity, None)
 }
 
 /// `lhs != rhs` for [`BooleanArray`]
@@ -91,6 +87,8 @@ pub fn neq_and_validity(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray
 }
 
 /// Perform `left != right` operation on an array and a scalar value.
+///
+/// As [`eq_scalar`], this is either `lhs` itself or its bitwise negation.
 pub fn neq_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
     eq_scalar(lhs, !rhs)
 }
@@ -103,6 +101,24 @@ pub fn neq_scalar_and_validity(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
     finish_neq_validities(out, validity, None)
 }
 
+/// Perform `lhs XOR rhs` operation on two [`BooleanArray`]s: a slot is true when exactly one
+/// of `lhs` and `rhs` is true. A null slot in either `lhs` or `rhs` results in a null slot.
+///
+/// This is the same as [`neq`], named after the logical operation.
+pub fn xor(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a ^ b)
+}
+
+/// Perform `lhs XOR rhs` operation on a [`BooleanArray`] and a scalar value.
+/// A null slot in `lhs` results in a null slot.
+pub fn xor_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
+    if rhs {
+        compare_op_scalar(lhs, rhs, |a, _| !a)
+    } else {
+        lhs.clone()
+    }
+}
+
 /// Perform `left < right` operation on two arrays.
 pub fn lt(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
     compare_op(lhs, rhs, |a, b| !a & b)
@@ -173,3 +189,54 @@ pub fn gt_eq_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
         compare_op_scalar(lhs, rhs, |_, _| all_ones)
     }
 }
+
+/// Perform `array >= low AND array <= high` operation on three [`BooleanArray`]s
+/// in a single pass. A null slot in any of the arrays results in a null slot.
+/// # Panics
+/// iff the arrays do not have the same length.
+pub fn between(array: &BooleanArray, low: &BooleanArray, high: &BooleanArray) -> BooleanArray {
+    let validity = combine_validities(array.validity(), low.validity());
+    let validity = combine_validities(validity.as_ref(), high.validity());
+
+    let values = ternary(
+        array.values(),
+        low.values(),
+        high.values(),
+        |a, low, high| (a | !low) & (!a | high),
+    );
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `array >= low AND array <= high` operation on a [`BooleanArray`] and two scalars
+/// in a single pass.
+pub fn between_scalar(array: &BooleanArray, low: bool, high: bool) -> BooleanArray {
+    let high = if high { !0 } else { 0 };
+    compare_op_scalar(array, low, |a, low| (a | !low) & (!a | high))
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each pair of elements of `lhs` and `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn compare(lhs: &BooleanArray, rhs: &BooleanArray) -> PrimitiveArray<i8> {
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| lhs.cmp(&rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), validity)
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each element of `lhs` with `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// Null elements of `lhs` are null in the result.
+pub fn compare_scalar(lhs: &BooleanArray, rhs: bool) -> PrimitiveArray<i8> {
+    let values = lhs
+        .values_iter()
+        .map(|lhs| lhs.cmp(&rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), lhs.validity().cloned())
+}
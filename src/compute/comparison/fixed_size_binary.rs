@@ -0,0 +1,269 @@
+//! Comparison functions for [`FixedSizeBinaryArray`]
+use std::collections::HashSet;
+
+use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
+use crate::{
+    array::{BooleanArray, FixedSizeBinaryArray, PrimitiveArray},
+    bitmap::Bitmap,
+    datatypes::DataType,
+};
+
+use super::super::utils::combine_validities;
+
+/// Evaluate `op(lhs, rhs)` for [`FixedSizeBinaryArray`]s using a specified
+/// comparison function.
+fn compare_op<F>(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray, op: F) -> BooleanArray
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .iter_values()
+        .zip(rhs.iter_values())
+        .map(|(lhs, rhs)| op(lhs, rhs));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Evaluate `op(lhs, rhs)` for [`FixedSizeBinaryArray`] and scalar using
+/// a specified comparison function.
+fn compare_op_scalar<F>(lhs: &FixedSizeBinaryArray, rhs: &[u8], op: F) -> BooleanArray
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    let validity = lhs.validity().cloned();
+
+    let values = lhs.iter_values().map(|lhs| op(lhs, rhs));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `lhs == rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length.
+pub fn eq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a == b)
+}
+
+/// Perform `lhs == rhs` operation on [`FixedSizeBinaryArray`] and include validities in comparison.
+/// # Panic
+/// iff the arrays do not have the same length.
+pub fn eq_and_validity(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    let validity_lhs = lhs.validity().cloned();
+    let validity_rhs = rhs.validity().cloned();
+    let lhs = lhs.with_validity(None);
+    let rhs = rhs.with_validity(None);
+    let out = compare_op(&lhs, &rhs, |a, b| a == b);
+
+    finish_eq_validities(out, validity_lhs, validity_rhs)
+}
+
+/// Perform `lhs == rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn eq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a == b)
+}
+
+/// Perform `lhs == rhs` operation on [`FixedSizeBinaryArray`] and a scalar and include validities in comparison.
+pub fn eq_scalar_and_validity(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    let validity = lhs.validity().cloned();
+    let lhs = lhs.with_validity(None);
+    let out = compare_op_scalar(&lhs, rhs, |a, b| a == b);
+
+    finish_eq_validities(out, validity, None)
+}
+
+/// Perform `lhs != rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length.
+pub fn neq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a != b)
+}
+
+/// Perform `lhs != rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length and include validities in comparison.
+pub fn neq_and_validity(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    let validity_lhs = lhs.validity().cloned();
+    let validity_rhs = rhs.validity().cloned();
+    let lhs = lhs.with_validity(None);
+    let rhs = rhs.with_validity(None);
+
+    let out = compare_op(&lhs, &rhs, |a, b| a != b);
+    finish_neq_validities(out, validity_lhs, validity_rhs)
+}
+
+/// Perform `lhs != rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn neq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a != b)
+}
+
+/// Perform `lhs != rhs` operation on [`FixedSizeBinaryArray`] and a scalar and include validities in comparison.
+pub fn neq_scalar_and_validity(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    let validity = lhs.validity().cloned();
+    let lhs = lhs.with_validity(None);
+    let out = compare_op_scalar(&lhs, rhs, |a, b| a != b);
+
+    finish_neq_validities(out, validity, None)
+}
+
+/// Perform `lhs < rhs` operation on [`FixedSizeBinaryArray`].
+///
+/// Values are ordered lexicographically by their bytes.
+pub fn lt(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a < b)
+}
+
+/// Perform `lhs < rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn lt_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a < b)
+}
+
+/// Perform `lhs <= rhs` operation on [`FixedSizeBinaryArray`].
+pub fn lt_eq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a <= b)
+}
+
+/// Perform `lhs <= rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn lt_eq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a <= b)
+}
+
+/// Perform `lhs > rhs` operation on [`FixedSizeBinaryArray`].
+pub fn gt(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a > b)
+}
+
+/// Perform `lhs > rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn gt_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a > b)
+}
+
+/// Perform `lhs >= rhs` operation on [`FixedSizeBinaryArray`].
+pub fn gt_eq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a >= b)
+}
+
+/// Perform `lhs >= rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn gt_eq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a >= b)
+}
+
+/// Perform `array >= low AND array <= high` operation on three [`FixedSizeBinaryArray`]s in a single pass.
+/// A null slot in any of the arrays results in a null slot.
+/// # Panics
+/// iff the arrays do not have the same length.
+pub fn between(
+    array: &FixedSizeBinaryArray,
+    low: &FixedSizeBinaryArray,
+    high: &FixedSizeBinaryArray,
+) -> BooleanArray {
+    assert_eq!(array.len(), low.len());
+    assert_eq!(array.len(), high.len());
+    let validity = combine_validities(array.validity(), low.validity());
+    let validity = combine_validities(validity.as_ref(), high.validity());
+
+    let values = array
+        .iter_values()
+        .zip(low.iter_values())
+        .zip(high.iter_values())
+        .map(|((a, low), high)| low <= a && a <= high);
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `array >= low AND array <= high` operation on [`FixedSizeBinaryArray`] and two scalars in a single
+/// pass.
+pub fn between_scalar(array: &FixedSizeBinaryArray, low: &[u8], high: &[u8]) -> BooleanArray {
+    compare_op_scalar(array, low, |a, low| low <= a && a <= high)
+}
+
+/// Returns whether each element of `array` is one of the (non-null) elements of `values`.
+/// Null elements of `array` are null in the result.
+pub fn is_in(array: &FixedSizeBinaryArray, values: &FixedSizeBinaryArray) -> BooleanArray {
+    let values = if values.len() <= super::IS_IN_MAX_LINEAR_SCAN {
+        let values = values.iter().flatten().collect::<Vec<_>>();
+        Bitmap::from_trusted_len_iter(array.iter_values().map(|x| values.contains(&x)))
+    } else {
+        let values = values.iter().flatten().collect::<HashSet<_>>();
+        Bitmap::from_trusted_len_iter(array.iter_values().map(|x| values.contains(x)))
+    };
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each pair of elements of `lhs` and `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn compare(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> PrimitiveArray<i8> {
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .iter_values()
+        .zip(rhs.iter_values())
+        .map(|(lhs, rhs)| lhs.cmp(rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), validity)
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each element of `lhs` with `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// Null elements of `lhs` are null in the result.
+pub fn compare_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> PrimitiveArray<i8> {
+    let values = lhs
+        .iter_values()
+        .map(|lhs| lhs.cmp(rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), lhs.validity().cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        let lhs = FixedSizeBinaryArray::from_slice([*b"aa", *b"ab", *b"ba"]);
+        let rhs = FixedSizeBinaryArray::from_slice([*b"aa", *b"ba", *b"ab"]);
+        assert_eq!(
+            eq(&lhs, &rhs),
+            BooleanArray::from_slice([true, false, false])
+        );
+        assert_eq!(
+            neq(&lhs, &rhs),
+            BooleanArray::from_slice([false, true, true])
+        );
+    }
+
+    #[test]
+    fn test_lt() {
+        let lhs = FixedSizeBinaryArray::from_slice([*b"aa", *b"ab", *b"ba"]);
+        let rhs = FixedSizeBinaryArray::from_slice([*b"aa", *b"ba", *b"ab"]);
+        assert_eq!(
+            lt(&lhs, &rhs),
+            BooleanArray::from_slice([false, true, false])
+        );
+        assert_eq!(
+            gt_eq(&lhs, &rhs),
+            BooleanArray::from_slice([true, false, true])
+        );
+    }
+
+    #[test]
+    fn test_lt_scalar() {
+        let lhs = FixedSizeBinaryArray::from_slice([*b"aa", *b"ab", *b"ba"]);
+        assert_eq!(
+            lt_scalar(&lhs, b"ab"),
+            BooleanArray::from_slice([true, false, false])
+        );
+        assert_eq!(
+            eq_scalar(&lhs, b"ab"),
+            BooleanArray::from_slice([false, true, false])
+        );
+    }
+}
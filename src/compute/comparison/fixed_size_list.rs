@@ -0,0 +1,87 @@
+//! Comparison functions for [`FixedSizeListArray`]
+use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
+use crate::{
+    array::{Array, BooleanArray, FixedSizeListArray},
+    bitmap::MutableBitmap,
+    compute,
+    datatypes::DataType,
+};
+
+use super::super::utils::combine_validities;
+
+/// Compares each pair of lists of `lhs` and `rhs`: two lists are equal when `op` is true for
+/// every pair of elements. Since all lists have the same size, their values are compared at once.
+/// When `op` is null for any pair of elements but false for none, the slot is null.
+/// The validities of `lhs` and `rhs` are not considered.
+fn compare_lists<F>(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray, op: F) -> BooleanArray
+where
+    F: Fn(&dyn Array, &dyn Array) -> BooleanArray,
+{
+    assert_eq!(
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type()
+    );
+    assert_eq!(lhs.len(), rhs.len());
+    let size = FixedSizeListArray::get_child_and_size(lhs.data_type()).1;
+
+    let elements = op(lhs.values().as_ref(), rhs.values().as_ref());
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    let mut validity = MutableBitmap::with_capacity(lhs.len());
+    (0..lhs.len()).for_each(|index| {
+        let mut is_valid = true;
+        let mut is_equal = true;
+        for i in index * size..(index + 1) * size {
+            if !elements.is_valid(i) {
+                is_valid = false;
+            } else if !elements.value(i) {
+                is_equal = false;
+                break;
+            }
+        }
+        values.push(is_equal);
+        validity.push(is_valid || !is_equal);
+    });
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity.into())
+}
+
+/// Perform `lhs == rhs` operation on two [`FixedSizeListArray`]s.
+///
+/// Two slots are equal when all their elements are equal.
+/// A null slot in either `lhs` or `rhs` results in a null slot. A null element results in a
+/// null slot unless another element is different.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> BooleanArray {
+    let out = compare_lists(lhs, rhs, super::eq);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(out.validity(), validity.as_ref());
+    out.with_validity(validity)
+}
+
+/// Perform `lhs == rhs` operation on two [`FixedSizeListArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq_and_validity(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> BooleanArray {
+    let out = compare_lists(lhs, rhs, super::eq_and_validity);
+    finish_eq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Perform `lhs != rhs` operation on two [`FixedSizeListArray`]s.
+///
+/// This is the negation of [`eq`], including its handling of nulls.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> BooleanArray {
+    compute::boolean::not(&eq(lhs, rhs))
+}
+
+/// Perform `lhs != rhs` operation on two [`FixedSizeListArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq_and_validity(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> BooleanArray {
+    let out = compare_lists(lhs, rhs, super::eq_and_validity);
+    let out = compute::boolean::not(&out);
+    finish_neq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
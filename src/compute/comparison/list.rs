@@ -0,0 +1,124 @@
+//! Comparison functions for [`ListArray`]
+use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
+use crate::{
+    array::{Array, BooleanArray, ListArray, PrimitiveArray},
+    bitmap::MutableBitmap,
+    compute,
+    datatypes::DataType,
+    types::Offset,
+};
+
+use super::super::utils::combine_validities;
+
+/// Compares each pair of lists of `lhs` and `rhs`: two lists are equal when they have the same
+/// length and `op` is true for every pair of elements.
+/// When the lengths are equal and `op` is null for any pair of elements but false for none,
+/// the slot is null. The validities of `lhs` and `rhs` are not considered.
+fn compare_lists<O, F>(lhs: &ListArray<O>, rhs: &ListArray<O>, op: F) -> BooleanArray
+where
+    O: Offset,
+    F: Fn(&dyn Array, &dyn Array) -> BooleanArray,
+{
+    assert_eq!(
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type()
+    );
+    assert_eq!(lhs.len(), rhs.len());
+
+    // gather the elements of the lists with equal lengths so that they are compared at once.
+    let mut lhs_indices = vec![];
+    let mut rhs_indices = vec![];
+    lhs.offsets()
+        .windows(2)
+        .zip(rhs.offsets().windows(2))
+        .for_each(|(lhs, rhs)| {
+            let length = lhs[1] - lhs[0];
+            if length == rhs[1] - rhs[0] {
+                let length = length.to_usize();
+                lhs_indices.extend((0..length).map(|i| lhs[0] + O::from_usize(i).unwrap()));
+                rhs_indices.extend((0..length).map(|i| rhs[0] + O::from_usize(i).unwrap()));
+            }
+        });
+    let lhs_values = compute::take::take(
+        lhs.values().as_ref(),
+        &PrimitiveArray::<O>::from_vec(lhs_indices),
+    )
+    .unwrap();
+    let rhs_values = compute::take::take(
+        rhs.values().as_ref(),
+        &PrimitiveArray::<O>::from_vec(rhs_indices),
+    )
+    .unwrap();
+    let elements = op(lhs_values.as_ref(), rhs_values.as_ref());
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    let mut validity = MutableBitmap::with_capacity(lhs.len());
+    let mut start = 0;
+    lhs.offsets()
+        .windows(2)
+        .zip(rhs.offsets().windows(2))
+        .for_each(|(lhs, rhs)| {
+            let length = lhs[1] - lhs[0];
+            if length == rhs[1] - rhs[0] {
+                let end = start + length.to_usize();
+                let mut is_valid = true;
+                let mut is_equal = true;
+                for i in start..end {
+                    if !elements.is_valid(i) {
+                        is_valid = false;
+                    } else if !elements.value(i) {
+                        is_equal = false;
+                        break;
+                    }
+                }
+                values.push(is_equal);
+                validity.push(is_valid || !is_equal);
+                start = end;
+            } else {
+                values.push(false);
+                validity.push(true);
+            }
+        });
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity.into())
+}
+
+/// Perform `lhs == rhs` operation on two [`ListArray`]s.
+///
+/// Two slots are equal when their lists have the same length and all their elements are equal.
+/// A null slot in either `lhs` or `rhs` results in a null slot. When the lists have the same
+/// length, a null element results in a null slot unless another element is different.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>) -> BooleanArray {
+    let out = compare_lists(lhs, rhs, super::eq);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(out.validity(), validity.as_ref());
+    out.with_validity(validity)
+}
+
+/// Perform `lhs == rhs` operation on two [`ListArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq_and_validity<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>) -> BooleanArray {
+    let out = compare_lists(lhs, rhs, super::eq_and_validity);
+    finish_eq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Perform `lhs != rhs` operation on two [`ListArray`]s.
+///
+/// This is the negation of [`eq`], including its handling of nulls.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>) -> BooleanArray {
+    compute::boolean::not(&eq(lhs, rhs))
+}
+
+/// Perform `lhs != rhs` operation on two [`ListArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq_and_validity<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>) -> BooleanArray {
+    let out = compare_lists(lhs, rhs, super::eq_and_validity);
+    let out = compute::boolean::not(&out);
+    finish_neq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
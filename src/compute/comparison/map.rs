@@ -0,0 +1,55 @@
+//! Comparison functions for [`MapArray`]
+//!
+//! Maps are compared as the lists of their entries (see [`list`]): two maps are
+//! equal when they have the same entries (keys and values) *in the same order*.
+//! Order-insensitive equality (e.g. `{a: 1, b: 2} == {b: 2, a: 1}`) is not yet supported.
+use crate::{
+    array::{Array, BooleanArray, ListArray, MapArray},
+    datatypes::DataType,
+};
+
+use super::list;
+
+/// Returns `array` as the [`ListArray`] of its entries.
+fn as_list(array: &MapArray) -> ListArray<i32> {
+    let field = MapArray::get_field(array.data_type()).clone();
+    ListArray::new(
+        DataType::List(Box::new(field)),
+        array.offsets().clone(),
+        array.field().clone(),
+        array.validity().cloned(),
+    )
+}
+
+/// Perform `lhs == rhs` operation on two [`MapArray`]s.
+///
+/// Two slots are equal when their maps have the same entries in the same order.
+/// Nulls are handled as per [`list::eq`].
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq(lhs: &MapArray, rhs: &MapArray) -> BooleanArray {
+    list::eq(&as_list(lhs), &as_list(rhs))
+}
+
+/// Perform `lhs == rhs` operation on two [`MapArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq_and_validity(lhs: &MapArray, rhs: &MapArray) -> BooleanArray {
+    list::eq_and_validity(&as_list(lhs), &as_list(rhs))
+}
+
+/// Perform `lhs != rhs` operation on two [`MapArray`]s.
+///
+/// This is the negation of [`eq`], including its handling of nulls.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq(lhs: &MapArray, rhs: &MapArray) -> BooleanArray {
+    list::neq(&as_list(lhs), &as_list(rhs))
+}
+
+/// Perform `lhs != rhs` operation on two [`MapArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq_and_validity(lhs: &MapArray, rhs: &MapArray) -> BooleanArray {
+    list::neq_and_validity(&as_list(lhs), &as_list(rhs))
+}
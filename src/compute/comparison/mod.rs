@@ -10,6 +10,19 @@
 //! The statically typed are available under each module of this module (e.g. [`primitive::eq`], [`primitive::lt_scalar`])
 //! The dynamically typed are available in this module (e.g. [`eq`] or [`lt_scalar`]).
 //!
+//! The dynamically typed functions between two [`Array`]s also compare decimals of
+//! different scales (e.g. `1.0` with scale 1 equals `1.00` with scale 2).
+//! A [`NullArray`] can be compared with an [`Array`] of any type, resulting in nulls.
+//! [`MapArray`]s are compared as the lists of their entries, i.e. order-sensitive (see [`map`]).
+//!
+//! # Broadcasting
+//!
+//! [`eq`], [`neq`], [`lt`], [`lt_eq`], [`gt`] and [`gt_eq`] broadcast an [`Array`] of length 1
+//! compared with an [`Array`] of any other length: its single value is compared with every
+//! element of the other array, as a [`Scalar`] would be (e.g. via [`eq_scalar`]), and the
+//! result has the length of the other array. Any other pair of different lengths panics, or errors
+//! for their `try_` versions (e.g. [`try_eq`]).
+//!
 //! # Examples
 //!
 //! Compare two [`PrimitiveArray`]s:
@@ -43,40 +56,65 @@
 //! let result = neq_scalar(&array, "compare");
 //! assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
 //! ```
+//!
+//! Check which slots of an [`Array`] are null (`IS NULL`), for any [`DataType`]:
+//! ```
+//! use arrow2::array::{BooleanArray, Utf8Array};
+//! use arrow2::compute::comparison::is_null;
+//!
+//! let array = Utf8Array::<i32>::from([Some("compute"), None, Some("compare")]);
+//! let result = is_null(&array);
+//! assert_eq!(result, BooleanArray::from_slice([false, true, false]));
+//! ```
 
 use crate::array::*;
-use crate::datatypes::{DataType, IntervalUnit};
+use crate::datatypes::DataType;
 use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod fixed_size_binary;
+pub mod fixed_size_list;
+pub mod list;
+pub mod map;
 pub mod primitive;
+pub mod struct_;
 pub mod utf8;
 
-mod simd;
+pub use struct_::eq_fields;
+
+mod rank;
+pub use rank::{rank, RankMethod};
+
+pub mod simd;
 pub use simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
+pub use super::boolean::{is_not_null, is_null};
 use super::take::take_boolean;
-use crate::bitmap::Bitmap;
+use crate::bitmap::{binary, ternary, Bitmap};
 use crate::compute;
+use crate::error::{ArrowError, Result};
 pub(crate) use primitive::{
     compare_values_op as primitive_compare_values_op,
     compare_values_op_scalar as primitive_compare_values_op_scalar,
 };
 
+// `Decimal`s of any precision supported (up to 38 digits) are backed by `i128` (`Int128`).
+// A 256-bit decimal requires its own arm once its backing integer is a `NativeType`.
 macro_rules! match_eq_ord {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
+    use crate::types::{days_ms, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
-        DaysMs => todo!(),
-        MonthDayNano => todo!(),
+        DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
@@ -113,12 +151,30 @@ macro_rules! compare {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
         let lhs = $lhs;
         let rhs = $rhs;
+        if let Some(result) = compare_nulls(lhs, rhs) {
+            return result;
+        }
+
+        use crate::datatypes::PhysicalType::*;
+        // dictionaries are compared by their (logical) values, irrespectively of their keys
+        if let (Dictionary(_), Dictionary(_)) = (
+            lhs.data_type().to_physical_type(),
+            rhs.data_type().to_physical_type(),
+        ) {
+            let lhs = dictionary_values(lhs);
+            let rhs = dictionary_values(rhs);
+            return $op(lhs.as_ref(), rhs.as_ref());
+        }
+        // decimals of different scales are compared at a common scale
+        if let Some((lhs, rhs)) = rescale_decimals(lhs, rhs) {
+            return $op(&lhs, &rhs);
+        }
+
         assert_eq!(
             lhs.data_type().to_logical_type(),
             rhs.data_type().to_logical_type()
         );
 
-        use crate::datatypes::PhysicalType::*;
         match lhs.data_type().to_physical_type() {
             Boolean => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
@@ -150,6 +206,11 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
+            FixedSizeBinary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                fixed_size_binary::$op(lhs, rhs)
+            }
             _ => todo!(
                 "Comparison between {:?} are not yet supported",
                 lhs.data_type()
@@ -158,190 +219,1828 @@ macro_rules! compare {
     }};
 }
 
+/// Returns an all-null result when either `lhs` or `rhs` is a [`NullArray`]: all its slots are
+/// null and so is any comparison with them.
+/// # Panic
+/// Panics iff the arrays do not have the same length.
+fn compare_nulls(lhs: &dyn Array, rhs: &dyn Array) -> Option<BooleanArray> {
+    if lhs.data_type().to_logical_type() != &DataType::Null
+        && rhs.data_type().to_logical_type() != &DataType::Null
+    {
+        return None;
+    }
+    assert_eq!(lhs.len(), rhs.len());
+    Some(BooleanArray::new_null(DataType::Boolean, lhs.len()))
+}
+
+/// When either `lhs` or `rhs` is a [`NullArray`], returns the other one: comparisons that
+/// include validities then only depend on which of its slots are null.
+/// # Panic
+/// Panics iff the arrays do not have the same length.
+fn other_than_nulls<'a>(lhs: &'a dyn Array, rhs: &'a dyn Array) -> Option<&'a dyn Array> {
+    let other = if lhs.data_type().to_logical_type() == &DataType::Null {
+        rhs
+    } else if rhs.data_type().to_logical_type() == &DataType::Null {
+        lhs
+    } else {
+        return None;
+    };
+    assert_eq!(lhs.len(), rhs.len());
+    Some(other)
+}
+
+/// Returns an error iff a (non-null) key of `array` is out of range of its values, e.g. because
+/// the dictionary is corrupt.
+fn check_dictionary_keys<K: DictionaryKey>(array: &DictionaryArray<K>) -> Result<()> {
+    let length = array.values().len();
+    let invalid = array
+        .keys()
+        .iter()
+        .flatten()
+        .find(|key| key.to_usize().map_or(true, |key| key >= length));
+    match invalid {
+        Some(key) => Err(ArrowError::OutOfSpec(format!(
+            "The dictionary key {:?} is out of range of its {} values",
+            key, length
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns `op(lhs, rhs)` computed from the keys of `lhs`, given `values`, the result of `op`
+/// on the values of `lhs`, without gathering `values` by the keys, or `None` when `lhs` is not
+/// an ordered dictionary, `values` has nulls or its `true` slots are not a prefix
+/// (`is_prefix`) or a suffix of it.
+///
+/// The values of an ordered dictionary are expected to be sorted (see [`DataType::Dictionary`]),
+/// so that the values for which the ordering operation `op` is `true` are either a prefix of
+/// them (`is_prefix`, e.g. for `<`) or a suffix (e.g. for `>`), and each key is compared with
+/// its boundary. Since this is verified on `values`, unsorted values are still compared
+/// correctly, via the gather.
+/// The keys must be in range of the values (see [`check_dictionary_keys`]).
+fn ordered_dictionary_op_scalar<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    values: &BooleanArray,
+    is_prefix: bool,
+) -> Option<BooleanArray> {
+    if !matches!(
+        lhs.data_type().to_logical_type(),
+        DataType::Dictionary(_, _, true)
+    ) || values.null_count() > 0
+    {
+        return None;
+    }
+
+    // the number of values in the prefix for which `op` is `is_prefix`
+    let low = values
+        .values()
+        .iter()
+        .take_while(|value| *value == is_prefix)
+        .count();
+    let matching = if is_prefix {
+        values.len() - values.values().null_count()
+    } else {
+        values.values().null_count()
+    };
+    if matching != low {
+        return None;
+    }
+
+    let keys = lhs.keys();
+    let result = keys.values().iter().map(|key| match key.to_usize() {
+        Some(key) => (key < low) == is_prefix,
+        // only null keys are out of range
+        None => false,
+    });
+    let result = Bitmap::from_trusted_len_iter(result);
+    Some(BooleanArray::new(
+        DataType::Boolean,
+        result,
+        keys.validity().cloned(),
+    ))
+}
+
+/// Returns an error iff `array` is a [`DictionaryArray`] with keys out of range of its values.
+fn check_keys(array: &dyn Array) -> Result<()> {
+    match array.data_type().to_physical_type() {
+        crate::datatypes::PhysicalType::Dictionary(key_type) => {
+            match_integer_type!(key_type, |$T| {
+                check_dictionary_keys(array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap())
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the values of a [`DictionaryArray`] gathered by its keys, i.e. its logical values.
+fn dictionary_values(array: &dyn Array) -> Box<dyn Array> {
+    match array.data_type().to_physical_type() {
+        crate::datatypes::PhysicalType::Dictionary(key_type) => {
+            match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                compute::take::take(array.values().as_ref(), array.keys()).unwrap()
+            })
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Returns `lhs` and `rhs` rescaled to the largest of their scales when they are decimals of
+/// different scales, so that they can be compared.
+///
+/// Values that overflow `i128` when rescaled saturate to `i128::MIN` or `i128::MAX`, which
+/// preserves their order: decimals have at most 38 digits (`|x| < 10^38 < i128::MAX`),
+/// so a value that overflows is larger in magnitude than any value of the other array.
+fn rescale_decimals(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+) -> Option<(PrimitiveArray<i128>, PrimitiveArray<i128>)> {
+    let (lhs_scale, rhs_scale) = match (
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type(),
+    ) {
+        (DataType::Decimal(_, lhs_scale), DataType::Decimal(_, rhs_scale))
+            if lhs_scale != rhs_scale =>
+        {
+            (*lhs_scale, *rhs_scale)
+        }
+        _ => return None,
+    };
+    let scale = lhs_scale.max(rhs_scale);
+    let data_type = DataType::Decimal(38, scale);
+
+    let rescale = |array: &dyn Array, array_scale: usize| {
+        let array = array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i128>>()
+            .unwrap();
+        let factor = u32::try_from(scale - array_scale)
+            .ok()
+            .and_then(|exponent| 10i128.checked_pow(exponent));
+        let values = array
+            .values()
+            .iter()
+            .map(|x| match factor {
+                Some(factor) => x.saturating_mul(factor),
+                None => x.signum().saturating_mul(i128::MAX),
+            })
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(data_type.clone(), values.into(), array.validity().cloned())
+    };
+    Some((rescale(lhs, lhs_scale), rescale(rhs, rhs_scale)))
+}
+
+/// Same as `compare!` but also supports nested types whose equality is
+/// defined from the equality of their children.
+macro_rules! compare_eq {
+    ($lhs:expr, $rhs:expr, $op:tt) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        if let Some(result) = compare_nulls(lhs, rhs) {
+            return result;
+        }
+
+        use crate::datatypes::PhysicalType;
+        match lhs.data_type().to_physical_type() {
+            PhysicalType::Struct => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                struct_::$op(lhs, rhs)
+            }
+            PhysicalType::List => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                list::$op::<i32>(lhs, rhs)
+            }
+            PhysicalType::LargeList => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                list::$op::<i64>(lhs, rhs)
+            }
+            PhysicalType::FixedSizeList => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                fixed_size_list::$op(lhs, rhs)
+            }
+            PhysicalType::Map => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                map::$op(lhs, rhs)
+            }
+            _ => compare!(lhs, rhs, $op, match_eq),
+        }
+    }};
+}
+
+/// Returns `op(lhs, rhs)` when `rhs` has length 1 and `lhs` does not, and `op_lhs(lhs, rhs)` when
+/// `lhs` has length 1 and `rhs` does not, with the array of length 1 as a [`Scalar`], i.e.
+/// its single value is broadcast to the length of the other array.
+/// Returns `None` for any other lengths or when `can_op` is `false` for the data type.
+fn broadcast(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: fn(&dyn Array, &dyn Scalar) -> BooleanArray,
+    op_lhs: fn(&dyn Scalar, &dyn Array) -> BooleanArray,
+    can_op: fn(&DataType) -> bool,
+) -> Option<BooleanArray> {
+    match (lhs.len(), rhs.len()) {
+        (1, length) if length != 1 && can_op(lhs.data_type()) => {
+            Some(op_lhs(new_scalar(lhs, 0).as_ref(), rhs))
+        }
+        (length, 1) if length != 1 && can_op(rhs.data_type()) => {
+            Some(op(lhs, new_scalar(rhs, 0).as_ref()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether arrays of `lhs` and `rhs` are compared by the operations between two
+/// [`Array`]s: they have the same logical type, except that a [`NullArray`] is compared with any
+/// type, dictionaries by their values and decimals of different scales at a common scale.
+fn comparable_types(lhs: &DataType, rhs: &DataType) -> bool {
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (DataType::Null, _) | (_, DataType::Null) => true,
+        (DataType::Dictionary(_, lhs, _), DataType::Dictionary(_, rhs, _)) => {
+            comparable_types(lhs, rhs)
+        }
+        (DataType::Decimal(_, lhs_scale), DataType::Decimal(_, rhs_scale))
+            if lhs_scale != rhs_scale =>
+        {
+            true
+        }
+        (lhs, rhs) => lhs == rhs,
+    }
+}
+
+/// Returns an error iff `lhs` and `rhs` do not have the same logical type, for operations that
+/// do not rescale decimals nor compare with [`NullArray`]s.
+fn check_same_type(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
+    if lhs.data_type().to_logical_type() != rhs.data_type().to_logical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Comparison requires arrays of the same logical type, but got {:?} and {:?}",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns an error iff `lhs` and `rhs` can't be compared by an operation supported for the
+/// data types for which `can_op` is `true`. `can_op_scalar` is the one of its scalar version
+/// when the operation broadcasts arrays of length 1 (see [broadcasting](self#broadcasting)).
+fn check_arrays(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    can_op: fn(&DataType) -> bool,
+    can_op_scalar: Option<fn(&DataType) -> bool>,
+) -> Result<()> {
+    let is_broadcast = match (can_op_scalar, lhs.len(), rhs.len()) {
+        (Some(can_op_scalar), 1, length) => length != 1 && can_op_scalar(lhs.data_type()),
+        (Some(can_op_scalar), length, 1) => length != 1 && can_op_scalar(rhs.data_type()),
+        _ => false,
+    };
+    // a broadcast array is compared as a scalar, which requires the same logical type
+    let comparable = if is_broadcast {
+        let (lhs, rhs) = (
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type(),
+        );
+        lhs == rhs || lhs == &DataType::Null || rhs == &DataType::Null
+    } else {
+        comparable_types(lhs.data_type(), rhs.data_type())
+    };
+    if !comparable {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Comparison requires arrays of the same logical type, but got {:?} and {:?}",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    for data_type in [lhs.data_type(), rhs.data_type()] {
+        if !can_op(data_type.to_logical_type()) {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Comparison between {:?} is not yet supported",
+                data_type
+            )));
+        }
+    }
+    if !is_broadcast && lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Comparison requires arrays of the same length, but got {} and {}",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns an error iff `lhs` and `rhs` can't be compared by an operation supported for the
+/// data types for which `can_op` is `true`, or `lhs` is a [`DictionaryArray`] with keys out of
+/// range of its values.
+fn check_scalar(lhs: &dyn Array, rhs: &dyn Scalar, can_op: fn(&DataType) -> bool) -> Result<()> {
+    let (lhs_type, rhs_type) = (
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type(),
+    );
+    if lhs_type != rhs_type && lhs_type != &DataType::Null && rhs_type != &DataType::Null {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Comparison requires an array and a scalar of the same logical type, but got {:?} and {:?}",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    if !can_op(lhs_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Comparison between {:?} is not yet supported",
+            lhs.data_type()
+        )));
+    }
+    check_keys(lhs)
+}
+
 /// `==` between two [`Array`]s.
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
 /// * the arrays do not have have the same logical type
-/// * the arrays do not have the same length
+/// * the arrays do not have the same length and neither has length 1 (see [broadcasting](self#broadcasting))
 /// * the operation is not supported for the logical type
 pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq, match_eq)
+    try_eq(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
-/// `==` between two [`Array`]s and includes validities in comparison.
+/// `==` between two [`Array`]s, returning an error instead of panicking.
 /// Use [`can_eq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
-/// * the arrays do not have have the same logical type
-/// * the arrays do not have the same length
-/// * the operation is not supported for the logical type
-pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq_and_validity, match_eq)
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length and neither has length 1 (see
+///   [broadcasting](self#broadcasting)) ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_eq, Some(can_eq_scalar))?;
+    Ok(eq_unchecked(lhs, rhs))
 }
 
-/// Returns whether a [`DataType`] is comparable is supported by [`eq`].
-pub fn can_eq(data_type: &DataType) -> bool {
-    can_partial_eq(data_type)
+/// [`eq`] without checking its arguments.
+fn eq_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(result) = broadcast(lhs, rhs, eq_scalar, eq_scalar_lhs, can_eq_scalar) {
+        return result;
+    }
+    if let Some(result) = eq_identical(lhs, rhs) {
+        return result;
+    }
+    compare_eq!(lhs, rhs, eq)
 }
 
-/// `!=` between two [`Array`]s.
-/// Use [`can_neq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
-/// * the arrays do not have have the same logical type
-/// * the arrays do not have the same length
-/// * the operation is not supported for the logical type
-pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq, match_eq)
+/// Returns `eq(lhs, rhs)` without comparing values when `lhs` and `rhs` are non-float
+/// primitive arrays sharing the same values and validity (e.g. an alias of the same column).
+fn eq_identical(lhs: &dyn Array, rhs: &dyn Array) -> Option<BooleanArray> {
+    if lhs.data_type().to_logical_type() != rhs.data_type().to_logical_type() {
+        return None;
+    }
+    use crate::datatypes::PhysicalType;
+    use crate::datatypes::PrimitiveType::*;
+    match lhs.data_type().to_physical_type() {
+        // floats are excluded since `NaN != NaN`
+        PhysicalType::Primitive(Float32 | Float64) => None,
+        PhysicalType::Primitive(primitive) => match_eq!(primitive, |$T| {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            primitive::eq_identical::<$T>(lhs, rhs)
+        }),
+        _ => None,
+    }
 }
 
-/// `!=` between two [`Array`]s and includes validities in comparison.
-/// Use [`can_neq`] to check whether the operation is valid
+/// `==` between two [`Array`]s and includes validities in comparison.
+/// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq_and_validity, match_eq)
+pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_eq_and_validity(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
-/// Returns whether a [`DataType`] is comparable is supported by [`neq`].
-pub fn can_neq(data_type: &DataType) -> bool {
-    can_partial_eq(data_type)
+/// `==` between two [`Array`]s and includes validities in comparison, returning an error instead of panicking.
+/// Use [`can_eq`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_eq, None)?;
+    Ok(eq_and_validity_unchecked(lhs, rhs))
 }
 
-/// `<` between two [`Array`]s.
-/// Use [`can_lt`] to check whether the operation is valid
+/// [`eq_and_validity`] without checking its arguments.
+fn eq_and_validity_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(other) = other_than_nulls(lhs, rhs) {
+        return is_null(other);
+    }
+    compare_eq!(lhs, rhs, eq_and_validity)
+}
+
+/// Null-safe `==` between two [`Array`]s (SQL's `IS NOT DISTINCT FROM`, MySQL's `<=>`):
+/// two null slots are equal and a null slot is different from any value.
+/// Contrarily to [`eq`], the result never contains nulls.
+/// Use [`can_eq`] to check whether the operation is valid
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::eq_null_safe;
+///
+/// let lhs = Int32Array::from([Some(1), None, Some(1), None]);
+/// let rhs = Int32Array::from([Some(1), Some(1), None, None]);
+/// let expected = BooleanArray::from_slice([true, false, false, true]);
+/// assert_eq!(eq_null_safe(&lhs, &rhs), expected);
+/// ```
 /// # Panic
 /// Panics iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn lt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, lt, match_eq_ord)
+pub fn eq_null_safe(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_eq_null_safe(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
-/// Returns whether a [`DataType`] is comparable is supported by [`lt`].
-pub fn can_lt(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord(data_type)
+/// Same as [`eq_null_safe`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_eq_null_safe(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_eq, None)?;
+    if let Some(other) = other_than_nulls(lhs, rhs) {
+        return Ok(is_null(other));
+    }
+    let out = eq_unchecked(lhs, rhs);
+    // the slots of nested types are also null when their children are, e.g. for a struct
+    // whose field is null, which are not equal
+    let out_values = match out.validity() {
+        Some(validity) => out.values() & validity,
+        None => out.values().clone(),
+    };
+    let values = match (lhs.validity(), rhs.validity()) {
+        (None, None) => out_values,
+        (Some(validity), None) | (None, Some(validity)) => &out_values & validity,
+        (Some(lhs), Some(rhs)) => ternary(&out_values, lhs, rhs, |values, lhs, rhs| {
+            (values & lhs & rhs) | !(lhs | rhs)
+        }),
+    };
+    Ok(BooleanArray::new(DataType::Boolean, values, None))
 }
 
-/// `<=` between two [`Array`]s.
-/// Use [`can_lt_eq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
-/// * the arrays do not have have the same logical type
-/// * the arrays do not have the same length
-/// * the operation is not supported for the logical type
-pub fn lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, lt_eq, match_eq_ord)
+macro_rules! match_total_cmp {(
+    $key_type:expr, | $_:tt $T:ident, $_2:tt $cmp:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident, $_2 $cmp:expr ) => ( $($body)* )}
+    use crate::array::ord::{total_cmp, total_cmp_f32, total_cmp_f64};
+    use crate::datatypes::PrimitiveType::*;
+    use crate::types::{days_ms, months_days_ns};
+    match $key_type {
+        Int8 => __with_ty__! { i8, total_cmp },
+        Int16 => __with_ty__! { i16, total_cmp },
+        Int32 => __with_ty__! { i32, total_cmp },
+        Int64 => __with_ty__! { i64, total_cmp },
+        Int128 => __with_ty__! { i128, total_cmp },
+        DaysMs => __with_ty__! { days_ms, |lhs: &days_ms, rhs: &days_ms| {
+            (lhs.days(), lhs.milliseconds()).cmp(&(rhs.days(), rhs.milliseconds()))
+        } },
+        MonthDayNano => __with_ty__! { months_days_ns, |lhs: &months_days_ns, rhs: &months_days_ns| {
+            (lhs.months(), lhs.days(), lhs.ns()).cmp(&(rhs.months(), rhs.days(), rhs.ns()))
+        } },
+        UInt8 => __with_ty__! { u8, total_cmp },
+        UInt16 => __with_ty__! { u16, total_cmp },
+        UInt32 => __with_ty__! { u32, total_cmp },
+        UInt64 => __with_ty__! { u64, total_cmp },
+        Float32 => __with_ty__! { f32, total_cmp_f32 },
+        Float64 => __with_ty__! { f64, total_cmp_f64 },
+    }
+})}
+
+/// Three-way comparison between two [`Array`]s: returns the [`Ordering`](std::cmp::Ordering)
+/// of each pair of elements as `-1` (less), `0` (equal) or `1` (greater).
+/// A null element in either `lhs` or `rhs` results in a null slot.
+///
+/// Floats are compared using IEEE 754's total order, so that `NaN` is ordered consistently.
+/// Use [`can_compare`] to check whether the operation is valid.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Int8Array};
+/// use arrow2::compute::comparison::compare;
+///
+/// let lhs = Float64Array::from([Some(1.0), Some(f64::NAN), None]);
+/// let rhs = Float64Array::from([Some(2.0), Some(1.0), Some(1.0)]);
+/// assert_eq!(compare(&lhs, &rhs).unwrap(), Int8Array::from([Some(-1), Some(1), None]));
+/// ```
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn compare(lhs: &dyn Array, rhs: &dyn Array) -> Result<PrimitiveArray<i8>> {
+    check_same_type(lhs, rhs)?;
+    check_arrays(lhs, rhs, can_compare, None)?;
+
+    use crate::datatypes::PhysicalType::*;
+    Ok(match lhs.data_type().to_physical_type() {
+        Boolean => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            boolean::compare(lhs, rhs)
+        }
+        Primitive(primitive) => match_total_cmp!(primitive, |$T, $cmp| {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            primitive::compare_by::<$T, _>(lhs, rhs, $cmp)
+        }),
+        Utf8 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            utf8::compare::<i32>(lhs, rhs)
+        }
+        LargeUtf8 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            utf8::compare::<i64>(lhs, rhs)
+        }
+        Binary => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            binary::compare::<i32>(lhs, rhs)
+        }
+        LargeBinary => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            binary::compare::<i64>(lhs, rhs)
+        }
+        FixedSizeBinary => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            fixed_size_binary::compare(lhs, rhs)
+        }
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Comparison between {:?} is not yet supported",
+                lhs.data_type()
+            )))
+        }
+    })
 }
 
-/// Returns whether a [`DataType`] is comparable is supported by [`lt`].
-pub fn can_lt_eq(data_type: &DataType) -> bool {
+/// Returns whether a [`DataType`] is supported by [`compare`].
+pub fn can_compare(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
 }
 
-/// `>` between two [`Array`]s.
-/// Use [`can_gt`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
-/// * the arrays do not have have the same logical type
-/// * the arrays do not have the same length
-/// * the operation is not supported for the logical type
-pub fn gt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, gt, match_eq_ord)
-}
+/// Three-way comparison between an [`Array`] and a [`Scalar`]: returns the
+/// [`Ordering`](std::cmp::Ordering) of each element with the scalar as `-1` (less),
+/// `0` (equal) or `1` (greater).
+/// Null elements of `lhs` are null in the result. If `rhs` is null, all slots are null.
+///
+/// Floats are compared using IEEE 754's total order, so that `NaN` is ordered consistently.
+/// Use [`can_compare_scalar`] to check whether the operation is valid.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn compare_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<PrimitiveArray<i8>> {
+    use crate::datatypes::PhysicalType::*;
+    if let Dictionary(key_type) = lhs.data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| {
+            let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            check_dictionary_keys(lhs)?;
+            let values = compare_scalar(lhs.values().as_ref(), rhs)?;
 
-/// Returns whether a [`DataType`] is comparable is supported by [`gt`].
-pub fn can_gt(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord(data_type)
+            let result = compute::take::take(&values, lhs.keys())?;
+            Ok(result
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i8>>()
+                .unwrap()
+                .clone())
+        });
+    }
+
+    check_scalar(lhs, rhs, can_compare_scalar)?;
+    if !rhs.is_valid() {
+        return Ok(PrimitiveArray::<i8>::new_null(DataType::Int8, lhs.len()));
+    }
+
+    Ok(match lhs.data_type().to_physical_type() {
+        Boolean => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            boolean::compare_scalar(lhs, rhs.value().unwrap())
+        }
+        Primitive(primitive) => match_total_cmp!(primitive, |$T, $cmp| {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            primitive::compare_scalar_by::<$T, _>(lhs, rhs.value().unwrap(), $cmp)
+        }),
+        Utf8 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            utf8::compare_scalar::<i32>(lhs, rhs.value().unwrap())
+        }
+        LargeUtf8 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            utf8::compare_scalar::<i64>(lhs, rhs.value().unwrap())
+        }
+        Binary => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            binary::compare_scalar::<i32>(lhs, rhs.value().unwrap())
+        }
+        LargeBinary => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            binary::compare_scalar::<i64>(lhs, rhs.value().unwrap())
+        }
+        FixedSizeBinary => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            fixed_size_binary::compare_scalar(lhs, rhs.value().unwrap())
+        }
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Comparison between {:?} is not yet supported",
+                lhs.data_type()
+            )))
+        }
+    })
 }
 
-/// `>=` between two [`Array`]s.
-/// Use [`can_gt_eq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
-/// * the arrays do not have have the same logical type
-/// * the arrays do not have the same length
-/// * the operation is not supported for the logical type
-pub fn gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, gt_eq, match_eq_ord)
+/// Returns whether a [`DataType`] is supported by [`compare_scalar`].
+pub fn can_compare_scalar(data_type: &DataType) -> bool {
+    can_partial_eq_and_ord_scalar(data_type)
 }
 
-/// Returns whether a [`DataType`] is comparable is supported by [`gt_eq`].
-pub fn can_gt_eq(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord(data_type)
+macro_rules! select {
+    ($lhs:expr, $rhs:expr, $op:ident, $op_by:ident) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        check_same_type(lhs, rhs)?;
+        check_arrays(lhs, rhs, can_select, None)?;
+
+        use crate::datatypes::PhysicalType::*;
+        Ok(match lhs.data_type().to_physical_type() {
+            Primitive(primitive) => match_total_cmp!(primitive, |$T, $cmp| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(primitive::$op_by::<$T, _>(lhs, rhs, $cmp)) as Box<dyn Array>
+            }),
+            Utf8 => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(utf8::$op::<i32>(lhs, rhs))
+            }
+            LargeUtf8 => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(utf8::$op::<i64>(lhs, rhs))
+            }
+            Binary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(binary::$op::<i32>(lhs, rhs))
+            }
+            LargeBinary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(binary::$op::<i64>(lhs, rhs))
+            }
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Selection of {:?} is not yet supported",
+                    lhs.data_type()
+                )))
+            }
+        })
+    }};
 }
 
-macro_rules! compare_scalar {
-    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+macro_rules! select_scalar {
+    ($lhs:expr, $rhs:expr, $op:ident, $op_by:ident) => {{
         let lhs = $lhs;
         let rhs = $rhs;
-        assert_eq!(
-            lhs.data_type().to_logical_type(),
-            rhs.data_type().to_logical_type()
-        );
+        check_scalar(lhs, rhs, can_select)?;
         if !rhs.is_valid() {
-            return BooleanArray::new_null(DataType::Boolean, lhs.len());
+            return Ok(new_null_array(lhs.data_type().clone(), lhs.len()));
         }
 
         use crate::datatypes::PhysicalType::*;
-        match lhs.data_type().to_physical_type() {
-            Boolean => {
-                let lhs = lhs.as_any().downcast_ref().unwrap();
-                let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
-                // validity checked above
-                boolean::$op(lhs, rhs.value().unwrap())
-            }
-            Primitive(primitive) => $p!(primitive, |$T| {
+        Ok(match lhs.data_type().to_physical_type() {
+            Primitive(primitive) => match_total_cmp!(primitive, |$T, $cmp| {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
-                primitive::$op::<$T>(lhs, rhs.value().unwrap())
+                Box::new(primitive::$op_by::<$T, _>(lhs, rhs.value().unwrap(), $cmp))
+                    as Box<dyn Array>
             }),
             Utf8 => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
-                utf8::$op::<i32>(lhs, rhs.value().unwrap())
+                Box::new(utf8::$op::<i32>(lhs, rhs.value().unwrap()))
             }
             LargeUtf8 => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
-                utf8::$op::<i64>(lhs, rhs.value().unwrap())
+                Box::new(utf8::$op::<i64>(lhs, rhs.value().unwrap()))
             }
             Binary => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
-                binary::$op::<i32>(lhs, rhs.value().unwrap())
+                Box::new(binary::$op::<i32>(lhs, rhs.value().unwrap()))
             }
             LargeBinary => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
-                binary::$op::<i64>(lhs, rhs.value().unwrap())
+                Box::new(binary::$op::<i64>(lhs, rhs.value().unwrap()))
+            }
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Selection of {:?} is not yet supported",
+                    lhs.data_type()
+                )))
+            }
+        })
+    }};
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the smallest of the two
+/// (SQL's `LEAST`). A null element in either `lhs` or `rhs` results in a null slot.
+///
+/// Floats are compared using IEEE 754's total order, so that `NaN` is larger than any other value.
+/// Use [`can_min`] to check whether the operation is valid.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::comparison::min;
+///
+/// let lhs = Int32Array::from([Some(1), Some(4), None]);
+/// let rhs = Int32Array::from([Some(2), Some(3), Some(1)]);
+/// let result = min(&lhs, &rhs).unwrap();
+/// assert_eq!(Int32Array::from([Some(1), Some(3), None]), result.as_ref());
+/// ```
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn min(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    select!(lhs, rhs, min, min_by)
+}
+
+/// Returns whether a [`DataType`] is supported by [`min`] and [`min_scalar`].
+pub fn can_min(data_type: &DataType) -> bool {
+    can_select(data_type)
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the largest of the two
+/// (SQL's `GREATEST`). A null element in either `lhs` or `rhs` results in a null slot.
+///
+/// Floats are compared using IEEE 754's total order, so that `NaN` is larger than any other value.
+/// Use [`can_max`] to check whether the operation is valid.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn max(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    select!(lhs, rhs, max, max_by)
+}
+
+/// Returns whether a [`DataType`] is supported by [`max`] and [`max_scalar`].
+pub fn can_max(data_type: &DataType) -> bool {
+    can_select(data_type)
+}
+
+/// Returns, for each element of `lhs`, the smallest of it and `rhs`, i.e. clamps `lhs`
+/// from above. Null elements of `lhs` are null in the result. If `rhs` is null, all slots are null.
+/// Use [`can_min`] to check whether the operation is valid.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn min_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    select_scalar!(lhs, rhs, min_scalar, min_scalar_by)
+}
+
+/// Returns, for each element of `lhs`, the largest of it and `rhs`, i.e. clamps `lhs`
+/// from below. Null elements of `lhs` are null in the result. If `rhs` is null, all slots are null.
+/// Use [`can_max`] to check whether the operation is valid.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn max_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    select_scalar!(lhs, rhs, max_scalar, max_scalar_by)
+}
+
+fn can_select(data_type: &DataType) -> bool {
+    use crate::datatypes::PhysicalType::*;
+    matches!(
+        data_type.to_physical_type(),
+        Primitive(_) | Utf8 | LargeUtf8 | Binary | LargeBinary
+    )
+}
+
+/// Maximum number of elements of `values` in [`is_in`] up to which they are scanned
+/// linearly instead of hashed.
+const IS_IN_MAX_LINEAR_SCAN: usize = 16;
+
+macro_rules! match_is_in {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    use crate::types::{days_ms, months_days_ns};
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 | Float64 => unreachable!("floats are handled by `is_in_float`"),
+    }
+})}
+
+/// Returns whether each element of `array` is one of the (non-null) elements of `values`
+/// (`array IN values`). Null elements of `array` are null in the result.
+///
+/// When `array` is a [`DictionaryArray`], `values` must be of the type of its values; the
+/// membership is computed once per dictionary value. Floats are equal as per [`eq`], so that
+/// `NaN` is in no `values`.
+/// Use [`can_is_in`] to check whether the operation is valid.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::is_in;
+///
+/// let array = Int32Array::from([Some(1), None, Some(3)]);
+/// let values = Int32Array::from_slice([3, 4]);
+/// assert_eq!(is_in(&array, &values), BooleanArray::from([Some(false), None, Some(true)]));
+/// ```
+/// # Panic
+/// Panics iff either:
+/// * `values` is not of the (values') logical type of `array`
+/// * the operation is not supported for the logical type
+pub fn is_in(array: &dyn Array, values: &dyn Array) -> BooleanArray {
+    try_is_in(array, values).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Returns whether each element of `array` is one of the (non-null) elements of `values`
+/// (`array IN values`), returning an error instead of panicking.
+/// Use [`can_is_in`] to check whether the operation is valid.
+/// # Errors
+/// Errors iff either:
+/// * `values` is not of the (values') logical type of `array` ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_is_in(array: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
+    use crate::datatypes::PhysicalType::*;
+    use crate::datatypes::PrimitiveType;
+    if let Dictionary(key_type) = array.data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let is_in_values = try_is_in(array.values().as_ref(), values)?;
+
+            Ok(take_boolean(&is_in_values, array.keys()))
+        });
+    }
+
+    if array.data_type().to_logical_type() != values.data_type().to_logical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "is_in requires values of the logical type of the array, but got {:?} and {:?}",
+            array.data_type(),
+            values.data_type()
+        )));
+    }
+    let result = match array.data_type().to_physical_type() {
+        Primitive(PrimitiveType::Float32) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            primitive::is_in_float::<f32>(array, values)
+        }
+        Primitive(PrimitiveType::Float64) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            primitive::is_in_float::<f64>(array, values)
+        }
+        Primitive(primitive) => match_is_in!(primitive, |$T| {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            primitive::is_in::<$T>(array, values)
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            utf8::is_in::<i32>(array, values)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            utf8::is_in::<i64>(array, values)
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            binary::is_in::<i32>(array, values)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            binary::is_in::<i64>(array, values)
+        }
+        FixedSizeBinary => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let values = values.as_any().downcast_ref().unwrap();
+            fixed_size_binary::is_in(array, values)
+        }
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "is_in of {:?} is not yet supported",
+                array.data_type()
+            )))
+        }
+    };
+    Ok(result)
+}
+
+/// Returns whether a [`DataType`] is supported by [`is_in`].
+pub fn can_is_in(data_type: &DataType) -> bool {
+    use crate::datatypes::PhysicalType::*;
+    match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => can_is_in(values.as_ref()),
+        data_type => matches!(
+            data_type.to_physical_type(),
+            Primitive(_) | Utf8 | LargeUtf8 | Binary | LargeBinary | FixedSizeBinary
+        ),
+    }
+}
+
+/// Returns the indices `i` of `array` where `array[i] != array[i - 1]`, i.e. the first index
+/// of each run of equal values, excluding the first run (that always starts at index 0).
+///
+/// Nulls are considered equal to each other and different from any non-null value, so that a
+/// transition from a null to a non-null slot (and vice-versa) is an edge.
+/// Use [`can_eq`] to check whether the operation is valid.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::comparison::edges;
+///
+/// let array = Int32Array::from_slice([1, 1, 2, 2, 2, 3]);
+/// assert_eq!(edges(&array), UInt32Array::from_slice([2, 5]));
+/// ```
+/// # Panic
+/// Panics iff the operation is not supported for the logical type or
+/// the length of `array` does not fit in a `u32`.
+pub fn edges(array: &dyn Array) -> PrimitiveArray<u32> {
+    if array.len() < 2 || array.data_type() == &DataType::Null {
+        return PrimitiveArray::<u32>::from_vec(vec![]);
+    }
+    assert!(array.len() <= u32::MAX as usize);
+    let length = array.len() - 1;
+    let equal = eq(
+        array.slice(0, length).as_ref(),
+        array.slice(1, length).as_ref(),
+    );
+    let equal = equal.values();
+
+    let validity = array.validity();
+    let is_valid = |i: usize| validity.map(|x| x.get_bit(i)).unwrap_or(true);
+    let edges = (0..length)
+        .filter(|&i| match (is_valid(i), is_valid(i + 1)) {
+            (true, true) => !equal.get_bit(i),
+            (lhs, rhs) => lhs != rhs,
+        })
+        .map(|i| (i + 1) as u32)
+        .collect::<Vec<_>>();
+    PrimitiveArray::<u32>::from_vec(edges)
+}
+
+/// Returns whether a [`DataType`] is comparable is supported by [`eq`].
+pub fn can_eq(data_type: &DataType) -> bool {
+    can_partial_eq(data_type)
+}
+
+/// `!=` between two [`Array`]s.
+/// Use [`can_neq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length and neither has length 1 (see [broadcasting](self#broadcasting))
+/// * the operation is not supported for the logical type
+pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_neq(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `!=` between two [`Array`]s, returning an error instead of panicking.
+/// Use [`can_neq`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length and neither has length 1 (see
+///   [broadcasting](self#broadcasting)) ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_neq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_neq, Some(can_neq_scalar))?;
+    Ok(neq_unchecked(lhs, rhs))
+}
+
+/// [`neq`] without checking its arguments.
+fn neq_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(result) = broadcast(lhs, rhs, neq_scalar, neq_scalar_lhs, can_neq_scalar) {
+        return result;
+    }
+    compare_eq!(lhs, rhs, neq)
+}
+
+/// `!=` between two [`Array`]s and includes validities in comparison.
+/// Use [`can_neq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_neq_and_validity(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `!=` between two [`Array`]s and includes validities in comparison, returning an error instead of panicking.
+/// Use [`can_neq`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_neq, None)?;
+    Ok(neq_and_validity_unchecked(lhs, rhs))
+}
+
+/// [`neq_and_validity`] without checking its arguments.
+fn neq_and_validity_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(other) = other_than_nulls(lhs, rhs) {
+        return is_not_null(other);
+    }
+    compare_eq!(lhs, rhs, neq_and_validity)
+}
+
+/// Returns whether a [`DataType`] is comparable is supported by [`neq`].
+pub fn can_neq(data_type: &DataType) -> bool {
+    can_partial_eq(data_type)
+}
+
+/// `XOR` between two boolean [`Array`]s.
+/// A null slot in either `lhs` or `rhs` results in a null slot.
+/// Use [`can_xor`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn xor(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_same_type(lhs, rhs)?;
+    check_arrays(lhs, rhs, can_xor, None)?;
+    match lhs.data_type().to_logical_type() {
+        DataType::Boolean => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            Ok(boolean::xor(lhs, rhs))
+        }
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "XOR between {:?} is not supported",
+            lhs.data_type()
+        ))),
+    }
+}
+
+/// Returns whether a [`DataType`] is supported by [`xor`].
+pub fn can_xor(data_type: &DataType) -> bool {
+    data_type.to_logical_type() == &DataType::Boolean
+}
+
+/// `<` between two [`Array`]s.
+/// Use [`can_lt`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length and neither has length 1 (see [broadcasting](self#broadcasting))
+/// * the operation is not supported for the logical type
+pub fn lt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_lt(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `<` between two [`Array`]s, returning an error instead of panicking.
+/// Use [`can_lt`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length and neither has length 1 (see
+///   [broadcasting](self#broadcasting)) ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_lt(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_lt, Some(can_lt_scalar))?;
+    Ok(lt_unchecked(lhs, rhs))
+}
+
+/// [`lt`] without checking its arguments.
+fn lt_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(result) = broadcast(lhs, rhs, lt_scalar, lt_scalar_lhs, can_lt_scalar) {
+        return result;
+    }
+    compare!(lhs, rhs, lt, match_eq_ord)
+}
+
+/// Returns whether a [`DataType`] is comparable is supported by [`lt`].
+pub fn can_lt(data_type: &DataType) -> bool {
+    can_partial_ord(data_type)
+}
+
+/// `<=` between two [`Array`]s.
+/// Use [`can_lt_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length and neither has length 1 (see [broadcasting](self#broadcasting))
+/// * the operation is not supported for the logical type
+pub fn lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_lt_eq(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `<=` between two [`Array`]s, returning an error instead of panicking.
+/// Use [`can_lt_eq`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length and neither has length 1 (see
+///   [broadcasting](self#broadcasting)) ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_lt_eq, Some(can_lt_eq_scalar))?;
+    Ok(lt_eq_unchecked(lhs, rhs))
+}
+
+/// [`lt_eq`] without checking its arguments.
+fn lt_eq_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(result) = broadcast(lhs, rhs, lt_eq_scalar, lt_eq_scalar_lhs, can_lt_eq_scalar) {
+        return result;
+    }
+    compare!(lhs, rhs, lt_eq, match_eq_ord)
+}
+
+/// Returns whether a [`DataType`] is comparable is supported by [`lt`].
+pub fn can_lt_eq(data_type: &DataType) -> bool {
+    can_partial_ord(data_type)
+}
+
+/// `>` between two [`Array`]s.
+/// Use [`can_gt`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length and neither has length 1 (see [broadcasting](self#broadcasting))
+/// * the operation is not supported for the logical type
+pub fn gt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_gt(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `>` between two [`Array`]s, returning an error instead of panicking.
+/// Use [`can_gt`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length and neither has length 1 (see
+///   [broadcasting](self#broadcasting)) ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_gt(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_gt, Some(can_gt_scalar))?;
+    Ok(gt_unchecked(lhs, rhs))
+}
+
+/// [`gt`] without checking its arguments.
+fn gt_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(result) = broadcast(lhs, rhs, gt_scalar, gt_scalar_lhs, can_gt_scalar) {
+        return result;
+    }
+    compare!(lhs, rhs, gt, match_eq_ord)
+}
+
+/// Returns whether a [`DataType`] is comparable is supported by [`gt`].
+pub fn can_gt(data_type: &DataType) -> bool {
+    can_partial_ord(data_type)
+}
+
+/// `>=` between two [`Array`]s.
+/// Use [`can_gt_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length and neither has length 1 (see [broadcasting](self#broadcasting))
+/// * the operation is not supported for the logical type
+pub fn gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_gt_eq(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `>=` between two [`Array`]s, returning an error instead of panicking.
+/// Use [`can_gt_eq`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length and neither has length 1 (see
+///   [broadcasting](self#broadcasting)) ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_gt_eq, Some(can_gt_eq_scalar))?;
+    Ok(gt_eq_unchecked(lhs, rhs))
+}
+
+/// [`gt_eq`] without checking its arguments.
+fn gt_eq_unchecked(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if let Some(result) = broadcast(lhs, rhs, gt_eq_scalar, gt_eq_scalar_lhs, can_gt_eq_scalar) {
+        return result;
+    }
+    compare!(lhs, rhs, gt_eq, match_eq_ord)
+}
+
+/// Returns whether a [`DataType`] is comparable is supported by [`gt_eq`].
+pub fn can_gt_eq(data_type: &DataType) -> bool {
+    can_partial_ord(data_type)
+}
+
+/// Which of the bounds of [`between`] (and [`between_scalar`]) are included in the interval,
+/// since e.g. SQL's `BETWEEN` includes both while other conventions exclude some.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Inclusivity {
+    /// `low <= x <= high`, as SQL's `BETWEEN`
+    Both,
+    /// `low <= x < high`
+    Left,
+    /// `low < x <= high`
+    Right,
+    /// `low < x < high`
+    Neither,
+}
+
+/// `low <= array <= high` (or with the bounds included as per `inclusivity`) between three
+/// [`Array`]s. When both bounds are included, it is evaluated in a single pass over them
+/// (instead of computing [`gt_eq`] and [`lt_eq`] and `AND`-ing their results).
+/// A null slot in any of the arrays results in a null slot.
+/// As [`gt_eq`] and [`lt_eq`], dictionaries are compared by their values and decimals of
+/// different scales at a common scale.
+/// Use [`can_between`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn between(
+    array: &dyn Array,
+    low: &dyn Array,
+    high: &dyn Array,
+    inclusivity: Inclusivity,
+) -> BooleanArray {
+    try_between(array, low, high, inclusivity).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `low <= array <= high` (or with the bounds included as per `inclusivity`) between three
+/// [`Array`]s, returning an error instead of panicking.
+/// Use [`can_between`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_between(
+    array: &dyn Array,
+    low: &dyn Array,
+    high: &dyn Array,
+    inclusivity: Inclusivity,
+) -> Result<BooleanArray> {
+    check_arrays(array, low, can_between, None)?;
+    check_arrays(array, high, can_between, None)?;
+    let and = |lhs: BooleanArray, rhs: BooleanArray| compute::boolean::and(&lhs, &rhs);
+    match inclusivity {
+        Inclusivity::Both => between_inclusive(array, low, high),
+        Inclusivity::Left => and(gt_eq_unchecked(array, low), lt_unchecked(array, high)),
+        Inclusivity::Right => and(gt_unchecked(array, low), lt_eq_unchecked(array, high)),
+        Inclusivity::Neither => and(gt_unchecked(array, low), lt_unchecked(array, high)),
+    }
+}
+
+/// `NOT (low <= array <= high)` (SQL's `NOT BETWEEN`), the negation of [`between`].
+/// A null slot in any of the arrays results in a null slot.
+/// Use [`can_not_between`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn not_between(
+    array: &dyn Array,
+    low: &dyn Array,
+    high: &dyn Array,
+    inclusivity: Inclusivity,
+) -> BooleanArray {
+    try_not_between(array, low, high, inclusivity).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`not_between`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_not_between(
+    array: &dyn Array,
+    low: &dyn Array,
+    high: &dyn Array,
+    inclusivity: Inclusivity,
+) -> Result<BooleanArray> {
+    Ok(compute::boolean::not(&try_between(
+        array,
+        low,
+        high,
+        inclusivity,
+    )?))
+}
+
+/// Returns whether a [`DataType`] is supported by [`not_between`].
+pub fn can_not_between(data_type: &DataType) -> bool {
+    can_between(data_type)
+}
+
+/// `low <= array <= high` in a single pass over the arrays, checked by [`try_between`].
+fn between_inclusive(array: &dyn Array, low: &dyn Array, high: &dyn Array) -> Result<BooleanArray> {
+    if let Some(result) = compare_nulls(array, low).or_else(|| compare_nulls(array, high)) {
+        return Ok(result);
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    // dictionaries are compared by their (logical) values, irrespectively of their keys
+    if let Dictionary(_) = array.data_type().to_physical_type() {
+        let array = dictionary_values(array);
+        let low = dictionary_values(low);
+        let high = dictionary_values(high);
+        return between_inclusive(array.as_ref(), low.as_ref(), high.as_ref());
+    }
+    // decimals of different scales are compared with each bound at their common scale, as a
+    // scale common to the three arrays could saturate both `array` and a bound when rescaled
+    let logical_type = array.data_type().to_logical_type();
+    if logical_type != low.data_type().to_logical_type()
+        || logical_type != high.data_type().to_logical_type()
+    {
+        return compute::boolean::and(&gt_eq_unchecked(array, low), &lt_eq_unchecked(array, high));
+    }
+
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => boolean::between(
+            array.as_any().downcast_ref().unwrap(),
+            low.as_any().downcast_ref().unwrap(),
+            high.as_any().downcast_ref().unwrap(),
+        ),
+        Primitive(primitive) => match_eq_ord!(primitive, |$T| {
+            primitive::between::<$T>(
+                array.as_any().downcast_ref().unwrap(),
+                low.as_any().downcast_ref().unwrap(),
+                high.as_any().downcast_ref().unwrap(),
+            )
+        }),
+        Utf8 => utf8::between::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            low.as_any().downcast_ref().unwrap(),
+            high.as_any().downcast_ref().unwrap(),
+        ),
+        LargeUtf8 => utf8::between::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            low.as_any().downcast_ref().unwrap(),
+            high.as_any().downcast_ref().unwrap(),
+        ),
+        Binary => binary::between::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            low.as_any().downcast_ref().unwrap(),
+            high.as_any().downcast_ref().unwrap(),
+        ),
+        LargeBinary => binary::between::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            low.as_any().downcast_ref().unwrap(),
+            high.as_any().downcast_ref().unwrap(),
+        ),
+        FixedSizeBinary => fixed_size_binary::between(
+            array.as_any().downcast_ref().unwrap(),
+            low.as_any().downcast_ref().unwrap(),
+            high.as_any().downcast_ref().unwrap(),
+        ),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "between is not yet supported for {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
+
+/// Returns whether a [`DataType`] is supported by [`between`].
+pub fn can_between(data_type: &DataType) -> bool {
+    can_partial_ord(data_type)
+}
+
+/// `$op` on arrays checked by `check_arrays`, except that floats are compared by `$total_op`.
+macro_rules! compare_total {
+    ($lhs:expr, $rhs:expr, $op:ident, $total_op:ident) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        if let Some(result) = compare_nulls(lhs, rhs) {
+            return Ok(result);
+        }
+
+        use crate::datatypes::{PhysicalType::Primitive, PrimitiveType::*};
+        Ok(match lhs.data_type().to_physical_type() {
+            Primitive(Float32) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                primitive::$total_op::<f32>(lhs, rhs)
+            }
+            Primitive(Float64) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                primitive::$total_op::<f64>(lhs, rhs)
+            }
+            _ => $op(lhs, rhs),
+        })
+    }};
+}
+
+/// `<` between two [`Array`]s where floats are compared using IEEE 754's total order
+/// (see [`primitive::TotalOrd`]), so that `NaN` and `-0.0` are ordered deterministically.
+/// For any other type this is the same as [`lt`].
+/// Use [`can_lt`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn lt_total(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_lt_total(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`lt_total`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_lt_total(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_lt, None)?;
+    compare_total!(lhs, rhs, lt_unchecked, lt_total)
+}
+
+/// `<=` between two [`Array`]s where floats are compared using IEEE 754's total order
+/// (see [`primitive::TotalOrd`]). For any other type this is the same as [`lt_eq`].
+/// Use [`can_lt_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn lt_eq_total(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_lt_eq_total(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`lt_eq_total`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_lt_eq_total(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_lt_eq, None)?;
+    compare_total!(lhs, rhs, lt_eq_unchecked, lt_eq_total)
+}
+
+/// `>` between two [`Array`]s where floats are compared using IEEE 754's total order
+/// (see [`primitive::TotalOrd`]). For any other type this is the same as [`gt`].
+/// Use [`can_gt`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn gt_total(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_gt_total(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`gt_total`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_gt_total(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_gt, None)?;
+    compare_total!(lhs, rhs, gt_unchecked, gt_total)
+}
+
+/// `>=` between two [`Array`]s where floats are compared using IEEE 754's total order
+/// (see [`primitive::TotalOrd`]). For any other type this is the same as [`gt_eq`].
+/// Use [`can_gt_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn gt_eq_total(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_gt_eq_total(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`gt_eq_total`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_gt_eq_total(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_gt_eq, None)?;
+    compare_total!(lhs, rhs, gt_eq_unchecked, gt_eq_total)
+}
+
+/// `==` between two [`Array`]s where floats are equal when both are `NaN`
+/// (see [`primitive::NanEq`]). For any other type this is the same as [`eq`].
+///
+/// This deviates from IEEE 754 and from SQL (where `NaN != NaN`) and is intended for
+/// e.g. grouping or deduplicating values.
+/// Use [`can_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn eq_nan(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_eq_nan(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`eq_nan`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the arrays do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn try_eq_nan(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    check_arrays(lhs, rhs, can_eq, None)?;
+    compare_total!(lhs, rhs, eq_unchecked, eq_nan)
+}
+
+/// `==` between two [`Utf8Array`]s of any offset width (i.e. [`DataType::Utf8`] or
+/// [`DataType::LargeUtf8`] on either side), comparing their strings without casting.
+/// Unlike [`eq`], `lhs` and `rhs` may have different logical types.
+/// # Panic
+/// Panics iff either:
+/// * either array is neither a [`DataType::Utf8`] nor a [`DataType::LargeUtf8`]
+/// * the arrays do not have the same length
+pub fn eq_utf8_any(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    try_eq_utf8_any(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`eq_utf8_any`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * either array is neither a [`DataType::Utf8`] nor a [`DataType::LargeUtf8`]
+///   ([`ArrowError::InvalidArgumentError`])
+/// * the arrays do not have the same length ([`ArrowError::InvalidArgumentError`])
+pub fn try_eq_utf8_any(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Comparison requires arrays of the same length, but got {} and {}",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    use crate::datatypes::PhysicalType::*;
+    Ok(match (
+        lhs.data_type().to_physical_type(),
+        rhs.data_type().to_physical_type(),
+    ) {
+        (Utf8, Utf8) => utf8::eq_any::<i32, i32>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        ),
+        (Utf8, LargeUtf8) => utf8::eq_any::<i32, i64>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        ),
+        (LargeUtf8, Utf8) => utf8::eq_any::<i64, i32>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        ),
+        (LargeUtf8, LargeUtf8) => utf8::eq_any::<i64, i64>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        ),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "eq_utf8_any is only supported between Utf8 and LargeUtf8 arrays, got {:?} and {:?}",
+                lhs.data_type(),
+                rhs.data_type()
+            )))
+        }
+    })
+}
+
+/// `$op` between an array and a scalar checked by `check_scalar`, as a [`Result`].
+/// `$is_prefix` is `Some` for ordering operations, whose results on ordered dictionaries are
+/// computed from their keys (see [`ordered_dictionary_op_scalar`]).
+macro_rules! compare_scalar {
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {
+        compare_scalar!($lhs, $rhs, $op, $p, None)
+    };
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt, $is_prefix:expr) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        // all slots of a `NullArray` are null, and so is a comparison with a null scalar
+        if lhs.data_type().to_logical_type() == &DataType::Null
+            || rhs.data_type().to_logical_type() == &DataType::Null
+        {
+            return Ok(BooleanArray::new_null(DataType::Boolean, lhs.len()));
+        }
+        if !rhs.is_valid() {
+            return Ok(BooleanArray::new_null(DataType::Boolean, lhs.len()));
+        }
+
+        use crate::datatypes::PhysicalType::*;
+        Ok(match lhs.data_type().to_physical_type() {
+            Boolean => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
+                // validity checked above
+                boolean::$op(lhs, rhs.value().unwrap())
+            }
+            Primitive(primitive) => $p!(primitive, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+                primitive::$op::<$T>(lhs, rhs.value().unwrap())
+            }),
+            Utf8 => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+                utf8::$op::<i32>(lhs, rhs.value().unwrap())
+            }
+            LargeUtf8 => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+                utf8::$op::<i64>(lhs, rhs.value().unwrap())
+            }
+            Binary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+                binary::$op::<i32>(lhs, rhs.value().unwrap())
+            }
+            LargeBinary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+                binary::$op::<i64>(lhs, rhs.value().unwrap())
+            }
+            FixedSizeBinary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryScalar>()
+                    .unwrap();
+                fixed_size_binary::$op(lhs, rhs.value().unwrap())
             }
             Dictionary(key_type) => {
                 match_integer_type!(key_type, |$T| {
                     let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
-                    let values = $op(lhs.values().as_ref(), rhs);
+                    let rhs = rhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+                    // validity checked above
+                    let rhs = rhs.value().unwrap();
 
+                    let values = $op(lhs.values().as_ref(), rhs.as_ref());
+                    let is_prefix: Option<bool> = $is_prefix;
+                    if let Some(result) = is_prefix
+                        .and_then(|is_prefix| ordered_dictionary_op_scalar(lhs, &values, is_prefix))
+                    {
+                        return Ok(result);
+                    }
                     take_boolean(&values, lhs.keys())
                 })
             }
-            _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
-        }
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Comparisons of {:?} are not yet supported",
+                    lhs.data_type()
+                )))
+            }
+        })
     }};
 }
 
+/// Returns the positions of the values of `array` equal to `rhs`, or `None` if
+/// `rhs` is null or this is not supported for their type.
+fn positions_eq_scalar(array: &dyn Array, rhs: &dyn Scalar) -> Option<Vec<usize>> {
+    fn positions<T: PartialEq, I: Iterator<Item = T>>(iter: I, rhs: T) -> Vec<usize> {
+        iter.enumerate()
+            .filter(|(_, x)| *x == rhs)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    Some(match array.data_type().to_physical_type() {
+        Primitive(primitive) => match_eq!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>()?;
+            positions(array.values().iter().copied(), rhs.value()?)
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i32>>()?;
+            positions(array.values_iter(), rhs.value()?)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i64>>()?;
+            positions(array.values_iter(), rhs.value()?)
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i32>>()?;
+            positions(array.values_iter(), rhs.value()?)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>()?;
+            positions(array.values_iter(), rhs.value()?)
+        }
+        _ => return None,
+    })
+}
+
+/// `==` between a [`DictionaryArray`] and a [`DictionaryScalar`] that compares its keys to
+/// the position of the value equal to `rhs`, instead of comparing every value and taking
+/// the result by the keys.
+/// Returns `None` when this is not possible, i.e. when the values contain nulls or `rhs`
+/// more than once, or their type is not supported.
+fn dictionary_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Option<BooleanArray> {
+    let key_type = match lhs.data_type().to_logical_type() {
+        DataType::Dictionary(key_type, _, _) => *key_type,
+        _ => return None,
+    };
+    if lhs.data_type().to_logical_type() != rhs.data_type().to_logical_type() {
+        return None;
+    }
+    match_integer_type!(key_type, |$T| {
+        let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+        let rhs = rhs.as_any().downcast_ref::<DictionaryScalar<$T>>()?.value()?;
+        let values = lhs.values();
+        if values.null_count() > 0 {
+            return None;
+        }
+
+        let positions = positions_eq_scalar(values.as_ref(), rhs.as_ref())?;
+        match positions.as_slice() {
+            [] => Some(BooleanArray::new(
+                DataType::Boolean,
+                Bitmap::new_zeroed(lhs.len()),
+                lhs.keys().validity().cloned(),
+            )),
+            [position] => {
+                let key = <$T as num_traits::FromPrimitive>::from_usize(*position)?;
+                Some(primitive::eq_scalar(lhs.keys(), key))
+            }
+            _ => None,
+        }
+    })
+}
+
 /// `==` between an [`Array`] and a [`Scalar`].
 /// Use [`can_eq_scalar`] to check whether the operation is valid
+///
+/// Dictionaries are compared by their keys against the position of the value equal to `rhs`
+/// when their values are unique and valid, without comparing every value.
 /// # Panic
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values (see [`try_eq_scalar`])
 pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, eq_scalar, match_eq)
+    try_eq_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
 /// `==` between an [`Array`] and a [`Scalar`] and includes validities in comparison.
@@ -350,23 +2049,116 @@ pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
 pub fn eq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    try_eq_scalar_and_validity(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`eq_scalar_and_validity`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_eq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_eq_scalar)?;
     compare_scalar!(lhs, rhs, eq_scalar_and_validity, match_eq)
 }
 
+/// Null-safe `==` between an [`Array`] and a [`Scalar`] (SQL's `IS NOT DISTINCT FROM`,
+/// MySQL's `<=>`): a null slot equals a null scalar and differs from any value.
+/// Contrarily to [`eq_scalar`], the result never contains nulls.
+/// Use [`can_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn eq_null_safe_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    try_eq_null_safe_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`eq_null_safe_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_eq_null_safe_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_eq_scalar)?;
+    if !rhs.is_valid() {
+        return Ok(is_null(lhs));
+    }
+    let out = eq_scalar(lhs, rhs);
+    let values = match out.validity() {
+        None => out.values().clone(),
+        Some(validity) => out.values() & validity,
+    };
+    Ok(BooleanArray::new(DataType::Boolean, values, None))
+}
+
 /// Returns whether a [`DataType`] is supported by [`eq_scalar`].
 pub fn can_eq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_scalar(data_type)
 }
 
+/// `==` between an [`Array`] and a [`Scalar`] where floats are equal when both are `NaN`
+/// (see [`primitive::NanEq`]). For any other type this is the same as [`eq_scalar`].
+///
+/// This deviates from IEEE 754 and from SQL (where `NaN != NaN`) and is intended for
+/// e.g. grouping or deduplicating values.
+/// Use [`can_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn eq_nan_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    try_eq_nan_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`eq_nan_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_eq_nan_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_eq_scalar)?;
+    use crate::datatypes::{PhysicalType::Primitive, PrimitiveType::*};
+    Ok(match lhs.data_type().to_physical_type() {
+        Primitive(Float32) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<f32>>().unwrap();
+            match rhs.value() {
+                Some(rhs) => primitive::eq_nan_scalar::<f32>(lhs, rhs),
+                None => BooleanArray::new_null(DataType::Boolean, lhs.len()),
+            }
+        }
+        Primitive(Float64) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<f64>>().unwrap();
+            match rhs.value() {
+                Some(rhs) => primitive::eq_nan_scalar::<f64>(lhs, rhs),
+                None => BooleanArray::new_null(DataType::Boolean, lhs.len()),
+            }
+        }
+        _ => eq_scalar(lhs, rhs),
+    })
+}
+
 /// `!=` between an [`Array`] and a [`Scalar`].
 /// Use [`can_neq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values (see [`try_neq_scalar`])
 pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, neq_scalar, match_eq)
+    try_neq_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
 /// `!=` between an [`Array`] and a [`Scalar`] and includes validities in comparison.
@@ -375,7 +2167,20 @@ pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
 pub fn neq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    try_neq_scalar_and_validity(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`neq_scalar_and_validity`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_neq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_neq_scalar)?;
     compare_scalar!(lhs, rhs, neq_scalar_and_validity, match_eq)
 }
 
@@ -384,19 +2189,50 @@ pub fn can_neq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_scalar(data_type)
 }
 
+/// `XOR` between a boolean [`Array`] and a [`Scalar`].
+/// A null slot in `lhs` or a null `rhs` results in a null slot.
+/// Use [`can_xor_scalar`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn xor_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_xor_scalar)?;
+    if !rhs.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, lhs.len()));
+    }
+    match lhs.data_type().to_logical_type() {
+        DataType::Boolean => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            Ok(boolean::xor_scalar(lhs, rhs.value().unwrap()))
+        }
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "XOR between {:?} is not supported",
+            lhs.data_type()
+        ))),
+    }
+}
+
+/// Returns whether a [`DataType`] is supported by [`xor_scalar`].
+pub fn can_xor_scalar(data_type: &DataType) -> bool {
+    can_xor(data_type)
+}
+
 /// `<` between an [`Array`] and a [`Scalar`].
 /// Use [`can_lt_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values (see [`try_lt_scalar`])
 pub fn lt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, lt_scalar, match_eq_ord)
+    try_lt_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
 /// Returns whether a [`DataType`] is supported by [`lt_scalar`].
 pub fn can_lt_scalar(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord_scalar(data_type)
+    can_partial_ord_scalar(data_type)
 }
 
 /// `<=` between an [`Array`] and a [`Scalar`].
@@ -405,13 +2241,14 @@ pub fn can_lt_scalar(data_type: &DataType) -> bool {
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values (see [`try_lt_eq_scalar`])
 pub fn lt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, lt_eq_scalar, match_eq_ord)
+    try_lt_eq_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
 /// Returns whether a [`DataType`] is supported by [`lt_eq_scalar`].
 pub fn can_lt_eq_scalar(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord_scalar(data_type)
+    can_partial_ord_scalar(data_type)
 }
 
 /// `>` between an [`Array`] and a [`Scalar`].
@@ -420,13 +2257,14 @@ pub fn can_lt_eq_scalar(data_type: &DataType) -> bool {
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values (see [`try_gt_scalar`])
 pub fn gt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, gt_scalar, match_eq_ord)
+    try_gt_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
 /// Returns whether a [`DataType`] is supported by [`gt_scalar`].
 pub fn can_gt_scalar(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord_scalar(data_type)
+    can_partial_ord_scalar(data_type)
 }
 
 /// `>=` between an [`Array`] and a [`Scalar`].
@@ -435,13 +2273,715 @@ pub fn can_gt_scalar(data_type: &DataType) -> bool {
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values (see [`try_gt_eq_scalar`])
 pub fn gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, gt_eq_scalar, match_eq_ord)
+    try_gt_eq_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
 }
 
 /// Returns whether a [`DataType`] is supported by [`gt_eq_scalar`].
 pub fn can_gt_eq_scalar(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord_scalar(data_type)
+    can_partial_ord_scalar(data_type)
+}
+
+macro_rules! filter_scalar {
+    ($lhs:expr, $rhs:expr, $op:ident) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        check_scalar(lhs, rhs, can_filter_scalar)?;
+        // a comparison with a null scalar is null, i.e. filtered out
+        if !rhs.is_valid() {
+            return Ok(new_empty_array(lhs.data_type().clone()));
+        }
+
+        match lhs.data_type().to_physical_type() {
+            crate::datatypes::PhysicalType::Primitive(primitive) => {
+                match_eq_ord!(primitive, |$T| {
+                    let lhs = lhs.as_any().downcast_ref().unwrap();
+                    let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+                    Ok(Box::new(primitive::$op::<$T>(lhs, rhs.value().unwrap())) as Box<dyn Array>)
+                })
+            }
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "Filtering {:?} by a comparison is not yet supported",
+                lhs.data_type()
+            ))),
+        }
+    }};
+}
+
+/// Returns the elements of `lhs` that are `< rhs`, i.e. `filter(lhs, &lt_scalar(lhs, rhs))`,
+/// evaluating the comparison and gathering the elements in a single pass, without the
+/// intermediate [`BooleanArray`]. This is faster for selective comparisons.
+/// Null elements are not returned. If `rhs` is null, the result is empty.
+/// Use [`can_filter_scalar`] to check whether the operation is valid
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::comparison::filter_lt_scalar;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from([Some(1), None, Some(5), Some(2)]);
+/// let result = filter_lt_scalar(&array, &PrimitiveScalar::from(Some(3i32))).unwrap();
+/// assert_eq!(Int32Array::from_slice([1, 2]), result.as_ref());
+/// ```
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn filter_lt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    filter_scalar!(lhs, rhs, filter_lt_scalar)
+}
+
+/// Returns the elements of `lhs` that are `<= rhs`, i.e. `filter(lhs, &lt_eq_scalar(lhs, rhs))`,
+/// in a single pass (see [`filter_lt_scalar`]).
+/// Use [`can_filter_scalar`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn filter_lt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    filter_scalar!(lhs, rhs, filter_lt_eq_scalar)
+}
+
+/// Returns the elements of `lhs` that are `> rhs`, i.e. `filter(lhs, &gt_scalar(lhs, rhs))`,
+/// in a single pass (see [`filter_lt_scalar`]).
+/// Use [`can_filter_scalar`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn filter_gt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    filter_scalar!(lhs, rhs, filter_gt_scalar)
+}
+
+/// Returns the elements of `lhs` that are `>= rhs`, i.e. `filter(lhs, &gt_eq_scalar(lhs, rhs))`,
+/// in a single pass (see [`filter_lt_scalar`]).
+/// Use [`can_filter_scalar`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+pub fn filter_gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    filter_scalar!(lhs, rhs, filter_gt_eq_scalar)
+}
+
+/// Returns whether a [`DataType`] is supported by [`filter_lt_scalar`], [`filter_lt_eq_scalar`],
+/// [`filter_gt_scalar`] and [`filter_gt_eq_scalar`].
+pub fn can_filter_scalar(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        crate::datatypes::PhysicalType::Primitive(_)
+    )
+}
+
+/// `low <= array <= high` (or with the bounds included as per `inclusivity`) between an
+/// [`Array`] and two [`Scalar`]s. When both bounds are included, it is evaluated in a single
+/// pass over `array`. Null slots of `array` are null in the result; if either bound is null,
+/// all slots are null.
+/// As [`gt_eq_scalar`] and [`lt_eq_scalar`], dictionaries are compared by their values.
+/// Use [`can_between_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the array and scalars do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+pub fn between_scalar(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+    inclusivity: Inclusivity,
+) -> BooleanArray {
+    try_between_scalar(array, low, high, inclusivity).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// `low <= array <= high` (or with the bounds included as per `inclusivity`) between an
+/// [`Array`] and two [`Scalar`]s, returning an error instead of panicking.
+/// Use [`can_between_scalar`] to check whether the operation is valid
+/// # Errors
+/// Errors iff either:
+/// * the array and scalars do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_between_scalar(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+    inclusivity: Inclusivity,
+) -> Result<BooleanArray> {
+    check_scalar(array, low, can_between_scalar)?;
+    check_scalar(array, high, can_between_scalar)?;
+    let and = |lhs: BooleanArray, rhs: BooleanArray| compute::boolean::and(&lhs, &rhs);
+    match inclusivity {
+        Inclusivity::Both => between_scalar_inclusive(array, low, high),
+        Inclusivity::Left => and(gt_eq_scalar(array, low), lt_scalar(array, high)),
+        Inclusivity::Right => and(gt_scalar(array, low), lt_eq_scalar(array, high)),
+        Inclusivity::Neither => and(gt_scalar(array, low), lt_scalar(array, high)),
+    }
+}
+
+/// `low < array <= high` between an [`Array`] and two [`Scalar`]s, i.e. [`between_scalar`]
+/// with [`Inclusivity::Right`].
+/// # Panic
+/// Panics iff either:
+/// * the array and scalars do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+pub fn between_scalar_left_open(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+) -> BooleanArray {
+    try_between_scalar_left_open(array, low, high).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`between_scalar_left_open`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the array and scalars do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_between_scalar_left_open(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+) -> Result<BooleanArray> {
+    try_between_scalar(array, low, high, Inclusivity::Right)
+}
+
+/// `low <= array < high` between an [`Array`] and two [`Scalar`]s, i.e. [`between_scalar`]
+/// with [`Inclusivity::Left`].
+/// # Panic
+/// Panics iff either:
+/// * the array and scalars do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+pub fn between_scalar_right_open(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+) -> BooleanArray {
+    try_between_scalar_right_open(array, low, high).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`between_scalar_right_open`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the array and scalars do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_between_scalar_right_open(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+) -> Result<BooleanArray> {
+    try_between_scalar(array, low, high, Inclusivity::Left)
+}
+
+/// `NOT (low <= array <= high)` (SQL's `NOT BETWEEN`) between an [`Array`] and two
+/// [`Scalar`]s, the negation of [`between_scalar`].
+/// Use [`can_not_between_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the array and scalars do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+pub fn not_between_scalar(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+    inclusivity: Inclusivity,
+) -> BooleanArray {
+    try_not_between_scalar(array, low, high, inclusivity)
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`not_between_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * the array and scalars do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `array` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_not_between_scalar(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+    inclusivity: Inclusivity,
+) -> Result<BooleanArray> {
+    Ok(compute::boolean::not(&try_between_scalar(
+        array,
+        low,
+        high,
+        inclusivity,
+    )?))
+}
+
+/// Returns whether a [`DataType`] is supported by [`not_between_scalar`].
+pub fn can_not_between_scalar(data_type: &DataType) -> bool {
+    can_between_scalar(data_type)
+}
+
+/// Returns whether each element of `array` starts with `prefix`, for string and binary arrays.
+/// Null elements of `array` are null in the result, and all elements are null when `prefix` is.
+/// Use [`can_starts_with`] to check whether the operation is valid
+/// # Error
+/// Errors iff either:
+/// * the operation is not supported for the logical type of `array`
+/// * `array` and `prefix` do not have the same logical type
+pub fn starts_with(array: &dyn Array, prefix: &dyn Scalar) -> Result<BooleanArray> {
+    if !can_starts_with(array.data_type()) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "starts_with is not supported for arrays of type {:?}",
+            array.data_type()
+        )));
+    }
+    if array.data_type().to_logical_type() != prefix.data_type().to_logical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "starts_with requires a prefix of the same logical type as the array, {:?}, but it is {:?}",
+            array.data_type(),
+            prefix.data_type()
+        )));
+    }
+    if !prefix.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, array.len()));
+    }
+
+    // types and validity checked above
+    use crate::datatypes::PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Utf8 => {
+            let prefix = prefix.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            utf8::starts_with::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                prefix.value().unwrap(),
+            )
+        }
+        LargeUtf8 => {
+            let prefix = prefix.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            utf8::starts_with::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                prefix.value().unwrap(),
+            )
+        }
+        Binary => {
+            let prefix = prefix.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            binary::starts_with::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                prefix.value().unwrap(),
+            )
+        }
+        LargeBinary => {
+            let prefix = prefix.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            binary::starts_with::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                prefix.value().unwrap(),
+            )
+        }
+        _ => unreachable!(),
+    })
+}
+
+/// Returns whether a [`DataType`] is supported by [`starts_with`].
+pub fn can_starts_with(data_type: &DataType) -> bool {
+    use crate::datatypes::PhysicalType::*;
+    matches!(
+        data_type.to_physical_type(),
+        Utf8 | LargeUtf8 | Binary | LargeBinary
+    )
+}
+
+/// `low <= array <= high` in a single pass over `array`, checked by [`try_between_scalar`].
+fn between_scalar_inclusive(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+) -> Result<BooleanArray> {
+    let is_null = |data_type: &DataType| data_type.to_logical_type() == &DataType::Null;
+    if is_null(array.data_type())
+        || is_null(low.data_type())
+        || is_null(high.data_type())
+        || !low.is_valid()
+        || !high.is_valid()
+    {
+        return Ok(BooleanArray::new_null(DataType::Boolean, array.len()));
+    }
+
+    // validities checked above
+    use crate::datatypes::PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => {
+            let low = low.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            let high = high.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            boolean::between_scalar(
+                array.as_any().downcast_ref().unwrap(),
+                low.value().unwrap(),
+                high.value().unwrap(),
+            )
+        }
+        Primitive(primitive) => match_eq_ord!(primitive, |$T| {
+            let low = low.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let high = high.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            primitive::between_scalar::<$T>(
+                array.as_any().downcast_ref().unwrap(),
+                low.value().unwrap(),
+                high.value().unwrap(),
+            )
+        }),
+        Utf8 => {
+            let low = low.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            let high = high.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            utf8::between_scalar::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                low.value().unwrap(),
+                high.value().unwrap(),
+            )
+        }
+        LargeUtf8 => {
+            let low = low.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            let high = high.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            utf8::between_scalar::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                low.value().unwrap(),
+                high.value().unwrap(),
+            )
+        }
+        Binary => {
+            let low = low.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            let high = high.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            binary::between_scalar::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                low.value().unwrap(),
+                high.value().unwrap(),
+            )
+        }
+        LargeBinary => {
+            let low = low.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            let high = high.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            binary::between_scalar::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                low.value().unwrap(),
+                high.value().unwrap(),
+            )
+        }
+        FixedSizeBinary => {
+            let low = low
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            let high = high
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            fixed_size_binary::between_scalar(
+                array.as_any().downcast_ref().unwrap(),
+                low.value().unwrap(),
+                high.value().unwrap(),
+            )
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let low = low.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+            let high = high.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+
+            let values = between_scalar_inclusive(
+                array.values().as_ref(),
+                low.value().unwrap().as_ref(),
+                high.value().unwrap().as_ref(),
+            )?;
+            take_boolean(&values, array.keys())
+        }),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "between is not yet supported for {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
+
+/// Returns whether a [`DataType`] is supported by [`between_scalar`].
+pub fn can_between_scalar(data_type: &DataType) -> bool {
+    can_partial_ord_scalar(data_type)
+}
+
+/// Same as [`eq_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_eq_scalar)?;
+    if let Some(result) = dictionary_eq_scalar(lhs, rhs) {
+        return Ok(result);
+    }
+    compare_scalar!(lhs, rhs, eq_scalar, match_eq)
+}
+
+/// Same as [`neq_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_neq_scalar)?;
+    compare_scalar!(lhs, rhs, neq_scalar, match_eq)
+}
+
+/// Same as [`lt_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_lt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_lt_scalar)?;
+    compare_scalar!(lhs, rhs, lt_scalar, match_eq_ord, Some(true))
+}
+
+/// Same as [`lt_eq_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_lt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_lt_eq_scalar)?;
+    compare_scalar!(lhs, rhs, lt_eq_scalar, match_eq_ord, Some(true))
+}
+
+/// Same as [`gt_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_gt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_gt_scalar)?;
+    compare_scalar!(lhs, rhs, gt_scalar, match_eq_ord, Some(false))
+}
+
+/// Same as [`gt_eq_scalar`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `lhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    check_scalar(lhs, rhs, can_gt_eq_scalar)?;
+    compare_scalar!(lhs, rhs, gt_eq_scalar, match_eq_ord, Some(false))
+}
+
+/// `==` between a [`Scalar`] and an [`Array`], i.e. [`eq_scalar`] with its arguments swapped.
+/// The result is null when `lhs` is null.
+/// Use [`can_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn eq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> BooleanArray {
+    try_eq_scalar_lhs(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`eq_scalar_lhs`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_eq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> Result<BooleanArray> {
+    try_eq_scalar(rhs, lhs)
+}
+
+/// `!=` between a [`Scalar`] and an [`Array`], i.e. [`neq_scalar`] with its arguments swapped.
+/// The result is null when `lhs` is null.
+/// Use [`can_neq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn neq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> BooleanArray {
+    try_neq_scalar_lhs(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`neq_scalar_lhs`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_neq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> Result<BooleanArray> {
+    try_neq_scalar(rhs, lhs)
+}
+
+/// `<` between a [`Scalar`] and an [`Array`], i.e. [`gt_scalar`] with its arguments swapped.
+/// The result is null when `lhs` is null.
+/// Use [`can_gt_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn lt_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> BooleanArray {
+    try_lt_scalar_lhs(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`lt_scalar_lhs`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_lt_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> Result<BooleanArray> {
+    try_gt_scalar(rhs, lhs)
+}
+
+/// `<=` between a [`Scalar`] and an [`Array`], i.e. [`gt_eq_scalar`] with its arguments swapped.
+/// The result is null when `lhs` is null.
+/// Use [`can_gt_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn lt_eq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> BooleanArray {
+    try_lt_eq_scalar_lhs(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`lt_eq_scalar_lhs`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_lt_eq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> Result<BooleanArray> {
+    try_gt_eq_scalar(rhs, lhs)
+}
+
+/// `>` between a [`Scalar`] and an [`Array`], i.e. [`lt_scalar`] with its arguments swapped.
+/// The result is null when `lhs` is null.
+/// Use [`can_lt_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn gt_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> BooleanArray {
+    try_gt_scalar_lhs(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`gt_scalar_lhs`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_gt_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> Result<BooleanArray> {
+    try_lt_scalar(rhs, lhs)
+}
+
+/// `>=` between a [`Scalar`] and an [`Array`], i.e. [`lt_eq_scalar`] with its arguments swapped.
+/// The result is null when `lhs` is null.
+/// Use [`can_lt_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+pub fn gt_eq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> BooleanArray {
+    try_gt_eq_scalar_lhs(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Same as [`gt_eq_scalar_lhs`], but returns an error instead of panicking.
+/// # Errors
+/// Errors iff either:
+/// * they do not have the same logical type ([`ArrowError::InvalidArgumentError`])
+/// * the operation is not supported for the logical type ([`ArrowError::NotYetImplemented`])
+/// * `rhs` is a [`DictionaryArray`] with keys out of range of its values
+///   ([`ArrowError::OutOfSpec`])
+pub fn try_gt_eq_scalar_lhs(lhs: &dyn Scalar, rhs: &dyn Array) -> Result<BooleanArray> {
+    try_lt_eq_scalar(rhs, lhs)
+}
+
+/// Returns whether comparing two [`Array`]s of `data_type` (e.g. via [`eq`] or [`lt`])
+/// materializes intermediate arrays before comparing them, which a planner may want to avoid:
+/// * dictionaries are compared by their values, taken by their keys (see [`compute::take`]);
+/// * structs, lists (including fixed-size lists) and maps combine the comparisons of their children.
+///
+/// Comparing decimals of different scales also materializes them at a common scale.
+pub fn requires_materialization(data_type: &DataType) -> bool {
+    use crate::datatypes::PhysicalType::*;
+    matches!(
+        data_type.to_physical_type(),
+        Dictionary(_) | Struct | List | LargeList | FixedSizeList | Map
+    )
+}
+
+/// Returns the estimated number of bytes allocated by comparing two [`Array`]s of `data_type`
+/// and length `len` (e.g. via [`eq`] or [`lt`]): its result (values and validity) and the
+/// arrays materialized to compute it (see [`requires_materialization`]).
+///
+/// The values of variable-sized types (e.g. of [`Utf8Array`]) materialized from dictionaries
+/// are not included, and variable-sized lists (and maps) are assumed to have one value each.
+pub fn estimated_output_bytes(data_type: &DataType, len: usize) -> usize {
+    use crate::bitmap::utils::bytes_for;
+    let result = 2 * bytes_for(len);
+    result
+        + match data_type.to_logical_type() {
+            DataType::Dictionary(_, values, _) => 2 * materialized_bytes(values.as_ref(), len),
+            DataType::Struct(fields) => fields
+                .iter()
+                .map(|field| estimated_output_bytes(field.data_type(), len))
+                .sum(),
+            DataType::List(field) | DataType::LargeList(field) | DataType::Map(field, _) => {
+                estimated_output_bytes(field.data_type(), len)
+            }
+            DataType::FixedSizeList(field, size) => {
+                estimated_output_bytes(field.data_type(), len * size)
+            }
+            _ => 0,
+        }
+}
+
+/// Returns the estimated number of bytes of an [`Array`] of `data_type` and length `len`
+/// materialized by [`compute::take`], excluding the values of variable-sized types.
+fn materialized_bytes(data_type: &DataType, len: usize) -> usize {
+    use crate::bitmap::utils::bytes_for;
+    use crate::datatypes::PhysicalType::*;
+    let validity = bytes_for(len);
+    validity
+        + match data_type.to_physical_type() {
+            Null => return 0,
+            Boolean => bytes_for(len),
+            Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+                len * std::mem::size_of::<$T>()
+            }),
+            Binary | Utf8 | List | Map => (len + 1) * std::mem::size_of::<i32>(),
+            LargeBinary | LargeUtf8 | LargeList => (len + 1) * std::mem::size_of::<i64>(),
+            FixedSizeBinary => len * FixedSizeBinaryArray::get_size(data_type),
+            Struct => StructArray::get_fields(data_type)
+                .iter()
+                .map(|field| materialized_bytes(field.data_type(), len))
+                .sum(),
+            _ => 0,
+        }
 }
 
 // The list of operations currently supported.
@@ -462,7 +3002,7 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::Int32
             | DataType::Date32
             | DataType::Time32(_)
-            | DataType::Interval(IntervalUnit::YearMonth)
+            | DataType::Interval(_)
             | DataType::Int64
             | DataType::Timestamp(_, _)
             | DataType::Date64
@@ -479,76 +3019,87 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::Decimal(_, _)
             | DataType::Binary
             | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
     )
 }
 
+// The list of operations currently supported.
+fn can_partial_ord(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => return can_partial_ord(values.as_ref()),
+        DataType::Null => return true,
+        _ => {}
+    }
+    can_partial_eq_and_ord(data_type)
+}
+
 // The list of operations currently supported.
 fn can_partial_eq(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => return can_partial_eq(values.as_ref()),
+        DataType::Struct(fields) => {
+            return fields.iter().all(|field| can_partial_eq(field.data_type()))
+        }
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => return can_partial_eq(field.data_type()),
+        DataType::Null => return true,
+        _ => {}
+    }
     can_partial_eq_and_ord(data_type)
-        || matches!(
-            data_type.to_logical_type(),
-            DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
-        )
 }
 
 // The list of operations currently supported.
 fn can_partial_eq_scalar(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord_scalar(data_type)
-        || matches!(
-            data_type.to_logical_type(),
-            DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
-        )
+    data_type.to_logical_type() == &DataType::Null || can_partial_eq_and_ord_scalar(data_type)
+}
+
+// The list of operations currently supported.
+fn can_partial_ord_scalar(data_type: &DataType) -> bool {
+    data_type.to_logical_type() == &DataType::Null || can_partial_eq_and_ord_scalar(data_type)
 }
 
+/// Returns `validity` when it has nulls. A validity without nulls is equivalent to no validity,
+/// so that the (more expensive) bitmap operations can be skipped for it.
+fn with_nulls(validity: Option<Bitmap>) -> Option<Bitmap> {
+    validity.filter(|validity| validity.null_count() > 0)
+}
+
+/// Returns `output_without_validities AND (validity_lhs == validity_rhs)`, i.e. slots are equal
+/// when their values are equal and they are either both valid or both null.
+/// The validities are used as they are (e.g. already sliced) and those without nulls are skipped.
 fn finish_eq_validities(
     output_without_validities: BooleanArray,
     validity_lhs: Option<Bitmap>,
     validity_rhs: Option<Bitmap>,
 ) -> BooleanArray {
-    match (validity_lhs, validity_rhs) {
-        (None, None) => output_without_validities,
-        (Some(lhs), None) => compute::boolean::and(
-            &BooleanArray::new(DataType::Boolean, lhs, None),
-            &output_without_validities,
-        )
-        .unwrap(),
-        (None, Some(rhs)) => compute::boolean::and(
-            &output_without_validities,
-            &BooleanArray::new(DataType::Boolean, rhs, None),
-        )
-        .unwrap(),
-        (Some(lhs), Some(rhs)) => {
-            let lhs = BooleanArray::new(DataType::Boolean, lhs, None);
-            let rhs = BooleanArray::new(DataType::Boolean, rhs, None);
-            let eq_validities = compute::comparison::boolean::eq(&lhs, &rhs);
-            compute::boolean::and(&output_without_validities, &eq_validities).unwrap()
-        }
-    }
+    let out = output_without_validities.values();
+    let values = match (with_nulls(validity_lhs), with_nulls(validity_rhs)) {
+        (None, None) => return output_without_validities,
+        (Some(validity), None) | (None, Some(validity)) => out & &validity,
+        (Some(lhs), Some(rhs)) => ternary(out, &lhs, &rhs, |out, lhs, rhs| out & !(lhs ^ rhs)),
+    };
+    let validity = output_without_validities.validity().cloned();
+    BooleanArray::new(DataType::Boolean, values, validity)
 }
+
+/// Returns `output_without_validities OR (validity_lhs != validity_rhs)`, i.e. slots are
+/// different when their values are different or only one of them is null.
+/// The validities are used as they are (e.g. already sliced) and those without nulls are skipped.
 fn finish_neq_validities(
     output_without_validities: BooleanArray,
     validity_lhs: Option<Bitmap>,
     validity_rhs: Option<Bitmap>,
 ) -> BooleanArray {
-    match (validity_lhs, validity_rhs) {
-        (None, None) => output_without_validities,
-        (Some(lhs), None) => {
-            let lhs_negated =
-                compute::boolean::not(&BooleanArray::new(DataType::Boolean, lhs, None));
-            compute::boolean::or(&lhs_negated, &output_without_validities).unwrap()
-        }
-        (None, Some(rhs)) => {
-            let rhs_negated =
-                compute::boolean::not(&BooleanArray::new(DataType::Boolean, rhs, None));
-            compute::boolean::or(&output_without_validities, &rhs_negated).unwrap()
+    let out = output_without_validities.values();
+    let values = match (with_nulls(validity_lhs), with_nulls(validity_rhs)) {
+        (None, None) => return output_without_validities,
+        (Some(validity), None) | (None, Some(validity)) => {
+            binary(out, &validity, |out, validity| out | !validity)
         }
-        (Some(lhs), Some(rhs)) => {
-            let lhs = BooleanArray::new(DataType::Boolean, lhs, None);
-            let rhs = BooleanArray::new(DataType::Boolean, rhs, None);
-            let neq_validities = compute::comparison::boolean::neq(&lhs, &rhs);
-            compute::boolean::or(&output_without_validities, &neq_validities).unwrap()
-        }
-    }
+        (Some(lhs), Some(rhs)) => ternary(out, &lhs, &rhs, |out, lhs, rhs| out | (lhs ^ rhs)),
+    };
+    let validity = output_without_validities.validity().cloned();
+    BooleanArray::new(DataType::Boolean, values, validity)
 }
@@ -1,8 +1,19 @@
 //! Comparison functions for [`PrimitiveArray`]
+//!
+//! Intervals ([`days_ms`](crate::types::days_ms) and
+//! [`months_days_ns`](crate::types::months_days_ns)) are ordered lexicographically by their
+//! fields (e.g. months, then days, then nanoseconds). This is a total order, but it does not
+//! compare their calendar durations: `1 month` is greater than `40 days`.
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use num_traits::Float;
+
 use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
 use crate::{
     array::{BooleanArray, PrimitiveArray},
-    bitmap::MutableBitmap,
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
     types::NativeType,
 };
@@ -10,7 +21,32 @@ use crate::{
 use super::super::utils::combine_validities;
 use super::simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
-pub(crate) fn compare_values_op<T, F>(lhs: &[T], rhs: &[T], op: F) -> MutableBitmap
+/// The number of bytes buffered on the stack before being appended to a [`MutableBitmap`].
+const BUFFER_LENGTH: usize = 64;
+
+/// Appends the bytes of `iterator`, representing `length` bits, to `out`,
+/// buffering them on the stack so that no intermediate allocation is needed.
+fn extend_from_bytes<I: Iterator<Item = u8>>(out: &mut MutableBitmap, iterator: I, length: usize) {
+    out.reserve(length);
+    let mut buffer = [0u8; BUFFER_LENGTH];
+    let mut remaining = length;
+    let mut used = 0;
+    for byte in iterator {
+        buffer[used] = byte;
+        used += 1;
+        if used == BUFFER_LENGTH {
+            // the last byte may be incomplete when the buffer is full at the end of `iterator`
+            let bits = (BUFFER_LENGTH * 8).min(remaining);
+            out.extend_from_slice(&buffer, 0, bits);
+            remaining -= bits;
+            used = 0;
+        }
+    }
+    out.extend_from_slice(&buffer, 0, remaining);
+}
+
+/// Appends `op(lhs, rhs)` to `out`, one bit per value.
+pub(crate) fn compare_values_op_into<T, F>(lhs: &[T], rhs: &[T], op: F, out: &mut MutableBitmap)
 where
     T: NativeType + Simd8,
     F: Fn(T::Simd, T::Simd) -> u8,
@@ -22,23 +58,34 @@ where
     let rhs_chunks_iter = rhs.chunks_exact(8);
     let rhs_remainder = rhs_chunks_iter.remainder();
 
-    let mut values = Vec::with_capacity((lhs.len() + 7) / 8);
     let iterator = lhs_chunks_iter.zip(rhs_chunks_iter).map(|(lhs, rhs)| {
         let lhs = T::Simd::from_chunk(lhs);
         let rhs = T::Simd::from_chunk(rhs);
         op(lhs, rhs)
     });
-    values.extend(iterator);
 
-    if !lhs_remainder.is_empty() {
+    let remainder = if !lhs_remainder.is_empty() {
         let lhs = T::Simd::from_incomplete_chunk(lhs_remainder, T::default());
         let rhs = T::Simd::from_incomplete_chunk(rhs_remainder, T::default());
-        values.push(op(lhs, rhs))
+        Some(op(lhs, rhs))
+    } else {
+        None
     };
-    MutableBitmap::from_vec(values, lhs.len())
+    extend_from_bytes(out, iterator.chain(remainder), lhs.len())
 }
 
-pub(crate) fn compare_values_op_scalar<T, F>(lhs: &[T], rhs: T, op: F) -> MutableBitmap
+pub(crate) fn compare_values_op<T, F>(lhs: &[T], rhs: &[T], op: F) -> MutableBitmap
+where
+    T: NativeType + Simd8,
+    F: Fn(T::Simd, T::Simd) -> u8,
+{
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    compare_values_op_into(lhs, rhs, op, &mut values);
+    values
+}
+
+/// Appends `op(lhs, rhs)` to `out`, one bit per value of `lhs`.
+pub(crate) fn compare_values_op_scalar_into<T, F>(lhs: &[T], rhs: T, op: F, out: &mut MutableBitmap)
 where
     T: NativeType + Simd8,
     F: Fn(T::Simd, T::Simd) -> u8,
@@ -48,19 +95,28 @@ where
     let lhs_chunks_iter = lhs.chunks_exact(8);
     let lhs_remainder = lhs_chunks_iter.remainder();
 
-    let mut values = Vec::with_capacity((lhs.len() + 7) / 8);
     let iterator = lhs_chunks_iter.map(|lhs| {
         let lhs = T::Simd::from_chunk(lhs);
         op(lhs, rhs)
     });
-    values.extend(iterator);
 
-    if !lhs_remainder.is_empty() {
+    let remainder = if !lhs_remainder.is_empty() {
         let lhs = T::Simd::from_incomplete_chunk(lhs_remainder, T::default());
-        values.push(op(lhs, rhs))
+        Some(op(lhs, rhs))
+    } else {
+        None
     };
+    extend_from_bytes(out, iterator.chain(remainder), lhs.len())
+}
 
-    MutableBitmap::from_vec(values, lhs.len())
+pub(crate) fn compare_values_op_scalar<T, F>(lhs: &[T], rhs: T, op: F) -> MutableBitmap
+where
+    T: NativeType + Simd8,
+    F: Fn(T::Simd, T::Simd) -> u8,
+{
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    compare_values_op_scalar_into(lhs, rhs, op, &mut values);
+    values
 }
 
 /// Evaluate `op(lhs, rhs)` for [`PrimitiveArray`]s using a specified
@@ -97,7 +153,69 @@ where
     T: NativeType + Simd8,
     T::Simd: Simd8PartialEq,
 {
-    compare_op(lhs, rhs, |a, b| a.eq(b))
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    eq_into(lhs, rhs, &mut values);
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
+/// Appends the result of `lhs == rhs` to `out`, one bit per slot, without allocating a new
+/// [`BooleanArray`]. This allows re-using `out` across comparisons, e.g. via
+/// [`MutableBitmap::clear`].
+///
+/// The length of `out` grows by `lhs.len()`; `out` is reserved to hold them, so a bitmap with
+/// enough capacity is never re-allocated. The validities of `lhs` and `rhs` are ignored:
+/// the bits of null slots are the comparison of their (unspecified) values and must be
+/// combined with the validities by the caller.
+/// # Panics
+/// iff `lhs.len() != rhs.len()`.
+pub fn eq_into<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>, out: &mut MutableBitmap)
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialEq,
+{
+    compare_values_op_into(lhs.values(), rhs.values(), |a, b| a.eq(b), out)
+}
+
+/// Returns whether `lhs` and `rhs` share the same values and validity (i.e. they are views
+/// over the same region of the same buffers).
+fn is_identical<T: NativeType>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> bool {
+    let same_values = lhs.len() == rhs.len()
+        && std::ptr::eq(
+            lhs.values().as_slice().as_ptr(),
+            rhs.values().as_slice().as_ptr(),
+        );
+    let same_validity = match (lhs.validity(), rhs.validity()) {
+        (None, None) => true,
+        (Some(lhs), Some(rhs)) => {
+            let (lhs, lhs_offset, _) = lhs.as_slice();
+            let (rhs, rhs_offset, _) = rhs.as_slice();
+            lhs_offset == rhs_offset && std::ptr::eq(lhs.as_ptr(), rhs.as_ptr())
+        }
+        _ => false,
+    };
+    same_values && same_validity
+}
+
+/// Returns the result of `lhs == rhs` without comparing values when both arrays are
+/// identical (see [`is_identical`]): every valid slot is equal to itself.
+/// This must not be used for floats, since `NaN != NaN`.
+pub(super) fn eq_identical<T: NativeType>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Option<BooleanArray> {
+    if is_identical(lhs, rhs) {
+        let values = Bitmap::from(MutableBitmap::from_len_set(lhs.len()));
+        Some(BooleanArray::new(
+            DataType::Boolean,
+            values,
+            lhs.validity().cloned(),
+        ))
+    } else {
+        None
+    }
 }
 
 /// Perform `lhs == rhs` operation on two arrays and include validities in comparison.
@@ -121,7 +239,22 @@ where
     T: NativeType + Simd8,
     T::Simd: Simd8PartialEq,
 {
-    compare_op_scalar(lhs, rhs, |a, b| a.eq(b))
+    let validity = lhs.validity().cloned();
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    eq_scalar_into(lhs, rhs, &mut values);
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
+/// Appends the result of `lhs == rhs` to `out`, one bit per slot of `lhs`.
+/// See [`eq_into`] for the contract on `out` and on validities.
+pub fn eq_scalar_into<T>(lhs: &PrimitiveArray<T>, rhs: T, out: &mut MutableBitmap)
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialEq,
+{
+    compare_values_op_scalar_into(lhs.values(), rhs, |a, b| a.eq(b), out)
 }
 
 /// Perform `left == right` operation on an array and a scalar value and include validities in comparison.
@@ -137,6 +270,51 @@ where
     finish_eq_validities(out, validity, None)
 }
 
+/// Perform `left == right` operation on an array and a value per slot, e.g. a bound computed
+/// for each row, without materializing the values into a second array.
+///
+/// The `i`-th item of `rhs` is compared with the `i`-th slot of `lhs`. A null slot or a `None`
+/// item results in a null slot.
+/// # Panic
+/// Panics iff `rhs` does not yield exactly `lhs.len()` items.
+pub fn eq_values<T, I>(lhs: &PrimitiveArray<T>, rhs: I) -> BooleanArray
+where
+    T: NativeType,
+    I: IntoIterator<Item = Option<T>>,
+{
+    let mut rhs = rhs.into_iter();
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    let mut rhs_validity = MutableBitmap::with_capacity(lhs.len());
+    lhs.values()
+        .iter()
+        .zip(rhs.by_ref())
+        .for_each(|(lhs, rhs)| match rhs {
+            Some(rhs) => {
+                values.push(*lhs == rhs);
+                rhs_validity.push(true);
+            }
+            None => {
+                values.push(false);
+                rhs_validity.push(false);
+            }
+        });
+    assert_eq!(
+        values.len(),
+        lhs.len(),
+        "eq_values requires one value per slot, but fewer were yielded"
+    );
+    assert!(
+        rhs.next().is_none(),
+        "eq_values requires one value per slot, but more were yielded"
+    );
+
+    let rhs_validity: Option<Bitmap> = rhs_validity.into();
+    let validity = combine_validities(lhs.validity(), rhs_validity.as_ref());
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
 /// Perform `left != right` operation on two arrays.
 pub fn neq<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray
 where
@@ -260,6 +438,445 @@ where
     compare_op_scalar(lhs, rhs, |a, b| a.gt_eq(b))
 }
 
+/// Returns the values of `lhs` for which `op(lhs, rhs)` is true and whose bit in `validity`
+/// (one byte per chunk of 8 values) is set, in a single pass over `lhs`.
+fn filter_values_op_scalar<T, F, I>(lhs: &[T], rhs: T, mut validity: I, op: F) -> Vec<T>
+where
+    T: NativeType + Simd8,
+    F: Fn(T::Simd, T::Simd) -> u8,
+    I: Iterator<Item = u8>,
+{
+    let rhs = T::Simd::from_chunk(&[rhs; 8]);
+
+    let chunks = lhs.chunks_exact(8);
+    let remainder = chunks.remainder();
+
+    let mut values = vec![];
+    // pushes the values of `chunk` whose bit in `mask` is set
+    let mut gather = |chunk: &[T], mut mask: u8| {
+        while mask != 0 {
+            values.push(chunk[mask.trailing_zeros() as usize]);
+            mask &= mask - 1;
+        }
+    };
+    for (chunk, is_valid) in chunks.zip(&mut validity) {
+        gather(chunk, op(T::Simd::from_chunk(chunk), rhs) & is_valid);
+    }
+    if !remainder.is_empty() {
+        let lhs = T::Simd::from_incomplete_chunk(remainder, T::default());
+        let is_valid = validity.next().unwrap_or(0) & ((1u8 << remainder.len()) - 1);
+        gather(remainder, op(lhs, rhs) & is_valid);
+    }
+    values
+}
+
+/// Returns the valid elements of `lhs` for which `op(lhs, rhs)` is true, i.e.
+/// `filter(lhs, &compare_op_scalar(lhs, rhs, op))`, without materializing the [`BooleanArray`]
+/// of the comparison.
+fn filter_op_scalar<T, F>(lhs: &PrimitiveArray<T>, rhs: T, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType + Simd8,
+    F: Fn(T::Simd, T::Simd) -> u8,
+{
+    let values = match lhs.validity() {
+        Some(validity) if validity.null_count() > 0 => {
+            let chunks = validity.chunks::<u8>();
+            let remainder = chunks.remainder();
+            filter_values_op_scalar(
+                lhs.values(),
+                rhs,
+                chunks.chain(std::iter::once(remainder)),
+                op,
+            )
+        }
+        _ => filter_values_op_scalar(lhs.values(), rhs, std::iter::repeat(u8::MAX), op),
+    };
+    PrimitiveArray::new(lhs.data_type().clone(), values.into(), None)
+}
+
+/// Returns the elements of `lhs` that are `< rhs`, i.e. `filter(lhs, &lt_scalar(lhs, rhs))`
+/// in a single pass. Null elements are not returned.
+pub fn filter_lt_scalar<T>(lhs: &PrimitiveArray<T>, rhs: T) -> PrimitiveArray<T>
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    filter_op_scalar(lhs, rhs, |a, b| a.lt(b))
+}
+
+/// Returns the elements of `lhs` that are `<= rhs`, i.e. `filter(lhs, &lt_eq_scalar(lhs, rhs))`
+/// in a single pass. Null elements are not returned.
+pub fn filter_lt_eq_scalar<T>(lhs: &PrimitiveArray<T>, rhs: T) -> PrimitiveArray<T>
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    filter_op_scalar(lhs, rhs, |a, b| a.lt_eq(b))
+}
+
+/// Returns the elements of `lhs` that are `> rhs`, i.e. `filter(lhs, &gt_scalar(lhs, rhs))`
+/// in a single pass. Null elements are not returned.
+pub fn filter_gt_scalar<T>(lhs: &PrimitiveArray<T>, rhs: T) -> PrimitiveArray<T>
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    filter_op_scalar(lhs, rhs, |a, b| a.gt(b))
+}
+
+/// Returns the elements of `lhs` that are `>= rhs`, i.e. `filter(lhs, &gt_eq_scalar(lhs, rhs))`
+/// in a single pass. Null elements are not returned.
+pub fn filter_gt_eq_scalar<T>(lhs: &PrimitiveArray<T>, rhs: T) -> PrimitiveArray<T>
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    filter_op_scalar(lhs, rhs, |a, b| a.gt_eq(b))
+}
+
+/// A [`NativeType`] with a total order, used by the `*_total` kernels such as [`lt_total`].
+///
+/// Floats are ordered according to IEEE 754's `totalOrder` predicate:
+/// `-NaN < -inf < ... < -0.0 < +0.0 < ... < +inf < +NaN`.
+pub trait TotalOrd: NativeType {
+    /// Returns the total [`Ordering`] between `self` and `other`.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl TotalOrd for f32 {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        crate::array::ord::total_cmp_f32(self, other)
+    }
+}
+
+impl TotalOrd for f64 {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        crate::array::ord::total_cmp_f64(self, other)
+    }
+}
+
+/// Evaluate `op(lhs.total_cmp(rhs))` for [`PrimitiveArray`]s.
+fn compare_op_total<T, F>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>, op: F) -> BooleanArray
+where
+    T: TotalOrd,
+    F: Fn(Ordering) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| op(lhs.total_cmp(rhs)));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `left < right` operation on two arrays using a total order (see [`TotalOrd`]).
+pub fn lt_total<T: TotalOrd>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray {
+    compare_op_total(lhs, rhs, |x| x == Ordering::Less)
+}
+
+/// Perform `left <= right` operation on two arrays using a total order (see [`TotalOrd`]).
+pub fn lt_eq_total<T: TotalOrd>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray {
+    compare_op_total(lhs, rhs, |x| x != Ordering::Greater)
+}
+
+/// Perform `left > right` operation on two arrays using a total order (see [`TotalOrd`]).
+pub fn gt_total<T: TotalOrd>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray {
+    compare_op_total(lhs, rhs, |x| x == Ordering::Greater)
+}
+
+/// Perform `left >= right` operation on two arrays using a total order (see [`TotalOrd`]).
+pub fn gt_eq_total<T: TotalOrd>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray {
+    compare_op_total(lhs, rhs, |x| x != Ordering::Less)
+}
+
+/// A float [`NativeType`] whose equality treats all `NaN`s as equal, used by the `*_nan`
+/// kernels such as [`eq_nan`].
+///
+/// This deviates from IEEE 754 and from SQL, where `NaN` differs from every value including
+/// itself, and is intended for e.g. grouping or deduplicating values. As in IEEE 754,
+/// `+0.0` equals `-0.0`.
+pub trait NanEq: NativeType {
+    /// Returns whether `self` equals `other`, where all `NaN`s are equal.
+    fn nan_eq(&self, other: &Self) -> bool;
+}
+
+impl NanEq for f32 {
+    #[inline]
+    fn nan_eq(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
+}
+
+impl NanEq for f64 {
+    #[inline]
+    fn nan_eq(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
+}
+
+/// Perform `left == right` operation on two arrays where all `NaN`s are equal (see [`NanEq`]).
+/// A null slot in either `lhs` or `rhs` results in a null slot.
+/// # Panics
+/// iff `lhs.len() != rhs.len()`.
+pub fn eq_nan<T: NanEq>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray {
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| lhs.nan_eq(rhs));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `left == right` operation on an array and a scalar value where all `NaN`s are equal
+/// (see [`NanEq`]).
+pub fn eq_nan_scalar<T: NanEq>(lhs: &PrimitiveArray<T>, rhs: T) -> BooleanArray {
+    let validity = lhs.validity().cloned();
+
+    let values = lhs.values().iter().map(|lhs| lhs.nan_eq(&rhs));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `left >= low AND left <= high` operation on an array and two scalar values.
+/// Both comparisons and the `AND` are evaluated in a single pass over the values.
+pub fn between_scalar<T>(lhs: &PrimitiveArray<T>, low: T, high: T) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    let high = T::Simd::from_chunk(&[high; 8]);
+    compare_op_scalar(lhs, low, |a, low| a.gt_eq(low) & a.lt_eq(high))
+}
+
+/// Perform `array >= low AND array <= high` operation on three arrays.
+/// Both comparisons and the `AND` are evaluated in a single pass over the values.
+/// A null slot in any of the arrays results in a null slot.
+/// # Panics
+/// iff the arrays do not have the same length.
+pub fn between<T>(
+    array: &PrimitiveArray<T>,
+    low: &PrimitiveArray<T>,
+    high: &PrimitiveArray<T>,
+) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    assert_eq!(array.len(), low.len());
+    assert_eq!(array.len(), high.len());
+    let validity = combine_validities(array.validity(), low.validity());
+    let validity = combine_validities(validity.as_ref(), high.validity());
+
+    let array_chunks = array.values().chunks_exact(8);
+    let low_chunks = low.values().chunks_exact(8);
+    let high_chunks = high.values().chunks_exact(8);
+    let array_remainder = array_chunks.remainder();
+    let low_remainder = low_chunks.remainder();
+    let high_remainder = high_chunks.remainder();
+
+    let op = |a: T::Simd, low: T::Simd, high: T::Simd| a.gt_eq(low) & a.lt_eq(high);
+    let iterator = array_chunks
+        .zip(low_chunks)
+        .zip(high_chunks)
+        .map(|((a, low), high)| {
+            op(
+                T::Simd::from_chunk(a),
+                T::Simd::from_chunk(low),
+                T::Simd::from_chunk(high),
+            )
+        });
+    let remainder = if !array_remainder.is_empty() {
+        Some(op(
+            T::Simd::from_incomplete_chunk(array_remainder, T::default()),
+            T::Simd::from_incomplete_chunk(low_remainder, T::default()),
+            T::Simd::from_incomplete_chunk(high_remainder, T::default()),
+        ))
+    } else {
+        None
+    };
+
+    let mut values = MutableBitmap::with_capacity(array.len());
+    extend_from_bytes(&mut values, iterator.chain(remainder), array.len());
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
+/// Returns whether each element of `array` is one of the (non-null) elements of `values`.
+/// Null elements of `array` are null in the result.
+pub fn is_in<T>(array: &PrimitiveArray<T>, values: &PrimitiveArray<T>) -> BooleanArray
+where
+    T: NativeType + Hash + Eq,
+{
+    let values = if values.len() <= super::IS_IN_MAX_LINEAR_SCAN {
+        let values = values.iter().flatten().collect::<Vec<_>>();
+        Bitmap::from_trusted_len_iter(array.values().iter().map(|x| values.contains(&x)))
+    } else {
+        let values = values.iter().flatten().collect::<HashSet<_>>();
+        Bitmap::from_trusted_len_iter(array.values().iter().map(|x| values.contains(x)))
+    };
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns whether each element of `array` is one of the (non-null) elements of `values`,
+/// where elements are equal as per [`eq`]: `NaN` is never in `values` and `+0.0` equals `-0.0`.
+/// Null elements of `array` are null in the result.
+pub fn is_in_float<T>(array: &PrimitiveArray<T>, values: &PrimitiveArray<T>) -> BooleanArray
+where
+    T: NativeType + Float,
+{
+    // `+ 0.0` maps `-0.0` to `+0.0`, so that equal values have the same key
+    let key = |x: T| (x + T::zero()).integer_decode();
+    let values = if values.len() <= super::IS_IN_MAX_LINEAR_SCAN {
+        let values = values.iter().flatten().copied().collect::<Vec<_>>();
+        Bitmap::from_trusted_len_iter(array.values().iter().map(|x| values.contains(x)))
+    } else {
+        let values = values
+            .iter()
+            .flatten()
+            .filter(|x| !x.is_nan())
+            .map(|x| key(*x))
+            .collect::<HashSet<_>>();
+        Bitmap::from_trusted_len_iter(
+            array
+                .values()
+                .iter()
+                .map(|x| !x.is_nan() && values.contains(&key(*x))),
+        )
+    };
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns the [`Ordering`] of each pair of elements of `lhs` and `rhs` according to `cmp`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn compare_by<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    cmp: F,
+) -> PrimitiveArray<i8>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| cmp(lhs, rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), validity)
+}
+
+/// Returns the [`Ordering`] of each element of `lhs` with `rhs` according to `cmp`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// Null elements of `lhs` are null in the result.
+pub fn compare_scalar_by<T, F>(lhs: &PrimitiveArray<T>, rhs: T, cmp: F) -> PrimitiveArray<i8>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let values = lhs
+        .values()
+        .iter()
+        .map(|lhs| cmp(lhs, &rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), lhs.validity().cloned())
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the smallest of the two according
+/// to `cmp`. Ties are resolved in favour of `lhs`.
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn min_by<T, F>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>, cmp: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    select_op(lhs, rhs, |lhs, rhs| cmp(lhs, rhs) != Ordering::Greater)
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the largest of the two according
+/// to `cmp`. Ties are resolved in favour of `lhs`.
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn max_by<T, F>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>, cmp: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    select_op(lhs, rhs, |lhs, rhs| cmp(lhs, rhs) != Ordering::Less)
+}
+
+/// Returns, for each element of `lhs`, the smallest of it and `rhs` according to `cmp`.
+/// Null elements of `lhs` are null in the result.
+pub fn min_scalar_by<T, F>(lhs: &PrimitiveArray<T>, rhs: T, cmp: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    select_op_scalar(lhs, rhs, |lhs, rhs| cmp(lhs, rhs) != Ordering::Greater)
+}
+
+/// Returns, for each element of `lhs`, the largest of it and `rhs` according to `cmp`.
+/// Null elements of `lhs` are null in the result.
+pub fn max_scalar_by<T, F>(lhs: &PrimitiveArray<T>, rhs: T, cmp: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    select_op_scalar(lhs, rhs, |lhs, rhs| cmp(lhs, rhs) != Ordering::Less)
+}
+
+/// Selects the element of `lhs` when `op(lhs, rhs)` is true and of `rhs` otherwise.
+fn select_op<T, F>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| if op(lhs, rhs) { *lhs } else { *rhs })
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(lhs.data_type().clone(), values.into(), validity)
+}
+
+/// Selects the element of `lhs` when `op(lhs, rhs)` is true and `rhs` otherwise.
+fn select_op_scalar<T, F>(lhs: &PrimitiveArray<T>, rhs: T, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> bool,
+{
+    let values = lhs
+        .values()
+        .iter()
+        .map(|lhs| if op(lhs, &rhs) { *lhs } else { rhs })
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        lhs.data_type().clone(),
+        values.into(),
+        lhs.validity().cloned(),
+    )
+}
+
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
@@ -371,6 +988,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_eq_nan() {
+        let a = PrimitiveArray::<f64>::from([
+            Some(f64::NAN),
+            Some(-0.0),
+            Some(1.0),
+            Some(f64::NAN),
+            None,
+        ]);
+        let b = PrimitiveArray::<f64>::from([
+            Some(-f64::NAN),
+            Some(0.0),
+            Some(f64::NAN),
+            Some(1.0),
+            Some(f64::NAN),
+        ]);
+        let expected = BooleanArray::from([Some(true), Some(true), Some(false), Some(false), None]);
+        assert_eq!(eq_nan(&a, &b), expected);
+        // contrarily to `eq`
+        let expected =
+            BooleanArray::from([Some(false), Some(true), Some(false), Some(false), None]);
+        assert_eq!(eq(&a, &b), expected);
+
+        let a = PrimitiveArray::<f32>::from([Some(f32::NAN), Some(1.0), None]);
+        let expected = BooleanArray::from([Some(true), Some(false), None]);
+        assert_eq!(eq_nan_scalar(&a, f32::NAN), expected);
+    }
+
+    #[test]
+    fn test_total_order() {
+        let a = PrimitiveArray::<f64>::from_slice([f64::NAN, 1.0, -0.0, f64::NAN]);
+        let b = PrimitiveArray::<f64>::from_slice([1.0, f64::NAN, 0.0, f64::NAN]);
+        assert_eq!(
+            lt_total(&a, &b),
+            BooleanArray::from_slice([false, true, true, false])
+        );
+        assert_eq!(
+            lt_eq_total(&a, &b),
+            BooleanArray::from_slice([false, true, true, true])
+        );
+        assert_eq!(
+            gt_total(&a, &b),
+            BooleanArray::from_slice([true, false, false, false])
+        );
+        assert_eq!(
+            gt_eq_total(&a, &b),
+            BooleanArray::from_slice([true, false, false, true])
+        );
+        // IEEE semantics
+        assert_eq!(lt(&a, &b), BooleanArray::from_slice([false; 4]));
+
+        let a = PrimitiveArray::<f32>::from([Some(f32::NAN), None]);
+        let b = PrimitiveArray::<f32>::from([Some(f32::INFINITY), Some(1.0)]);
+        assert_eq!(gt_total(&a, &b), BooleanArray::from([Some(true), None]));
+    }
+
+    #[test]
+    fn test_interval_lt() {
+        use crate::types::{days_ms, months_days_ns};
+
+        let a = PrimitiveArray::<days_ms>::from_slice([
+            days_ms::new(1, 10),
+            days_ms::new(1, 10),
+            days_ms::new(0, 1000),
+        ])
+        .to(DataType::Interval(crate::datatypes::IntervalUnit::DayTime));
+        let b = PrimitiveArray::<days_ms>::from_slice([
+            days_ms::new(1, 11),
+            days_ms::new(1, 10),
+            days_ms::new(1, 0),
+        ])
+        .to(DataType::Interval(crate::datatypes::IntervalUnit::DayTime));
+        assert_eq!(lt(&a, &b), BooleanArray::from_slice([true, false, true]));
+        assert_eq!(lt_eq(&a, &b), BooleanArray::from_slice([true, true, true]));
+        assert_eq!(gt(&a, &b), BooleanArray::from_slice([false, false, false]));
+
+        // lexicographic: 1 month is greater than 40 days
+        let a = PrimitiveArray::<months_days_ns>::from_slice([
+            months_days_ns::new(1, 0, 0),
+            months_days_ns::new(0, 1, 2),
+            months_days_ns::new(0, 1, 2),
+        ]);
+        let b = PrimitiveArray::<months_days_ns>::from_slice([
+            months_days_ns::new(0, 40, 0),
+            months_days_ns::new(0, 1, 1),
+            months_days_ns::new(0, 1, 2),
+        ]);
+        assert_eq!(gt(&a, &b), BooleanArray::from_slice([true, true, false]));
+        assert_eq!(gt_eq(&a, &b), BooleanArray::from_slice([true, true, true]));
+        assert_eq!(
+            lt_scalar(&a, months_days_ns::new(0, 1, 3)),
+            BooleanArray::from_slice([false, true, true])
+        );
+    }
+
     #[test]
     fn test_primitive_array_lt_scalar() {
         cmp_i64_scalar!(
@@ -561,6 +1273,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_primitive_array_between_scalar() {
+        let a = Int64Array::from(
+            vec![None, Some(1), Some(2), None, Some(2), Some(3), None, Some(3), Some(4), Some(5)],
+        );
+        let c = between_scalar(&a, 2, 4);
+        assert_eq!(
+            BooleanArray::from(vec![None, Some(false), Some(true), None, Some(true), Some(true), None, Some(true), Some(true), Some(false)]),
+            c
+        );
+
+        let expected =
+            crate::compute::boolean::and(&gt_eq_scalar(&a, 2), &lt_eq_scalar(&a, 4)).unwrap();
+        assert_eq!(expected, c);
+    }
+
+    #[test]
+    fn test_primitive_array_between_scalar_slice() {
+        let a = (0..100).map(Some).collect::<PrimitiveArray<i32>>();
+        let a = a.slice(45, 10);
+        let actual = between_scalar(&a, 50, 52);
+        let expected: BooleanArray = (45..55).map(|x| Some((50..=52).contains(&x))).collect();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn test_primitive_array_compare_slice() {
         let a = (0..100).map(Some).collect::<PrimitiveArray<i32>>();
@@ -593,4 +1330,29 @@ mod tests {
 
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn test_eq_into() {
+        let lhs = Int64Array::from_vec((0..1001).collect());
+        let rhs = Int64Array::from_vec((0..1001).map(|x| x - x % 2).collect());
+        let expected = (0..1001).map(|x| x % 2 == 0);
+
+        let mut out = MutableBitmap::with_capacity(2048);
+        eq_into(&lhs, &rhs, &mut out);
+        // appends to what is already there, also when it is not aligned to bytes and
+        // longer than the bytes buffered at a time
+        eq_scalar_into(&lhs, 3, &mut out);
+        let expected = expected
+            .chain((0..1001).map(|x| x == 3))
+            .collect::<Vec<_>>();
+        assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+
+        // re-used without re-allocating
+        let capacity = out.capacity();
+        out.clear();
+        eq_into(&lhs.slice(1, 10), &rhs.slice(1, 10), &mut out);
+        let expected = (1..11).map(|x| x % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(out.capacity(), capacity);
+    }
 }
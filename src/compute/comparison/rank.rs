@@ -0,0 +1,77 @@
+//! Contains the [`rank`] kernel.
+use std::cmp::Ordering;
+
+use crate::array::{ord::build_compare, Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::Result;
+
+/// How [`rank`] ranks elements that are equal (ties).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RankMethod {
+    /// Ties take the smallest of their ranks, e.g. `[a, b, b, c]` ranks `[1, 2, 2, 4]`
+    Min,
+    /// Ties take the largest of their ranks, e.g. `[a, b, b, c]` ranks `[1, 3, 3, 4]`
+    Max,
+    /// Ties take the average of their ranks, rounded down, e.g. `[a, b, b, c]` ranks `[1, 2, 2, 4]`
+    /// and `[a, b, b, b]` ranks `[1, 3, 3, 3]`
+    Average,
+    /// Ties take the same rank and ranks have no gaps, e.g. `[a, b, b, c]` ranks `[1, 2, 2, 3]`
+    Dense,
+}
+
+/// Returns the (1-based) rank of each element of `array` in ascending order, with ties ranked
+/// according to `method`.
+///
+/// Elements are ordered as per [`build_compare`]: floats are compared using IEEE 754's total
+/// order, so that `NaN` is larger than any other value. Nulls rank last, as ties among them.
+/// The result has no validity.
+/// # Error
+/// Errors iff the [`DataType`] of `array` has no natural order.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::comparison::{rank, RankMethod};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(3)]);
+/// let result = rank(&array, RankMethod::Min)?;
+/// assert_eq!(result, UInt32Array::from_slice([2, 4, 1, 2]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn rank(array: &dyn Array, method: RankMethod) -> Result<PrimitiveArray<u32>> {
+    let compare = build_compare(array, array)?;
+
+    let (mut valids, nulls): (Vec<usize>, Vec<usize>) =
+        (0..array.len()).partition(|index| array.is_valid(*index));
+    // ties are ranked equally, so their order does not matter
+    valids.sort_unstable_by(|lhs, rhs| compare(*lhs, *rhs));
+
+    let mut ranks = vec![0u32; array.len()];
+    let mut assign = |indices: &[usize], start: usize, dense: usize| {
+        let rank = match method {
+            RankMethod::Min => start + 1,
+            RankMethod::Max => start + indices.len(),
+            // the average of the first (`start + 1`) and last (`start + len`) ranks
+            RankMethod::Average => (2 * start + 1 + indices.len()) / 2,
+            RankMethod::Dense => dense,
+        } as u32;
+        indices.iter().for_each(|index| ranks[*index] = rank);
+    };
+
+    let mut dense = 0;
+    let mut start = 0;
+    while start < valids.len() {
+        let end = valids[start + 1..]
+            .iter()
+            .position(|index| compare(valids[start], *index) != Ordering::Equal)
+            .map_or(valids.len(), |position| start + 1 + position);
+        dense += 1;
+        assign(&valids[start..end], start, dense);
+        start = end;
+    }
+    if !nulls.is_empty() {
+        assign(&nulls, valids.len(), dense + 1);
+    }
+
+    Ok(PrimitiveArray::new(DataType::UInt32, ranks.into(), None))
+}
@@ -1,3 +1,8 @@
+//! Contains the 8-lane representations of [`NativeType`]s used by the comparison kernels.
+//!
+//! Without the `simd` feature, or for types without a SIMD representation (e.g. `i128`),
+//! the 8 lanes are an array of 8 values compared one by one. Use [`active_lanes`] to inspect
+//! which one is compiled in.
 use crate::types::NativeType;
 
 /// [`NativeType`] that supports a representation of 8 lanes
@@ -8,12 +13,29 @@ pub trait Simd8: NativeType {
 
 /// Trait declaring an 8-lane multi-data.
 pub trait Simd8Lanes<T>: Copy {
+    /// The number of lanes compared at once by this representation: 8 for SIMD vectors and
+    /// 1 for the scalar fallback, which compares its values one by one.
+    const LANES: usize = 1;
+
     /// loads a complete chunk
     fn from_chunk(v: &[T]) -> Self;
     /// loads an incomplete chunk, filling the remaining items with `remaining`.
     fn from_incomplete_chunk(v: &[T], remaining: T) -> Self;
 }
 
+/// Returns the number of lanes of `T` compared at once by the comparison kernels on this target,
+/// i.e. 1 when `T` is compared by the scalar fallback (e.g. without the `simd` feature).
+/// # Example
+/// ```
+/// use arrow2::compute::comparison::simd::active_lanes;
+///
+/// let lanes = active_lanes::<i32>();
+/// assert!(lanes == 1 || lanes == 8);
+/// ```
+pub fn active_lanes<T: Simd8>() -> usize {
+    <T::Simd as Simd8Lanes<T>>::LANES
+}
+
 /// Trait implemented by implementors of [`Simd8Lanes`] whose [`Simd8`] implements [PartialEq].
 pub trait Simd8PartialEq: Copy {
     /// Equal
@@ -114,6 +136,49 @@ macro_rules! simd8_native_partial_ord {
     };
 }
 
+/// Types that do not implement PartialOrd but are ordered by a key
+macro_rules! simd8_native_partial_ord_by_key {
+    ($type:ty, $key:expr) => {
+        impl Simd8PartialOrd for [$type; 8] {
+            #[inline]
+            fn lt_eq(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) <= $key(y))
+            }
+
+            #[inline]
+            fn lt(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) < $key(y))
+            }
+
+            #[inline]
+            fn gt_eq(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) >= $key(y))
+            }
+
+            #[inline]
+            fn gt(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) > $key(y))
+            }
+        }
+    };
+}
+
+/// Intervals are ordered lexicographically by their fields
+macro_rules! simd8_intervals {
+    () => {
+        simd8_native!(days_ms);
+        simd8_native_partial_eq!(days_ms);
+        simd8_native_partial_ord_by_key!(days_ms, |x: days_ms| (x.days(), x.milliseconds()));
+        simd8_native!(months_days_ns);
+        simd8_native_partial_eq!(months_days_ns);
+        simd8_native_partial_ord_by_key!(months_days_ns, |x: months_days_ns| (
+            x.months(),
+            x.days(),
+            x.ns()
+        ));
+    };
+}
+
 /// Types that implement simd8, PartialEq and PartialOrd
 macro_rules! simd8_native_all {
     ($type:ty) => {
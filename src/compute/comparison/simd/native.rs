@@ -14,7 +14,4 @@ simd8_native_all!(i128);
 simd8_native_all!(i64);
 simd8_native_all!(f32);
 simd8_native_all!(f64);
-simd8_native!(days_ms);
-simd8_native_partial_eq!(days_ms);
-simd8_native!(months_days_ns);
-simd8_native_partial_eq!(months_days_ns);
+simd8_intervals!();
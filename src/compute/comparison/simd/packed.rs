@@ -13,6 +13,8 @@ macro_rules! simd8 {
         }
 
         impl Simd8Lanes<$type> for $md {
+            const LANES: usize = 8;
+
             #[inline]
             fn from_chunk(v: &[$type]) -> Self {
                 <$md>::from_slice(v)
@@ -73,7 +75,4 @@ simd8!(i64, i64x8);
 simd8_native_all!(i128);
 simd8!(f32, f32x8);
 simd8!(f64, f64x8);
-simd8_native!(days_ms);
-simd8_native_partial_eq!(days_ms);
-simd8_native!(months_days_ns);
-simd8_native_partial_eq!(months_days_ns);
+simd8_intervals!();
@@ -0,0 +1,141 @@
+//! Comparison functions for [`StructArray`]
+use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
+use crate::{
+    array::{Array, BooleanArray, StructArray},
+    bitmap::MutableBitmap,
+    compute,
+    datatypes::DataType,
+    error::Result,
+};
+
+use super::super::utils::combine_validities;
+
+/// Applies `op` to each pair of fields of `lhs` and `rhs` (matched by position) and reduces
+/// the results with `reduce`. Returns an array of `empty` without validity when there are no fields.
+fn fields_op<F, R>(
+    lhs: &StructArray,
+    rhs: &StructArray,
+    op: F,
+    reduce: R,
+    empty: bool,
+) -> BooleanArray
+where
+    F: Fn(&dyn Array, &dyn Array) -> BooleanArray,
+    R: Fn(&BooleanArray, &BooleanArray) -> Result<BooleanArray>,
+{
+    assert_eq!(
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type()
+    );
+    assert_eq!(lhs.len(), rhs.len());
+
+    lhs.values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| op(lhs.as_ref(), rhs.as_ref()))
+        .reduce(|acc, x| reduce(&acc, &x).unwrap())
+        .unwrap_or_else(|| {
+            let values = if empty {
+                MutableBitmap::from_len_set(lhs.len())
+            } else {
+                MutableBitmap::from_len_zeroed(lhs.len())
+            };
+            BooleanArray::new(DataType::Boolean, values.into(), None)
+        })
+}
+
+/// Sets the validity of `out` to be null wherever either `lhs` or `rhs` is null.
+fn propagate_validities(out: BooleanArray, lhs: &StructArray, rhs: &StructArray) -> BooleanArray {
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(out.validity(), validity.as_ref());
+    out.with_validity(validity)
+}
+
+/// Perform `lhs == rhs` operation on two [`StructArray`]s.
+///
+/// Two slots are equal when all their fields (matched by position) are equal.
+/// A null slot in either `lhs` or `rhs` results in a null slot.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq(lhs: &StructArray, rhs: &StructArray) -> BooleanArray {
+    let out = fields_op(lhs, rhs, super::eq, compute::boolean::and, true);
+    propagate_validities(out, lhs, rhs)
+}
+
+/// Perform `lhs == rhs` operation on two [`StructArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq_and_validity(lhs: &StructArray, rhs: &StructArray) -> BooleanArray {
+    let out = fields_op(
+        lhs,
+        rhs,
+        super::eq_and_validity,
+        compute::boolean::and,
+        true,
+    );
+    finish_eq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Perform `lhs != rhs` operation on two [`StructArray`]s.
+///
+/// Two slots are different when any of their fields (matched by position) are different.
+/// A null slot in either `lhs` or `rhs` results in a null slot.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq(lhs: &StructArray, rhs: &StructArray) -> BooleanArray {
+    let out = fields_op(lhs, rhs, super::neq, compute::boolean::or, false);
+    propagate_validities(out, lhs, rhs)
+}
+
+/// Perform `lhs != rhs` operation on two [`StructArray`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn neq_and_validity(lhs: &StructArray, rhs: &StructArray) -> BooleanArray {
+    let out = fields_op(
+        lhs,
+        rhs,
+        super::neq_and_validity,
+        compute::boolean::or,
+        false,
+    );
+    finish_neq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// The name of the pseudo-field returned by [`eq_fields`] comparing the validities of the structs.
+pub const VALIDITY_FIELD_NAME: &str = "$validity";
+
+/// Performs `lhs == rhs` on each pair of fields of two [`StructArray`]s (matched by position),
+/// returning the result of each field (as per [`eq`](super::eq)) keyed by its name, e.g. to
+/// report which fields differ.
+///
+/// The result ends with a pseudo-field named [`VALIDITY_FIELD_NAME`] whose slots are `true`
+/// when the slots of `lhs` and `rhs` are either both valid or both null.
+/// The validities of `lhs` and `rhs` are otherwise not applied to the results of the fields.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or length.
+pub fn eq_fields(lhs: &StructArray, rhs: &StructArray) -> Vec<(String, BooleanArray)> {
+    assert_eq!(
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type()
+    );
+    assert_eq!(lhs.len(), rhs.len());
+
+    let mut fields = StructArray::get_fields(lhs.data_type())
+        .iter()
+        .zip(lhs.values().iter().zip(rhs.values().iter()))
+        .map(|(field, (lhs, rhs))| (field.name.clone(), super::eq(lhs.as_ref(), rhs.as_ref())))
+        .collect::<Vec<_>>();
+
+    let validity = |array: &StructArray| {
+        let values = array
+            .validity()
+            .cloned()
+            .unwrap_or_else(|| MutableBitmap::from_len_set(array.len()).into());
+        BooleanArray::new(DataType::Boolean, values, None)
+    };
+    fields.push((
+        VALIDITY_FIELD_NAME.to_string(),
+        super::boolean::eq(&validity(lhs), &validity(rhs)),
+    ));
+    fields
+}
@@ -1,8 +1,10 @@
 //! Comparison functions for [`Utf8Array`]
+use std::collections::HashSet;
+
 use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
 use crate::{
-    array::{BooleanArray, Offset, Utf8Array},
-    bitmap::Bitmap,
+    array::{Array, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
 };
 
@@ -44,7 +46,48 @@ where
 
 /// Perform `lhs == rhs` operation on [`Utf8Array`].
 pub fn eq<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> BooleanArray {
-    compare_op(lhs, rhs, |a, b| a == b)
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    eq_into(lhs, rhs, &mut values);
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
+/// Appends the result of `lhs == rhs` to `out`, one bit per slot, without allocating a new
+/// [`BooleanArray`]. This allows re-using `out` across comparisons, e.g. via
+/// [`MutableBitmap::clear`].
+///
+/// The length of `out` grows by `lhs.len()`; `out` is reserved to hold them, so a bitmap with
+/// enough capacity is never re-allocated. The validities of `lhs` and `rhs` are ignored:
+/// the bits of null slots are the comparison of their (unspecified) values and must be
+/// combined with the validities by the caller.
+/// # Panics
+/// iff `lhs.len() != rhs.len()`.
+pub fn eq_into<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>, out: &mut MutableBitmap) {
+    assert_eq!(lhs.len(), rhs.len());
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| lhs == rhs);
+    out.extend_from_trusted_len_iter(values)
+}
+
+/// Perform `lhs == rhs` operation on [`Utf8Array`]s of possibly different offsets, e.g. a
+/// [`Utf8Array<i32>`] and a [`Utf8Array<i64>`], by comparing their strings.
+/// # Panics
+/// iff `lhs.len() != rhs.len()`.
+pub fn eq_any<O1: Offset, O2: Offset>(lhs: &Utf8Array<O1>, rhs: &Utf8Array<O2>) -> BooleanArray {
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| lhs == rhs);
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
 }
 
 /// Perform `lhs == rhs` operation on [`Utf8Array`] and include validities in comparison.
@@ -71,7 +114,19 @@ pub fn neq_and_validity<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Bo
 
 /// Perform `lhs == rhs` operation on [`Utf8Array`] and a scalar.
 pub fn eq_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
-    compare_op_scalar(lhs, rhs, |a, b| a == b)
+    let validity = lhs.validity().cloned();
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    eq_scalar_into(lhs, rhs, &mut values);
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
+/// Appends the result of `lhs == rhs` to `out`, one bit per slot of `lhs`.
+/// See [`eq_into`] for the contract on `out` and on validities.
+pub fn eq_scalar_into<O: Offset>(lhs: &Utf8Array<O>, rhs: &str, out: &mut MutableBitmap) {
+    let values = lhs.values_iter().map(|lhs| lhs == rhs);
+    out.extend_from_trusted_len_iter(values)
 }
 
 /// Perform `lhs == rhs` operation on [`Utf8Array`] and a scalar. Also includes null values in comparisson.
@@ -102,6 +157,21 @@ pub fn neq_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
     compare_op_scalar(lhs, rhs, |a, b| a != b)
 }
 
+/// Perform `lhs == rhs` operation on [`Utf8Array`], ignoring the case of ASCII letters.
+///
+/// This only folds ASCII letters (as per [`str::eq_ignore_ascii_case`]), not full Unicode case
+/// folding: all other bytes, including those of non-ASCII characters, are compared exactly.
+/// Values are compared in place, without allocating lowercased copies.
+pub fn eq_ignore_case<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a.eq_ignore_ascii_case(b))
+}
+
+/// Perform `lhs == rhs` operation on [`Utf8Array`] and a scalar, ignoring the case of ASCII
+/// letters. See [`eq_ignore_case`] for the folding rules.
+pub fn eq_scalar_ignore_case<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a.eq_ignore_ascii_case(b))
+}
+
 /// Perform `lhs < rhs` operation on [`Utf8Array`].
 pub fn lt<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> BooleanArray {
     compare_op(lhs, rhs, |a, b| a < b)
@@ -142,6 +212,214 @@ pub fn gt_eq_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
     compare_op_scalar(lhs, rhs, |a, b| a >= b)
 }
 
+/// Perform `array >= low AND array <= high` operation on three [`Utf8Array`]s in a single pass.
+/// A null slot in any of the arrays results in a null slot.
+/// # Panics
+/// iff the arrays do not have the same length.
+pub fn between<O: Offset>(
+    array: &Utf8Array<O>,
+    low: &Utf8Array<O>,
+    high: &Utf8Array<O>,
+) -> BooleanArray {
+    assert_eq!(array.len(), low.len());
+    assert_eq!(array.len(), high.len());
+    let validity = combine_validities(array.validity(), low.validity());
+    let validity = combine_validities(validity.as_ref(), high.validity());
+
+    let values = array
+        .values_iter()
+        .zip(low.values_iter())
+        .zip(high.values_iter())
+        .map(|((a, low), high)| low <= a && a <= high);
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `array >= low AND array <= high` operation on [`Utf8Array`] and two scalars in a single
+/// pass.
+pub fn between_scalar<O: Offset>(array: &Utf8Array<O>, low: &str, high: &str) -> BooleanArray {
+    compare_op_scalar(array, low, |a, low| low <= a && a <= high)
+}
+
+/// Returns whether each element of `array` starts with `prefix`.
+/// Null elements of `array` are null in the result.
+pub fn starts_with<O: Offset>(array: &Utf8Array<O>, prefix: &str) -> BooleanArray {
+    compare_op_scalar(array, prefix, |x, prefix| x.starts_with(prefix))
+}
+
+/// Evaluate `op(len)` on the length in bytes of each element of `array`, in a single pass over
+/// its offsets (its values are not read).
+fn compare_len_scalar<O, F>(array: &Utf8Array<O>, op: F) -> BooleanArray
+where
+    O: Offset,
+    F: Fn(usize) -> bool,
+{
+    let values = array
+        .offsets()
+        .windows(2)
+        .map(|offsets| op((offsets[1] - offsets[0]).to_usize()));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Perform `len(array) == n` on [`Utf8Array`], where `len` is the length in *bytes* of each
+/// element (e.g. `"é"` has length 2 and 1 character), computed from the offsets alone.
+/// Null elements of `array` are null in the result.
+pub fn len_eq_scalar<O: Offset>(array: &Utf8Array<O>, n: usize) -> BooleanArray {
+    compare_len_scalar(array, |len| len == n)
+}
+
+/// Perform `len(array) != n` on [`Utf8Array`] (see [`len_eq_scalar`]).
+pub fn len_neq_scalar<O: Offset>(array: &Utf8Array<O>, n: usize) -> BooleanArray {
+    compare_len_scalar(array, |len| len != n)
+}
+
+/// Perform `len(array) < n` on [`Utf8Array`] (see [`len_eq_scalar`]).
+pub fn len_lt_scalar<O: Offset>(array: &Utf8Array<O>, n: usize) -> BooleanArray {
+    compare_len_scalar(array, |len| len < n)
+}
+
+/// Perform `len(array) <= n` on [`Utf8Array`] (see [`len_eq_scalar`]).
+pub fn len_lt_eq_scalar<O: Offset>(array: &Utf8Array<O>, n: usize) -> BooleanArray {
+    compare_len_scalar(array, |len| len <= n)
+}
+
+/// Perform `len(array) > n` on [`Utf8Array`] (see [`len_eq_scalar`]).
+pub fn len_gt_scalar<O: Offset>(array: &Utf8Array<O>, n: usize) -> BooleanArray {
+    compare_len_scalar(array, |len| len > n)
+}
+
+/// Perform `len(array) >= n` on [`Utf8Array`] (see [`len_eq_scalar`]).
+pub fn len_gt_eq_scalar<O: Offset>(array: &Utf8Array<O>, n: usize) -> BooleanArray {
+    compare_len_scalar(array, |len| len >= n)
+}
+
+/// Returns a non-null [`BooleanArray`] that is `true` where the slot of `array` is
+/// null or an empty string.
+/// # Implementation
+/// This is a single pass over the offsets and validity of `array`; its values are not read.
+pub fn is_empty_or_null<O: Offset>(array: &Utf8Array<O>) -> BooleanArray {
+    let offsets = array.offsets().windows(2);
+    let values = if let Some(validity) = array.validity() {
+        Bitmap::from_trusted_len_iter(
+            offsets
+                .zip(validity.iter())
+                .map(|(offsets, is_valid)| !is_valid || offsets[0] == offsets[1]),
+        )
+    } else {
+        Bitmap::from_trusted_len_iter(offsets.map(|offsets| offsets[0] == offsets[1]))
+    };
+
+    BooleanArray::new(DataType::Boolean, values, None)
+}
+
+/// Returns whether each element of `array` is one of the (non-null) elements of `values`.
+/// Null elements of `array` are null in the result.
+pub fn is_in<O: Offset>(array: &Utf8Array<O>, values: &Utf8Array<O>) -> BooleanArray {
+    let values = if values.len() <= super::IS_IN_MAX_LINEAR_SCAN {
+        let values = values.iter().flatten().collect::<Vec<_>>();
+        Bitmap::from_trusted_len_iter(array.values_iter().map(|x| values.contains(&x)))
+    } else {
+        let values = values.iter().flatten().collect::<HashSet<_>>();
+        Bitmap::from_trusted_len_iter(array.values_iter().map(|x| values.contains(x)))
+    };
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each pair of elements of `lhs` and `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn compare<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> PrimitiveArray<i8> {
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| lhs.cmp(rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), validity)
+}
+
+/// Returns the [`Ordering`](std::cmp::Ordering) of each element of `lhs` with `rhs`
+/// as `-1` (less), `0` (equal) or `1` (greater).
+/// Null elements of `lhs` are null in the result.
+pub fn compare_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> PrimitiveArray<i8> {
+    let values = lhs
+        .values_iter()
+        .map(|lhs| lhs.cmp(rhs) as i8)
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(DataType::Int8, values.into(), lhs.validity().cloned())
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the smallest of the two.
+/// Ties are resolved in favour of `lhs`.
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn min<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Utf8Array<O> {
+    select_op(lhs, rhs, |lhs, rhs| lhs <= rhs)
+}
+
+/// Returns, for each pair of elements of `lhs` and `rhs`, the largest of the two.
+/// Ties are resolved in favour of `lhs`.
+/// A null element in either `lhs` or `rhs` results in a null slot.
+pub fn max<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Utf8Array<O> {
+    select_op(lhs, rhs, |lhs, rhs| lhs >= rhs)
+}
+
+/// Returns, for each element of `lhs`, the smallest of it and `rhs`.
+/// Null elements of `lhs` are null in the result.
+pub fn min_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Utf8Array<O> {
+    select_op_scalar(lhs, rhs, |lhs, rhs| lhs <= rhs)
+}
+
+/// Returns, for each element of `lhs`, the largest of it and `rhs`.
+/// Null elements of `lhs` are null in the result.
+pub fn max_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Utf8Array<O> {
+    select_op_scalar(lhs, rhs, |lhs, rhs| lhs >= rhs)
+}
+
+/// Selects the element of `lhs` when `op(lhs, rhs)` is true and of `rhs` otherwise.
+fn select_op<O, F>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>, op: F) -> Utf8Array<O>
+where
+    O: Offset,
+    F: Fn(&str, &str) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| if op(lhs, rhs) { lhs } else { rhs });
+    let array = Utf8Array::<O>::from_trusted_len_values_iter(values);
+    Utf8Array::<O>::new(
+        lhs.data_type().clone(),
+        array.offsets().clone(),
+        array.values().clone(),
+        validity,
+    )
+}
+
+/// Selects the element of `lhs` when `op(lhs, rhs)` is true and `rhs` otherwise.
+fn select_op_scalar<O, F>(lhs: &Utf8Array<O>, rhs: &str, op: F) -> Utf8Array<O>
+where
+    O: Offset,
+    F: Fn(&str, &str) -> bool,
+{
+    let values = lhs
+        .values_iter()
+        .map(|lhs| if op(lhs, rhs) { lhs } else { rhs });
+    let array = Utf8Array::<O>::from_trusted_len_values_iter(values);
+    Utf8Array::<O>::new(
+        lhs.data_type().clone(),
+        array.offsets().clone(),
+        array.values().clone(),
+        lhs.validity().cloned(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,6 +477,87 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_len_scalar() {
+        // "é" has 2 bytes
+        let array = Utf8Array::<i32>::from([Some("a"), Some("é"), None, Some(""), Some("abc")]);
+        let expected =
+            BooleanArray::from([Some(false), Some(true), None, Some(false), Some(false)]);
+        assert_eq!(len_eq_scalar(&array, 2), expected);
+        let expected = BooleanArray::from([Some(true), Some(false), None, Some(true), Some(true)]);
+        assert_eq!(len_neq_scalar(&array, 2), expected);
+        let expected = BooleanArray::from([Some(true), Some(false), None, Some(true), Some(false)]);
+        assert_eq!(len_lt_scalar(&array, 2), expected);
+        let expected = BooleanArray::from([Some(true), Some(true), None, Some(true), Some(false)]);
+        assert_eq!(len_lt_eq_scalar(&array, 2), expected);
+        let expected =
+            BooleanArray::from([Some(false), Some(false), None, Some(false), Some(true)]);
+        assert_eq!(len_gt_scalar(&array, 2), expected);
+        let expected = BooleanArray::from([Some(false), Some(true), None, Some(false), Some(true)]);
+        assert_eq!(len_gt_eq_scalar(&array, 2), expected);
+
+        // sliced arrays only consider their offsets
+        let expected = BooleanArray::from([Some(true), None]);
+        assert_eq!(len_eq_scalar(&array.slice(1, 2), 2), expected);
+
+        test_generic_scalar::<i64, _>(
+            vec!["arrow", "datafusion", "flight"],
+            "",
+            |array, _| len_gt_scalar(array, 5),
+            vec![false, true, true],
+        );
+    }
+
+    #[test]
+    fn test_eq_ignore_case() {
+        test_generic::<i32, _>(
+            vec!["Arrow", "ARROW", "arrow", "arrows", "Ärrow", "ärrow"],
+            vec!["arrow", "arrow", "aRrOw", "arrow", "ärrow", "ärrow"],
+            eq_ignore_case,
+            // non-ASCII characters are not folded
+            vec![true, true, true, false, false, true],
+        );
+
+        let lhs = Utf8Array::<i64>::from(&[Some("A"), None, Some("b")]);
+        let rhs = Utf8Array::<i64>::from(&[Some("a"), Some("a"), None]);
+        let expected = BooleanArray::from(&[Some(true), None, None]);
+        assert_eq!(eq_ignore_case(&lhs, &rhs), expected);
+    }
+
+    #[test]
+    fn test_eq_scalar_ignore_case() {
+        test_generic_scalar::<i32, _>(
+            vec!["Flight", "FLIGHT", "flights", "ﬂight"],
+            "flight",
+            eq_scalar_ignore_case,
+            vec![true, true, false, false],
+        );
+
+        let lhs = Utf8Array::<i32>::from(&[Some("A"), None]);
+        let expected = BooleanArray::from(&[Some(true), None]);
+        assert_eq!(eq_scalar_ignore_case(&lhs, "a"), expected);
+    }
+
+    #[test]
+    fn test_eq_into() {
+        let lhs = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+        let rhs = Utf8Array::<i32>::from_slice(["a", "c", "c"]);
+
+        let mut out = MutableBitmap::with_capacity(8);
+        eq_into(&lhs, &rhs, &mut out);
+        // appends to what is already there
+        eq_scalar_into(&lhs, "b", &mut out);
+        let expected = [true, false, true, false, true, false];
+        assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+
+        // re-used without re-allocating
+        let capacity = out.capacity();
+        out.clear();
+        eq_into(&rhs, &rhs, &mut out);
+        assert_eq!(out.iter().collect::<Vec<_>>(), [true, true, true]);
+        assert_eq!(out.capacity(), capacity);
+    }
+
     #[test]
     fn test_eq_scalar() {
         test_generic_scalar::<i32, _>(
@@ -229,6 +588,17 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_is_empty_or_null() {
+        let array = Utf8Array::<i32>::from(&[Some(""), None, Some("x")]);
+        let expected = BooleanArray::from_slice(&[true, true, false]);
+        assert_eq!(is_empty_or_null(&array), expected);
+
+        let array = Utf8Array::<i64>::from_slice(&["a", "", "bc", ""]).slice(1, 3);
+        let expected = BooleanArray::from_slice(&[true, false, true]);
+        assert_eq!(is_empty_or_null(&array), expected);
+    }
+
     /*
     test_utf8!(
         test_utf8_array_lt,
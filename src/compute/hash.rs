@@ -12,7 +12,7 @@ macro_rules! new_state {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, StructArray, Utf8Array},
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{ArrowError, Result},
     types::NativeType,
@@ -71,6 +71,45 @@ pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
     PrimitiveArray::<u64>::new(DataType::UInt64, values, array.validity().cloned())
 }
 
+/// The hash of null rows of a [`StructArray`] in [`hash_struct`] (and of null values of its fields).
+pub const NULL_HASH: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Row-wise hash of a [`StructArray`], e.g. to use it as a key of a group-by.
+///
+/// The hash of a row combines the hashes of the values of its fields (as per [`hash`], in order
+/// and recursively for nested structs), so that equal rows hash equally. Null rows and null
+/// values of its fields hash to [`NULL_HASH`]. The result has no validity.
+/// # Errors
+/// This function errors whenever [`hash`] does not support the `DataType` of a field.
+pub fn hash_struct(array: &StructArray) -> Result<PrimitiveArray<u64>> {
+    let state = new_state!();
+
+    let mut values = vec![0u64; array.len()];
+    for field in array.values() {
+        let hashes = hash(field.as_ref())?;
+        values
+            .iter_mut()
+            .zip(hashes.iter())
+            .for_each(|(value, hash)| {
+                let hash = hash.copied().unwrap_or(NULL_HASH);
+                *value = u128::get_hash(&(((*value as u128) << 64) | hash as u128), &state);
+            });
+    }
+    if let Some(validity) = array.validity() {
+        values
+            .iter_mut()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| !is_valid)
+            .for_each(|(value, _)| *value = NULL_HASH);
+    }
+
+    Ok(PrimitiveArray::<u64>::new(
+        DataType::UInt64,
+        values.into(),
+        None,
+    ))
+}
+
 macro_rules! with_match_primitive_type {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
@@ -101,7 +140,8 @@ macro_rules! with_match_primitive_type {(
 /// * Boolean types
 /// * All primitive types except `Float32` and `Float64`
 /// * `[Large]Utf8`;
-/// * `[Large]Binary`.
+/// * `[Large]Binary`;
+/// * `Struct` whose fields are supported (see [`hash_struct`]).
 /// # Errors
 /// This function errors whenever it does not support the specific `DataType`.
 pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
@@ -115,6 +155,7 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
         LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
         Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
         LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
+        Struct => hash_struct(array.as_any().downcast_ref().unwrap())?,
         t => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Hash not implemented for type {:?}",
@@ -138,6 +179,11 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
 /// assert_eq!(can_hash(&data_type), false);
 /// ```
 pub fn can_hash(data_type: &DataType) -> bool {
+    if let PhysicalType::Struct = data_type.to_physical_type() {
+        return StructArray::get_fields(data_type)
+            .iter()
+            .all(|field| can_hash(field.data_type()));
+    }
     matches!(
         data_type.to_physical_type(),
         PhysicalType::Boolean
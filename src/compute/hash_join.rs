@@ -0,0 +1,256 @@
+//! Contains [`build_key_index`], an index of the rows of the keys of the build side of a hash join.
+//!
+//! The index buckets the rows of the keys by their hash (as per [`hash_struct`]) and verifies
+//! the candidates of a probe by comparing their key columns, so that rows whose hashes collide
+//! are never mistaken for each other.
+use hash_hasher::HashedMap;
+
+use crate::{
+    array::*,
+    datatypes::PhysicalType,
+    error::{ArrowError, Result},
+    types::Offset,
+};
+
+use super::hash::hash_struct;
+
+/// A function returning the hash of each row of a [`StructArray`], such as [`hash_struct`].
+pub type RowHasher = fn(&StructArray) -> Result<PrimitiveArray<u64>>;
+
+/// Returns whether the row `i` of an array equals the row `j` of another array.
+type RowEq<'a> = Box<dyn Fn(usize, usize) -> bool + 'a>;
+
+/// An index of the rows of a [`StructArray`] of key columns, built by [`build_key_index`].
+///
+/// Rows are matched as in an SQL equi-join: a null row, or a row with a null key, matches
+/// no row.
+#[derive(Debug, Clone)]
+pub struct KeyIndex {
+    keys: StructArray,
+    hasher: RowHasher,
+    buckets: HashedMap<u64, Vec<usize>>,
+}
+
+impl KeyIndex {
+    /// Returns the keys that this index was built from.
+    pub fn keys(&self) -> &StructArray {
+        &self.keys
+    }
+
+    /// Returns a [`Prober`] of the rows of `probe_keys` against this index. The rows of
+    /// `probe_keys` are hashed and the comparison of their key columns is prepared once.
+    /// # Panics
+    /// Panics iff `probe_keys` does not have the same logical type as the keys of this index.
+    pub fn prober<'a>(&'a self, probe_keys: &'a StructArray) -> Prober<'a> {
+        assert_eq!(
+            self.keys.data_type().to_logical_type(),
+            probe_keys.data_type().to_logical_type()
+        );
+        // the hasher and the comparison already succeeded on the keys, which have the same type
+        let hashes = (self.hasher)(probe_keys).unwrap();
+        let eq = build_row_eq(&self.keys, probe_keys).unwrap();
+        Prober {
+            index: self,
+            probe_keys,
+            hashes,
+            eq,
+        }
+    }
+}
+
+/// Matches the rows of a [`StructArray`] of key columns against a [`KeyIndex`],
+/// built by [`KeyIndex::prober`].
+pub struct Prober<'a> {
+    index: &'a KeyIndex,
+    probe_keys: &'a StructArray,
+    hashes: PrimitiveArray<u64>,
+    eq: RowEq<'a>,
+}
+
+impl<'a> Prober<'a> {
+    /// Returns the rows of [`KeyIndex::keys`] equal to the row `row` of the probed keys,
+    /// in increasing order.
+    /// # Panics
+    /// Panics iff `row` is out of bounds.
+    pub fn probe(&self, row: usize) -> impl Iterator<Item = usize> + '_ {
+        assert!(row < self.probe_keys.len());
+        let candidates = match self.index.buckets.get(&self.hashes.value(row)) {
+            Some(candidates) if is_valid_row(self.probe_keys, row) => candidates.as_slice(),
+            _ => &[],
+        };
+
+        candidates
+            .iter()
+            .copied()
+            .filter(move |candidate| (self.eq)(*candidate, row))
+    }
+}
+
+impl std::fmt::Debug for Prober<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Prober")
+            .field("index", &self.index)
+            .field("probe_keys", &self.probe_keys)
+            .finish()
+    }
+}
+
+/// Returns whether neither the row `row` of `keys` nor any of its keys is null.
+fn is_valid_row(keys: &StructArray, row: usize) -> bool {
+    keys.is_valid(row) && keys.values().iter().all(|field| field.is_valid(row))
+}
+
+/// Returns a [`RowEq`] of the rows of `lhs` and `rhs`, which must have the same logical type.
+/// As in the comparison kernels, a null (nested) value is not equal to anything.
+fn build_row_eq<'a>(lhs: &'a dyn Array, rhs: &'a dyn Array) -> Result<RowEq<'a>> {
+    use PhysicalType::*;
+    macro_rules! values_eq {
+        ($array:ty) => {{
+            let lhs = lhs.as_any().downcast_ref::<$array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<$array>().unwrap();
+            Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+        }};
+    }
+    let values_eq: RowEq<'a> = match lhs.data_type().to_physical_type() {
+        Null => Box::new(|_, _| false),
+        Boolean => values_eq!(BooleanArray),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            values_eq!(PrimitiveArray<$T>)
+        }),
+        Utf8 => values_eq!(Utf8Array<i32>),
+        LargeUtf8 => values_eq!(Utf8Array<i64>),
+        Binary => values_eq!(BinaryArray<i32>),
+        LargeBinary => values_eq!(BinaryArray<i64>),
+        FixedSizeBinary => values_eq!(FixedSizeBinaryArray),
+        Struct => {
+            let lhs = lhs.as_any().downcast_ref::<StructArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = lhs
+                .values()
+                .iter()
+                .zip(rhs.values().iter())
+                .map(|(lhs, rhs)| build_row_eq(lhs.as_ref(), rhs.as_ref()))
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(move |i, j| fields.iter().all(|eq| eq(i, j)))
+        }
+        List => build_list_eq::<i32>(lhs, rhs)?,
+        LargeList => build_list_eq::<i64>(lhs, rhs)?,
+        FixedSizeList => {
+            let lhs = lhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = FixedSizeListArray::get_child_and_size(lhs.data_type()).1;
+            let values = build_row_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+            Box::new(move |i, j| (0..size).all(|k| values(i * size + k, j * size + k)))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            build_dictionary_eq::<$T>(lhs, rhs)?
+        }),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Comparing keys of type {:?} is not yet supported",
+                lhs.data_type()
+            )))
+        }
+    };
+    Ok(Box::new(move |i, j| {
+        lhs.is_valid(i) && rhs.is_valid(j) && values_eq(i, j)
+    }))
+}
+
+fn build_dictionary_eq<'a, K: DictionaryKey>(
+    lhs: &'a dyn Array,
+    rhs: &'a dyn Array,
+) -> Result<RowEq<'a>> {
+    let lhs = lhs.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = build_row_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+    Ok(Box::new(move |i, j| {
+        values(
+            lhs.keys().value(i).to_usize().unwrap(),
+            rhs.keys().value(j).to_usize().unwrap(),
+        )
+    }))
+}
+
+fn build_list_eq<'a, O: Offset>(lhs: &'a dyn Array, rhs: &'a dyn Array) -> Result<RowEq<'a>> {
+    let lhs = lhs.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let values = build_row_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+    Ok(Box::new(move |i, j| {
+        let (lhs_start, lhs_end) = (lhs.offsets()[i].to_usize(), lhs.offsets()[i + 1].to_usize());
+        let (rhs_start, rhs_end) = (rhs.offsets()[j].to_usize(), rhs.offsets()[j + 1].to_usize());
+        lhs_end - lhs_start == rhs_end - rhs_start
+            && (0..lhs_end - lhs_start).all(|k| values(lhs_start + k, rhs_start + k))
+    }))
+}
+
+/// Builds a [`KeyIndex`] of the rows of `keys` (whose fields are the key columns),
+/// hashing them with [`hash_struct`].
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, StructArray, Utf8Array};
+/// use arrow2::compute::hash_join::build_key_index;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let fields = vec![
+///     Field::new("id", DataType::Int32, true),
+///     Field::new("name", DataType::Utf8, true),
+/// ];
+/// let keys = |ids: &[i32], names: &[&str]| {
+///     let values: Vec<Arc<dyn Array>> = vec![
+///         Arc::new(Int32Array::from_slice(ids)),
+///         Arc::new(Utf8Array::<i32>::from_slice(names)),
+///     ];
+///     StructArray::from_data(DataType::Struct(fields.clone()), values, None)
+/// };
+///
+/// let build = keys(&[1, 2, 1], &["a", "b", "a"]);
+/// let index = build_key_index(&build).unwrap();
+///
+/// let probe = keys(&[1, 2], &["a", "a"]);
+/// let prober = index.prober(&probe);
+/// assert_eq!(prober.probe(0).collect::<Vec<_>>(), vec![0, 2]);
+/// assert_eq!(prober.probe(1).count(), 0);
+/// ```
+/// # Errors
+/// This function errors whenever [`hash_struct`] or the comparison of keys
+/// do not support the `DataType` of a key.
+pub fn build_key_index(keys: &StructArray) -> Result<KeyIndex> {
+    build_key_index_with(keys, hash_struct)
+}
+
+/// Same as [`build_key_index`], but hashing the rows with `hasher`, which must hash equal rows
+/// equally. Rows whose hashes collide are told apart when probing.
+/// # Errors
+/// This function errors whenever `hasher` errors, the comparison of keys does not
+/// support the `DataType` of a key, or `hasher` does not return one hash per row.
+pub fn build_key_index_with(keys: &StructArray, hasher: RowHasher) -> Result<KeyIndex> {
+    // errors iff the keys cannot be compared
+    let _ = build_row_eq(keys, keys)?;
+    let hashes = hasher(keys)?;
+    if hashes.len() != keys.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The hasher of a key index must return one hash per row ({}), but returned {}",
+            keys.len(),
+            hashes.len()
+        )));
+    }
+
+    let mut buckets = HashedMap::<u64, Vec<usize>>::default();
+    // rows with nulls match no row and are thus not indexed
+    let has_nulls =
+        keys.null_count() > 0 || keys.values().iter().any(|field| field.null_count() > 0);
+    hashes
+        .values()
+        .iter()
+        .enumerate()
+        .filter(|(row, _)| !has_nulls || is_valid_row(keys, *row))
+        .for_each(|(row, hash)| buckets.entry(*hash).or_default().push(row));
+
+    Ok(KeyIndex {
+        keys: keys.clone(),
+        hasher,
+        buckets,
+    })
+}
@@ -1,4 +1,7 @@
 //! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`].
+//!
+//! Patterns support two wildcards, `%` (zero, one, or multiple characters) and
+//! `_` (a single character). A wildcard preceded by `\` is matched literally.
 use std::collections::HashMap;
 
 use regex::bytes::Regex as BytesRegex;
@@ -12,19 +15,101 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+/// Whether `c` has a special meaning in a LIKE pattern
 #[inline]
 fn is_like_pattern(c: char) -> bool {
-    c == '%' || c == '_'
+    c == '%' || c == '_' || c == '\\'
 }
 
+/// Converts a LIKE pattern into an equivalent regex pattern
 fn replace_pattern(pattern: &str) -> String {
-    pattern.replace('%', ".*").replace('_', ".")
+    let mut result = String::with_capacity(pattern.len() + 2);
+    let mut escaped = false;
+    for c in pattern.chars() {
+        if escaped {
+            result.push_str(&regex::escape(c.encode_utf8(&mut [0; 4])));
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => escaped = true,
+            '%' => result.push_str(".*"),
+            '_' => result.push('.'),
+            c => result.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    if escaped {
+        // a trailing escape matches itself
+        result.push_str(&regex::escape("\\"));
+    }
+    result
+}
+
+/// Returns the regex pattern (anchored at both ends) equivalent to the LIKE `pattern`
+fn regex_pattern(pattern: &str, case_insensitive: bool) -> String {
+    let flags = if case_insensitive { "(?si)" } else { "(?s)" };
+    format!("^{}{}$", flags, replace_pattern(pattern))
+}
+
+/// A LIKE pattern without wildcards nor escapes other than a leading or a trailing `%`,
+/// which is matched without a regex
+enum Literal<'a> {
+    /// No wildcard: the value equals the literal
+    Eq(&'a [u8]),
+    /// A trailing `%`: the value starts with the literal
+    StartsWith(&'a [u8]),
+    /// A leading `%`: the value ends with the literal
+    EndsWith(&'a [u8]),
+}
+
+impl<'a> Literal<'a> {
+    /// Returns the [`Literal`] of `pattern`, if any.
+    fn try_new(pattern: &'a str) -> Option<Self> {
+        if !pattern.contains(is_like_pattern) {
+            Some(Self::Eq(pattern.as_bytes()))
+        } else if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains(is_like_pattern)
+        {
+            Some(Self::StartsWith(&pattern.as_bytes()[..pattern.len() - 1]))
+        } else if pattern.starts_with('%') && !pattern[1..].contains(is_like_pattern) {
+            Some(Self::EndsWith(&pattern.as_bytes()[1..]))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn is_match(&self, value: &[u8]) -> bool {
+        match self {
+            Self::Eq(literal) => value == *literal,
+            Self::StartsWith(literal) => value.starts_with(literal),
+            Self::EndsWith(literal) => value.ends_with(literal),
+        }
+    }
+}
+
+fn build_regex(pattern: &str, case_insensitive: bool) -> Result<Regex> {
+    Regex::new(&regex_pattern(pattern, case_insensitive)).map_err(|e| {
+        ArrowError::InvalidArgumentError(format!("Unable to build regex from LIKE pattern: {}", e))
+    })
+}
+
+/// Returns whether the LIKE kernels support arrays of `data_type`: [`like_utf8`] and
+/// [`ilike_utf8`] (and their negated and scalar variants) support [`DataType::Utf8`] and
+/// [`DataType::LargeUtf8`], and [`like_binary`] (and its negated and scalar variants) supports
+/// [`DataType::Binary`] and [`DataType::LargeBinary`].
+/// There is no case-insensitive kernel for binary arrays.
+pub fn can_like(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+    )
 }
 
 #[inline]
 fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &Utf8Array<O>,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     if lhs.len() != rhs.len() {
@@ -44,13 +129,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = replace_pattern(pattern);
-                        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
+                        let re = build_regex(pattern, case_insensitive)?;
                         map.insert(pattern, re);
                         map.get(pattern).unwrap()
                     };
@@ -70,6 +149,9 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -86,7 +168,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
 /// ```
 pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| x)
+    a_like_utf8(lhs, rhs, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`].
@@ -95,36 +177,62 @@ pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<Bo
 ///
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
+///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
 pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| !x)
+    a_like_utf8(lhs, rhs, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation on two [`Utf8Array`], i.e. a case-insensitive [`like_utf8`].
+///
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8;
+///
+/// let strings = Utf8Array::<i32>::from_slice(&["Arrow", "arrow", "Arrow"]);
+/// let patterns = Utf8Array::<i32>::from_slice(&["a%", "%ROW", "b%"]);
+///
+/// let result = ilike_utf8(&strings, &patterns).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+/// ```
+pub fn ilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on two [`Utf8Array`].
+pub fn nilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| !x)
 }
 
 fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &str,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
 
-    let values = if !rhs.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
-    } else if rhs.ends_with('%') && !rhs[..rhs.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if rhs.starts_with('%') && !rhs[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else {
-        let re_pattern = replace_pattern(rhs);
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+    // case-insensitive literals are matched against the lowercased values
+    let lowercase = case_insensitive.then(|| rhs.to_lowercase());
+    let values = match Literal::try_new(lowercase.as_deref().unwrap_or(rhs)) {
+        Some(literal) if case_insensitive => Bitmap::from_trusted_len_iter(
+            lhs.values_iter()
+                .map(|x| op(literal.is_match(x.to_lowercase().as_bytes()))),
+        ),
+        Some(literal) => Bitmap::from_trusted_len_iter(
+            lhs.values_iter()
+                .map(|x| op(literal.is_match(x.as_bytes()))),
+        ),
+        None => {
+            let re = build_regex(rhs, case_insensitive)?;
+            Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+        }
     };
     Ok(BooleanArray::new(
         DataType::Boolean,
@@ -140,6 +248,9 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -155,7 +266,7 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
 /// ```
 pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| x)
+    a_like_utf8_scalar(lhs, rhs, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation.
@@ -164,8 +275,37 @@ pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<Bool
 ///
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
+///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| !x)
+    a_like_utf8_scalar(lhs, rhs, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation, i.e. a case-insensitive [`like_utf8_scalar`].
+///
+/// Patterns without wildcards, or with only a leading or a trailing `%`, are matched against the
+/// lowercased values ([`str::to_lowercase`]) without a regex; other patterns are compiled once
+/// for the whole array.
+/// # Error
+/// Errors iff the pattern is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8_scalar;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["Arrow", "arrow", "BA"]);
+///
+/// let result = ilike_utf8_scalar(&array, "ARROW").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+/// ```
+pub fn ilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation.
+pub fn nilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, true, |x| !x)
 }
 
 #[inline]
@@ -192,8 +332,8 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
                         pattern
                     } else {
                         let re_pattern = simdutf8::basic::from_utf8(pattern).unwrap();
-                        let re_pattern = replace_pattern(re_pattern);
-                        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
+                        let re_pattern = regex_pattern(re_pattern, false);
+                        let re = BytesRegex::new(&re_pattern).map_err(|e| {
                             ArrowError::InvalidArgumentError(format!(
                                 "Unable to build regex from LIKE pattern: {}",
                                 e
@@ -218,6 +358,9 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -244,6 +387,9 @@ pub fn like_binary<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> Res
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
+///
 pub fn nlike_binary<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> Result<BooleanArray> {
     a_like_binary(lhs, rhs, |x| !x)
 }
@@ -261,19 +407,11 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
         ))
     })?;
 
-    let values = if !pattern.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
-    } else if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if pattern.starts_with('%') && !pattern[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
+    let values = if let Some(literal) = Literal::try_new(pattern) {
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(literal.is_match(x))))
     } else {
-        let re_pattern = replace_pattern(pattern);
-        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
+        let re_pattern = regex_pattern(pattern, false);
+        let re = BytesRegex::new(&re_pattern).map_err(|e| {
             ArrowError::InvalidArgumentError(format!(
                 "Unable to build regex from LIKE pattern: {}",
                 e
@@ -295,6 +433,9 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -320,6 +461,9 @@ pub fn like_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A `\` escapes the character that follows it, which is then matched literally (e.g. `\%`
+/// matches `%` and `\\` matches `\`). A trailing `\` matches itself.
+///
 pub fn nlike_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result<BooleanArray> {
     a_like_binary_scalar(lhs, rhs, |x| !x)
 }
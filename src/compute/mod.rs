@@ -30,6 +30,9 @@ pub mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_coalesce")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_coalesce")))]
+pub mod coalesce;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -45,6 +48,9 @@ pub mod filter;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
+#[cfg(feature = "compute_hash_join")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_hash_join")))]
+pub mod hash_join;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
@@ -10,7 +10,10 @@ use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
-/// Regex matches
+/// Returns whether each value of `values` matches (anywhere) the regex of the same slot of `regex`.
+/// Slots where either side is null are null; each distinct regex is compiled once.
+/// # Errors
+/// This function errors iff the arrays have different lengths or a regex is invalid.
 pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Result<BooleanArray> {
     if values.len() != regex.len() {
         return Err(ArrowError::InvalidArgumentError(
@@ -33,10 +36,7 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
             regex
         } else {
             let re = Regex::new(regex).map_err(|e| {
-                ArrowError::InvalidArgumentError(format!(
-                    "Unable to build regex from LIKE pattern: {}",
-                    e
-                ))
+                ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e))
             })?;
             map.insert(regex, re);
             map.get(regex).unwrap()
@@ -49,7 +49,10 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
     Ok(BooleanArray::new(DataType::Boolean, new_values, validity))
 }
 
-/// Regex matches
+/// Returns whether each value of `values` matches (anywhere) `regex`, which is compiled once.
+/// Null slots are null.
+/// # Errors
+/// This function errors iff `regex` is invalid.
 /// # Example
 /// ```
 /// use arrow2::array::{Utf8Array, BooleanArray};
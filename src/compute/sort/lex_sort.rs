@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 use crate::compute::take;
 use crate::error::{ArrowError, Result};
 use crate::{
-    array::{ord, Array, PrimitiveArray},
+    array::{ord, Array, PrimitiveArray, StructArray},
     types::Index,
 };
 
@@ -182,3 +182,76 @@ pub fn lexsort_to_indices<I: Index>(
     let data_type = I::PRIMITIVE.into();
     Ok(PrimitiveArray::<I>::new(data_type, values.into(), None))
 }
+
+/// Returns the index of the row of `array` whose tuple of field values is lexicographically the
+/// smallest, with the fields compared in order, each according to the [`SortOptions`] of the
+/// same position in `options`. This is the first index of [`lexsort_to_indices`] on the fields
+/// of `array`, without sorting it.
+///
+/// Ties are broken by the smallest index. Rows where `array` itself is null are skipped, and
+/// `None` is returned when there is no (valid) row.
+/// # Errors
+/// This function errors iff:
+/// * `options` does not have one entry per field of `array`
+/// * the [`DataType`](crate::datatypes::DataType) of any field has no natural order
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Int32Array, StructArray, Utf8Array};
+/// use arrow2::compute::sort::{lexicographic_min_index, SortOptions};
+/// use arrow2::datatypes::{DataType, Field};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = StructArray::from_data(
+///     DataType::Struct(vec![
+///         Field::new("a", DataType::Int32, true),
+///         Field::new("b", DataType::Utf8, true),
+///     ]),
+///     vec![
+///         Arc::new(Int32Array::from_slice([2, 1, 1])),
+///         Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+///     ],
+///     None,
+/// );
+/// let ascending = SortOptions::default();
+/// let descending = SortOptions { descending: true, ..ascending };
+/// assert_eq!(lexicographic_min_index(&array, &[ascending, descending])?, Some(2));
+/// # Ok(())
+/// # }
+/// ```
+pub fn lexicographic_min_index(
+    array: &StructArray,
+    options: &[SortOptions],
+) -> Result<Option<usize>> {
+    if options.len() != array.values().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "lexicographic_min_index requires one SortOptions per field ({}), but {} were provided",
+            array.values().len(),
+            options.len()
+        )));
+    }
+    let comparators = array
+        .values()
+        .iter()
+        .zip(options.iter())
+        .map(|(values, options)| build_compare(values.as_ref(), *options))
+        .collect::<Result<Vec<DynComparator>>>()?;
+
+    let compare = |lhs: usize, rhs: usize| {
+        comparators
+            .iter()
+            .map(|comparator| comparator(lhs, rhs))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    };
+
+    Ok((0..array.len())
+        .filter(|index| array.is_valid(*index))
+        .reduce(|min, index| {
+            // only a strictly smaller row replaces the minimum, so that ties keep the first
+            if compare(index, min) == Ordering::Less {
+                index
+            } else {
+                min
+            }
+        }))
+}
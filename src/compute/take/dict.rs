@@ -15,20 +15,37 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::array::{DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::datatypes::DataType;
 
 use super::primitive::take as take_primitive;
 use super::Index;
 
+/// Returns whether the non-null `indices` are non-decreasing
+fn is_monotonic<I: Index>(indices: &PrimitiveArray<I>) -> bool {
+    let mut previous = None;
+    indices.iter().flatten().all(|index| {
+        let is_monotonic = previous.map_or(true, |previous| previous <= *index);
+        previous = Some(*index);
+        is_monotonic
+    })
+}
+
 /// `take` implementation for dictionary arrays
 ///
 /// applies `take` to the keys of the dictionary array and returns a new dictionary array
-/// with the same dictionary values and reordered keys
+/// with the same dictionary values and reordered keys. The values are shared (not copied).
+/// Null indices result in null keys.
+/// The result is ordered iff `values` is ordered and the non-null `indices` are non-decreasing.
 pub fn take<K, I>(values: &DictionaryArray<K>, indices: &PrimitiveArray<I>) -> DictionaryArray<K>
 where
     K: DictionaryKey,
     I: Index,
 {
     let keys = take_primitive::<K, I>(values.keys(), indices);
-    DictionaryArray::<K>::from_data(keys, values.values().clone())
+    let mut array = DictionaryArray::<K>::from_data(keys, values.values().clone());
+    if matches!(values.data_type(), DataType::Dictionary(_, _, true)) {
+        array.set_ordered(is_monotonic(indices));
+    }
+    array
 }
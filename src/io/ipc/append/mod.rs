@@ -38,6 +38,7 @@ impl<R: Read + Seek + Write> FileWriter<R> {
                 &metadata.schema.fields,
                 &metadata.ipc_schema,
                 blocks,
+                &Default::default(),
             )?
         } else {
             Default::default()
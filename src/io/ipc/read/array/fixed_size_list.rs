@@ -2,14 +2,14 @@ use std::collections::VecDeque;
 use std::io::{Read, Seek};
 
 use crate::array::FixedSizeListArray;
-use crate::datatypes::DataType;
+use crate::datatypes::{DataType, Field};
 use crate::error::{ArrowError, Result};
 
 use super::super::super::IpcField;
-use super::super::deserialize::{read, skip};
+use super::super::deserialize::{read, skip, NestedProjection};
 use super::super::read_basic::*;
 use super::super::Dictionaries;
-use super::super::{Compression, IpcBuffer, Node, Version};
+use super::super::{Compression, IpcBuffer, Node, ReadOptions, Version};
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_fixed_size_list<R: Read + Seek>(
@@ -23,6 +23,8 @@ pub fn read_fixed_size_list<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<Compression>,
     version: Version,
+    projection: Option<&NestedProjection>,
+    options: &ReadOptions,
 ) -> Result<FixedSizeListArray> {
     let field_node = field_nodes.pop_front().ok_or_else(|| {
         ArrowError::oos(format!(
@@ -38,9 +40,10 @@ pub fn read_fixed_size_list<R: Read + Seek>(
         block_offset,
         is_little_endian,
         compression,
+        options,
     )?;
 
-    let (field, _) = FixedSizeListArray::get_child_and_size(&data_type);
+    let (field, size) = FixedSizeListArray::get_child_and_size(&data_type);
 
     let values = read(
         field_nodes,
@@ -53,7 +56,20 @@ pub fn read_fixed_size_list<R: Read + Seek>(
         is_little_endian,
         compression,
         version,
+        projection,
+        options,
     )?;
+
+    // the values of a projection only contain the selected nested fields
+    let data_type = if projection.is_some() {
+        let field = Box::new(Field {
+            data_type: values.data_type().clone(),
+            ..field.clone()
+        });
+        DataType::FixedSizeList(field, size)
+    } else {
+        data_type
+    };
     FixedSizeListArray::try_new(data_type, values, validity)
 }
 
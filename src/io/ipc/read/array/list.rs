@@ -1,41 +1,102 @@
 use std::collections::VecDeque;
 use std::convert::TryInto;
+#[cfg(feature = "io_ipc_read_async")]
+use std::io::SeekFrom;
 use std::io::{Read, Seek};
+use std::sync::Arc;
 
-use crate::array::{ListArray, Offset};
+#[cfg(feature = "io_ipc_read_async")]
+use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::array::{Array, ListArray, Offset};
 use crate::buffer::Buffer;
-use crate::datatypes::DataType;
+use crate::datatypes::{DataType, Field};
 use crate::error::{ArrowError, Result};
 
 use super::super::super::IpcField;
-use super::super::deserialize::{read, skip};
+use super::super::deserialize::{count_nodes, read, skip, NestedProjection};
 use super::super::read_basic::*;
 use super::super::Dictionaries;
-use super::super::{Compression, IpcBuffer, Node, Version};
+use super::super::{Compression, IpcBuffer, Node, ReadOptions, Version};
+use super::read_primitive_rows;
 
-#[allow(clippy::too_many_arguments)]
+/// The arguments of [`read_list`] besides the nodes, buffers and reader it reads from.
+#[derive(Debug, Clone, Copy)]
+pub struct ListArgs<'a> {
+    /// The dictionaries of the values of the list
+    pub dictionaries: &'a Dictionaries,
+    /// The offset of the body of the message in the reader
+    pub block_offset: u64,
+    /// Whether the buffers are little endian
+    pub is_little_endian: bool,
+    /// The compression of the buffers
+    pub compression: Option<Compression<'a>>,
+    /// The version of the IPC format
+    pub version: Version,
+    /// The projection of the values of the list
+    pub projection: Option<&'a NestedProjection>,
+    /// How the list is read
+    pub options: &'a ReadOptions,
+    /// The window of rows of the list to read, as `(offset, length)`, or all of them when `None`
+    pub rows: Option<(usize, usize)>,
+}
+
+/// Reads a [`ListArray`], or only its `rows.1` rows starting at the row `rows.0` when
+/// `args.rows` is `Some`.
+///
+/// Reading a window of rows still reads (and scans) the validity and all the offsets of the
+/// list, since they are needed to find the range of values spanned by the window. Only this
+/// range of values is then read: primitive values are read from their buffer without the
+/// other values (unless compressed) and nested lists only read their own window, while other
+/// values are read as a whole and sliced.
 pub fn read_list<O: Offset, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
     reader: &mut R,
-    dictionaries: &Dictionaries,
-    block_offset: u64,
-    is_little_endian: bool,
-    compression: Option<Compression>,
-    version: Version,
+    args: ListArgs,
 ) -> Result<ListArray<O>>
 where
     Vec<u8>: TryInto<O::Bytes>,
 {
+    let ListArgs {
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+        projection,
+        options,
+        rows,
+    } = args;
     let field_node = field_nodes.pop_front().ok_or_else(|| {
         ArrowError::oos(format!(
             "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
             data_type
         ))
     })?;
+    if let Some((skip, take)) = rows {
+        if skip.saturating_add(take) > field_node.length() as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "IPC: the rows {}..{} are out of bounds of a list of length {}",
+                skip,
+                skip.saturating_add(take),
+                field_node.length()
+            )));
+        }
+    }
 
+    if let Some(buffer) = buffers.front() {
+        check_buffer_length(buffer, reader, block_offset, options)?;
+    }
+    // the validity is only read (and decompressed) when the list has nulls
+    let first_buffer = if field_node.null_count() > 0 {
+        "validity"
+    } else {
+        "offsets"
+    };
+    check_compression(compression, first_buffer)?;
     let validity = read_validity(
         buffers,
         field_node,
@@ -43,34 +104,318 @@ where
         block_offset,
         is_little_endian,
         compression,
+        options,
     )?;
 
-    let offsets = read_buffer::<O, _>(
-        buffers,
-        1 + field_node.length() as usize,
-        reader,
-        block_offset,
-        is_little_endian,
-        compression,
-    )
-    // Older versions of the IPC format sometimes do not report an offset
-    .or_else(|_| Result::Ok(Buffer::<O>::from(vec![O::default()])))?;
+    // Legacy versions of the IPC format sometimes omit the offsets of empty lists.
+    // Other versions must report them: a missing buffer errors as corrupted.
+    let is_legacy = matches!(
+        version,
+        Version::V1 | Version::V2 | Version::V3 | Version::V4
+    );
+    let offsets = if is_legacy
+        && field_node.length() == 0
+        && buffers.front().map_or(false, |buffer| buffer.length() == 0)
+    {
+        let _ = buffers.pop_front();
+        Buffer::<O>::from(vec![O::default()])
+    } else {
+        read_offsets::<O, _>(
+            buffers,
+            1 + field_node.length() as usize,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            options,
+        )?
+    };
 
     let field = ListArray::<O>::get_child_field(&data_type);
+    let ipc_field = ipc_field.fields.first().ok_or_else(|| {
+        ArrowError::oos("IPC: the IPC field of a list must have the IPC field of its values")
+    })?;
+
+    // the full offsets are verified against the full values, before only a window is read
+    let values_length = field_nodes.front().map(|node| node.length() as usize);
+    if options.verify_offsets && rows.is_some() {
+        verify_offsets(&offsets, values_length.unwrap_or(0))?;
+    }
+    let (validity, offsets, values_rows) = match rows {
+        Some((skip, take)) => {
+            let window = &offsets.as_slice()[skip..skip + take + 1];
+            let (start, end) = (window[0], window[take]);
+            if end.to_usize() > values_length.unwrap_or(0) {
+                return Err(ArrowError::oos(format!(
+                    "IPC: the offsets of a list end at {}, but its values have length {}",
+                    end.to_usize(),
+                    values_length.unwrap_or(0)
+                )));
+            }
+            let offsets = window.iter().map(|x| *x - start).collect::<Vec<_>>();
+            (
+                validity.map(|validity| validity.slice(skip, take)),
+                offsets.into(),
+                Some((start.to_usize(), (end - start).to_usize())),
+            )
+        }
+        None => (validity, offsets, None),
+    };
+
+    let remaining = field_nodes.len();
+    let values = match values_rows {
+        Some(values_rows) => read_values_rows(
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            args,
+            values_rows,
+        )?,
+        None => read(
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+            projection,
+            options,
+        )?,
+    };
+    // a desync would silently misalign the siblings of the list, e.g. on malformed nested schemas
+    let consumed = remaining - field_nodes.len();
+    let expected = count_nodes(field.data_type());
+    if consumed != expected {
+        return Err(ArrowError::oos(format!(
+            "IPC: the values of a list must span {} field nodes, but {} were read. The file or stream is corrupted.",
+            expected, consumed
+        )));
+    }
+
+    // the offsets are read even if the values are projected: they remain valid for them
+    let data_type = if projection.is_some() {
+        let field = Box::new(Field {
+            data_type: values.data_type().clone(),
+            ..field.clone()
+        });
+        if O::is_large() {
+            DataType::LargeList(field)
+        } else {
+            DataType::List(field)
+        }
+    } else {
+        data_type
+    };
+    if options.verify_offsets && rows.is_none() {
+        verify_offsets(&offsets, values.len())?;
+    }
+    ListArray::try_new(data_type, offsets, values, validity)
+}
+
+/// Reads the `rows.1` values of a list starting at the value `rows.0`, reading only these
+/// when possible (see [`read_list`]). The rows of `args` are the ones of the list.
+fn read_values_rows<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    args: ListArgs,
+    rows: (usize, usize),
+) -> Result<Arc<dyn Array>> {
+    use crate::datatypes::PhysicalType;
+    let data_type = field.data_type().clone();
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            read_primitive_rows::<$T, _>(
+                field_nodes,
+                data_type,
+                buffers,
+                reader,
+                args.block_offset,
+                args.is_little_endian,
+                args.compression,
+                rows,
+                args.options,
+            )
+            .map(|x| Arc::new(x) as Arc<dyn Array>)
+        }),
+        PhysicalType::List => read_list::<i32, _>(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            reader,
+            ListArgs {
+                rows: Some(rows),
+                ..args
+            },
+        )
+        .map(|x| Arc::new(x) as Arc<dyn Array>),
+        PhysicalType::LargeList => read_list::<i64, _>(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            reader,
+            ListArgs {
+                rows: Some(rows),
+                ..args
+            },
+        )
+        .map(|x| Arc::new(x) as Arc<dyn Array>),
+        _ => {
+            let values = read(
+                field_nodes,
+                field,
+                ipc_field,
+                buffers,
+                reader,
+                args.dictionaries,
+                args.block_offset,
+                args.is_little_endian,
+                args.compression,
+                args.version,
+                args.projection,
+                args.options,
+            )?;
+            Ok(values.slice(rows.0, rows.1).into())
+        }
+    }
+}
 
-    let values = read(
+/// Async version of [`read_list`]: reads the list from an [`AsyncRead`] + [`AsyncSeek`].
+///
+/// The bytes spanned by the buffers of the list (including the ones of its values) are read
+/// at once and then deserialized as in [`read_list`], including their decompression.
+///
+/// Like the other async readers of IPC ([`file_async`](super::super::file_async) and
+/// [`stream_async`](super::super::stream_async)), it requires the `io_ipc_read_async` feature,
+/// which already declares the dependencies it needs (`futures`), instead of a feature of its own.
+#[cfg(feature = "io_ipc_read_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_read_async")))]
+#[allow(clippy::too_many_arguments)]
+pub async fn read_list_async<O: Offset, R: AsyncRead + AsyncSeek + Unpin>(
+    field_nodes: &mut VecDeque<Node<'_>>,
+    data_type: DataType,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer<'_>>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression<'_>>,
+    version: Version,
+    projection: Option<&NestedProjection>,
+    options: &ReadOptions,
+) -> Result<ListArray<O>>
+where
+    Vec<u8>: TryInto<O::Bytes>,
+{
+    // the buffers of the list are the ones that skipping it consumes
+    let mut remaining_nodes = field_nodes.clone();
+    let mut remaining_buffers = buffers.clone();
+    skip(&mut remaining_nodes, &data_type, &mut remaining_buffers)?;
+    let consumed = buffers.len() - remaining_buffers.len();
+
+    let (start, end) = buffers_range(buffers.iter().take(consumed))?;
+
+    let available = reader.seek(SeekFrom::End(0)).await?;
+    let (start, end) = (
+        block_offset.saturating_add(start),
+        block_offset.saturating_add(end),
+    );
+    if end > available {
+        return Err(ArrowError::oos(format!(
+            "IPC: the buffers of a list declare {} bytes, but only {} bytes are available. The file or stream is corrupted.",
+            end - start,
+            available.saturating_sub(start)
+        )));
+    }
+    reader.seek(SeekFrom::Start(start)).await?;
+    let mut data = vec![0; (end - start) as usize];
+    reader.read_exact(&mut data).await?;
+
+    let mut reader = OffsetCursor {
+        inner: std::io::Cursor::new(data),
+        offset: start,
+    };
+    read_list(
         field_nodes,
-        field,
-        &ipc_field.fields[0],
+        data_type,
+        ipc_field,
         buffers,
-        reader,
-        dictionaries,
-        block_offset,
-        is_little_endian,
-        compression,
-        version,
-    )?;
-    ListArray::try_new(data_type, offsets, values, validity)
+        &mut reader,
+        ListArgs {
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+            projection,
+            options,
+            rows: None,
+        },
+    )
+}
+
+/// A [`std::io::Cursor`] over the bytes of a stream starting at `offset`, whose positions are
+/// the ones of the stream.
+#[cfg(feature = "io_ipc_read_async")]
+struct OffsetCursor {
+    inner: std::io::Cursor<Vec<u8>>,
+    offset: u64,
+}
+
+#[cfg(feature = "io_ipc_read_async")]
+impl Read for OffsetCursor {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+#[cfg(feature = "io_ipc_read_async")]
+impl Seek for OffsetCursor {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "seek before the start of the read bytes",
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        self.inner.seek(pos).map(|pos| pos + self.offset)
+    }
+}
+
+/// Errors iff `offsets` do not start at zero or do not end at `values_len`.
+/// Their monotonicity is already checked when they are read.
+fn verify_offsets<O: Offset>(offsets: &[O], values_len: usize) -> Result<()> {
+    let first = offsets.first().map_or(0, |x| x.to_usize());
+    if first != 0 {
+        return Err(ArrowError::oos(format!(
+            "IPC: the first offset of a list must be 0, but it is {}",
+            first
+        )));
+    }
+    let last = offsets.last().map_or(0, |x| x.to_usize());
+    if last != values_len {
+        return Err(ArrowError::oos(format!(
+            "IPC: the last offset of a list must equal the length of its values ({}), but it is {}",
+            values_len, last
+        )));
+    }
+    Ok(())
 }
 
 pub fn skip_list<O: Offset>(
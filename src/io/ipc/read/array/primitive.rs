@@ -6,8 +6,9 @@ use crate::error::{ArrowError, Result};
 use crate::{array::PrimitiveArray, types::NativeType};
 
 use super::super::read_basic::*;
-use super::super::{Compression, IpcBuffer, Node};
+use super::super::{Compression, IpcBuffer, Node, ReadOptions};
 
+#[allow(clippy::too_many_arguments)]
 pub fn read_primitive<T: NativeType, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
@@ -16,6 +17,7 @@ pub fn read_primitive<T: NativeType, R: Read + Seek>(
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<Compression>,
+    options: &ReadOptions,
 ) -> Result<PrimitiveArray<T>>
 where
     Vec<u8>: TryInto<T::Bytes>,
@@ -34,6 +36,7 @@ where
         block_offset,
         is_little_endian,
         compression,
+        options,
     )?;
 
     let values = read_buffer(
@@ -43,10 +46,60 @@ where
         block_offset,
         is_little_endian,
         compression,
+        options,
     )?;
     PrimitiveArray::<T>::try_new(data_type, values, validity)
 }
 
+/// Same as [`read_primitive`], but only returns the `rows.1` rows starting at the row `rows.0`.
+/// Only their values are read when the buffers are not compressed; the validity is read
+/// as a whole (one bit per row) and sliced.
+#[allow(clippy::too_many_arguments)]
+pub fn read_primitive_rows<T: NativeType, R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    data_type: DataType,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    rows: (usize, usize),
+    options: &ReadOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    Vec<u8>: TryInto<T::Bytes>,
+{
+    let field_node = field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            data_type
+        ))
+    })?;
+
+    let validity = read_validity(
+        buffers,
+        field_node,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+        options,
+    )?;
+
+    let values = read_buffer_rows(
+        buffers,
+        field_node.length() as usize,
+        rows,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+        options,
+    )?;
+    let validity = validity.map(|validity| validity.slice(rows.0, rows.1));
+    PrimitiveArray::<T>::try_new(data_type, values, validity)
+}
+
 pub fn skip_primitive(
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -2,14 +2,14 @@ use std::collections::VecDeque;
 use std::io::{Read, Seek};
 
 use crate::array::StructArray;
-use crate::datatypes::DataType;
+use crate::datatypes::{DataType, Field};
 use crate::error::{ArrowError, Result};
 
 use super::super::super::IpcField;
-use super::super::deserialize::{read, skip};
+use super::super::deserialize::{read, skip, NestedProjection};
 use super::super::read_basic::*;
 use super::super::Dictionaries;
-use super::super::{Compression, IpcBuffer, Node, Version};
+use super::super::{Compression, IpcBuffer, Node, ReadOptions, Version};
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_struct<R: Read + Seek>(
@@ -23,6 +23,8 @@ pub fn read_struct<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<Compression>,
     version: Version,
+    projection: Option<&NestedProjection>,
+    options: &ReadOptions,
 ) -> Result<StructArray> {
     let field_node = field_nodes.pop_front().ok_or_else(|| {
         ArrowError::oos(format!(
@@ -38,30 +40,78 @@ pub fn read_struct<R: Read + Seek>(
         block_offset,
         is_little_endian,
         compression,
+        options,
     )?;
 
     let fields = StructArray::get_fields(&data_type);
+    if ipc_field.fields.len() != fields.len() {
+        return Err(ArrowError::oos(format!(
+            "IPC: a struct with {} fields must have as many IPC fields, but it has {}",
+            fields.len(),
+            ipc_field.fields.len()
+        )));
+    }
 
-    let values = fields
-        .iter()
-        .zip(ipc_field.fields.iter())
-        .map(|(field, ipc_field)| {
-            read(
-                field_nodes,
-                field,
-                ipc_field,
-                buffers,
-                reader,
-                dictionaries,
-                block_offset,
-                is_little_endian,
-                compression,
-                version,
-            )
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let projection = match projection {
+        Some(projection) => projection,
+        None => {
+            let values = fields
+                .iter()
+                .zip(ipc_field.fields.iter())
+                .map(|(field, ipc_field)| {
+                    read(
+                        field_nodes,
+                        field,
+                        ipc_field,
+                        buffers,
+                        reader,
+                        dictionaries,
+                        block_offset,
+                        is_little_endian,
+                        compression,
+                        version,
+                        None,
+                        options,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            return StructArray::try_new(data_type, values, validity);
+        }
+    };
+
+    projection.check_bounds(fields.len())?;
+
+    let mut projected_fields = vec![];
+    let mut values = vec![];
+    for (index, (field, ipc_field)) in fields.iter().zip(ipc_field.fields.iter()).enumerate() {
+        match projection.get(index) {
+            Some(projection) => {
+                let value = read(
+                    field_nodes,
+                    field,
+                    ipc_field,
+                    buffers,
+                    reader,
+                    dictionaries,
+                    block_offset,
+                    is_little_endian,
+                    compression,
+                    version,
+                    projection,
+                    options,
+                )?;
+                projected_fields.push(Field {
+                    data_type: value.data_type().clone(),
+                    ..field.clone()
+                });
+                values.push(value);
+            }
+            None => skip(field_nodes, field.data_type(), buffers)?,
+        }
+    }
 
-    StructArray::try_new(data_type, values, validity)
+    StructArray::try_new(DataType::Struct(projected_fields), values, validity)
 }
 
 pub fn skip_struct(
@@ -10,11 +10,29 @@ use crate::datatypes::{DataType, Field};
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::{IpcField, IpcSchema};
 
-use super::deserialize::{read, skip};
-use super::Dictionaries;
+use super::deserialize::{read, skip, NestedProjection};
+use super::{Dictionaries, IpcBuffer, Node};
 
 type ArrayRef = Arc<dyn Array>;
 
+/// Options declaring the behaviour of reading from IPC
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ReadOptions {
+    /// Whether the offsets of list arrays are verified to start at zero and end at the length
+    /// of their values, on top of the checks that are always performed (monotonicity and bounds).
+    /// Pipelines reading trusted files may leave it disabled to skip its cost.
+    pub verify_offsets: bool,
+    /// The alignment (in bytes) that the offsets of all buffers must be a multiple of, e.g. `8`
+    /// for producers that pad buffers to 8 bytes instead of 64. Buffers are read from their
+    /// declared offsets regardless of it; `None` (the default) skips the check.
+    pub alignment: Option<usize>,
+    /// The maximum number of bytes that a buffer may declare or, when compressed, require once
+    /// decompressed, rejecting larger buffers before they are allocated. The buffers of list
+    /// arrays are always bounded by the bytes available in the reader; `None` (the default)
+    /// sets no further limit.
+    pub max_buffer_bytes: Option<usize>,
+}
+
 #[derive(Debug, Eq, PartialEq, Hash)]
 enum ProjectionResult<A> {
     Selected(A),
@@ -87,16 +105,36 @@ pub fn read_record_batch<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
 ) -> Result<Chunk<Arc<dyn Array>>> {
-    assert_eq!(fields.len(), ipc_schema.fields.len());
-    let buffers = batch
-        .buffers()?
-        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain buffers"))?;
-    let mut buffers: VecDeque<arrow_format::ipc::BufferRef> = buffers.iter().collect();
+    read_record_batch_with_options(
+        batch,
+        fields,
+        ipc_schema,
+        projection,
+        dictionaries,
+        version,
+        reader,
+        block_offset,
+        &ReadOptions::default(),
+    )
+}
 
-    let field_nodes = batch
-        .nodes()?
-        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain field nodes"))?;
-    let mut field_nodes = field_nodes.iter().collect::<VecDeque<_>>();
+/// Same as [`read_record_batch`], but reads the arrays as declared by `options`.
+/// # Panic
+/// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+#[allow(clippy::too_many_arguments)]
+pub fn read_record_batch_with_options<R: Read + Seek>(
+    batch: arrow_format::ipc::RecordBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    projection: Option<&[usize]>,
+    dictionaries: &Dictionaries,
+    version: arrow_format::ipc::MetadataVersion,
+    reader: &mut R,
+    block_offset: u64,
+    options: &ReadOptions,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    assert_eq!(fields.len(), ipc_schema.fields.len());
+    let (mut field_nodes, mut buffers) = nodes_and_buffers(&batch)?;
 
     let columns = if let Some(projection) = projection {
         let projection =
@@ -115,6 +153,8 @@ pub fn read_record_batch<R: Read + Seek>(
                     ipc_schema.is_little_endian,
                     batch.compression()?,
                     version,
+                    None,
+                    options,
                 )?)),
                 ProjectionResult::NotSelected((field, _)) => {
                     skip(&mut field_nodes, &field.data_type, &mut buffers)?;
@@ -139,6 +179,8 @@ pub fn read_record_batch<R: Read + Seek>(
                     ipc_schema.is_little_endian,
                     batch.compression()?,
                     version,
+                    None,
+                    options,
                 )
             })
             .collect::<Result<Vec<_>>>()?
@@ -146,6 +188,64 @@ pub fn read_record_batch<R: Read + Seek>(
     Chunk::try_new(columns)
 }
 
+/// Same as [`read_record_batch`], but only reads the columns (and their nested fields)
+/// selected by `projection`, whose top-level indices are the indices of the columns in `fields`.
+/// The columns are returned in increasing order of their index.
+/// # Errors
+/// This function errors iff `projection` references a column or field that does not exist
+/// or can not be projected, in addition to the errors of [`read_record_batch`].
+#[allow(clippy::too_many_arguments)]
+pub fn read_projected_record_batch<R: Read + Seek>(
+    batch: arrow_format::ipc::RecordBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    projection: &NestedProjection,
+    dictionaries: &Dictionaries,
+    version: arrow_format::ipc::MetadataVersion,
+    reader: &mut R,
+    block_offset: u64,
+    options: &ReadOptions,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    assert_eq!(fields.len(), ipc_schema.fields.len());
+    projection.check_bounds(fields.len())?;
+    let (mut field_nodes, mut buffers) = nodes_and_buffers(&batch)?;
+
+    let mut columns = vec![];
+    for (index, (field, ipc_field)) in fields.iter().zip(ipc_schema.fields.iter()).enumerate() {
+        match projection.get(index) {
+            Some(projection) => columns.push(read(
+                &mut field_nodes,
+                field,
+                ipc_field,
+                &mut buffers,
+                reader,
+                dictionaries,
+                block_offset,
+                ipc_schema.is_little_endian,
+                batch.compression()?,
+                version,
+                projection,
+                options,
+            )?),
+            None => skip(&mut field_nodes, &field.data_type, &mut buffers)?,
+        }
+    }
+    Chunk::try_new(columns)
+}
+
+/// Returns the field nodes and buffers of `batch`.
+fn nodes_and_buffers<'a>(
+    batch: &arrow_format::ipc::RecordBatchRef<'a>,
+) -> Result<(VecDeque<Node<'a>>, VecDeque<IpcBuffer<'a>>)> {
+    let buffers = batch
+        .buffers()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain buffers"))?;
+    let field_nodes = batch
+        .nodes()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain field nodes"))?;
+    Ok((field_nodes.iter().collect(), buffers.iter().collect()))
+}
+
 fn find_first_dict_field_d<'a>(
     id: i64,
     data_type: &'a DataType,
@@ -200,7 +300,10 @@ fn first_dict_field<'a>(
 }
 
 /// Read the dictionary from the buffer and provided metadata,
-/// updating the `dictionaries` with the resulting dictionary
+/// updating the `dictionaries` with the resulting dictionary.
+///
+/// The values of a delta dictionary batch are appended to the ones already read for its id,
+/// so that keys of later batches resolve against the merged values.
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
     fields: &[Field],
@@ -209,12 +312,27 @@ pub fn read_dictionary<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
 ) -> Result<()> {
-    if batch.is_delta()? {
-        return Err(ArrowError::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+    read_dictionary_with_options(
+        batch,
+        fields,
+        ipc_schema,
+        dictionaries,
+        reader,
+        block_offset,
+        &ReadOptions::default(),
+    )
+}
 
+/// Same as [`read_dictionary`], but reads the values as declared by `options`.
+pub fn read_dictionary_with_options<R: Read + Seek>(
+    batch: arrow_format::ipc::DictionaryBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut Dictionaries,
+    reader: &mut R,
+    block_offset: u64,
+    options: &ReadOptions,
+) -> Result<()> {
     let id = batch.id()?;
     let (first_field, first_ipc_field) = first_dict_field(id, fields, &ipc_schema.fields)?;
 
@@ -229,7 +347,7 @@ pub fn read_dictionary<R: Read + Seek>(
                 fields: vec![first_ipc_field.clone()],
                 is_little_endian: ipc_schema.is_little_endian,
             };
-            let columns = read_record_batch(
+            let columns = read_record_batch_with_options(
                 batch
                     .data()?
                     .ok_or_else(|| ArrowError::oos("The dictionary batch must have data."))?,
@@ -240,6 +358,7 @@ pub fn read_dictionary<R: Read + Seek>(
                 arrow_format::ipc::MetadataVersion::V5,
                 reader,
                 block_offset,
+                options,
             )?;
             let mut arrays = columns.into_arrays();
             Some(arrays.pop().unwrap())
@@ -250,11 +369,36 @@ pub fn read_dictionary<R: Read + Seek>(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    let dictionary_values = if batch.is_delta()? {
+        let existing = dictionaries.get(&id).ok_or_else(|| {
+            ArrowError::oos(format!(
+                "The delta dictionary batch of id {} must follow a dictionary batch of the same id",
+                id
+            ))
+        })?;
+        concatenate_values(existing.as_ref(), dictionary_values.as_ref())
+    } else {
+        dictionary_values
+    };
+
     dictionaries.insert(id, dictionary_values);
 
     Ok(())
 }
 
+/// Returns the values of `existing` followed by the ones of `delta`.
+fn concatenate_values(existing: &dyn Array, delta: &dyn Array) -> ArrayRef {
+    let use_validity = existing.null_count() > 0 || delta.null_count() > 0;
+    let mut growable = growable::make_growable(
+        &[existing, delta],
+        use_validity,
+        existing.len() + delta.len(),
+    );
+    growable.extend(0, 0, existing.len());
+    growable.extend(1, 0, delta.len());
+    growable.as_arc()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,11 +9,115 @@ use arrow_format::ipc::MetadataVersion;
 
 use crate::array::*;
 use crate::datatypes::{DataType, Field, PhysicalType};
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::io::ipc::IpcField;
 
+use super::read_basic::buffers_range;
 use super::{array::*, Dictionaries};
-use super::{IpcBuffer, Node};
+use super::{IpcBuffer, Node, ReadOptions};
+
+/// A projection of the nested fields of a [`Field`], used to read only some of them.
+///
+/// `fields` are the indices of the fields to read of the (first) struct found in the field
+/// (e.g. the struct itself or the struct inside a list), each with the projection of its own
+/// nested fields, where `None` reads the whole field. Fields not declared are skipped.
+///
+/// Lists and fixed-size lists pass their projection to their values. Projections of any
+/// other type (e.g. maps, unions or dictionaries) are not supported and error when read.
+///
+/// Lists are always read up to their offsets and validity: these define the length of each
+/// slot and remain valid for the projected values, so only the values are projected.
+/// # Example
+/// ```
+/// use arrow2::io::ipc::read::NestedProjection;
+/// // reads the fields `0` and `2` of a struct and, of the field `2`, only its field `1`
+/// let projection = NestedProjection::new(vec![
+///     (0, None),
+///     (2, Some(NestedProjection::new(vec![(1, None)]))),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestedProjection {
+    /// The indices of the fields to read, each with its (optional) nested projection
+    pub fields: Vec<(usize, Option<NestedProjection>)>,
+}
+
+impl NestedProjection {
+    /// Returns a new [`NestedProjection`].
+    pub fn new(fields: Vec<(usize, Option<NestedProjection>)>) -> Self {
+        Self { fields }
+    }
+
+    /// Returns the projection of the field at `index`: `None` if it is not selected,
+    /// `Some(None)` if it is selected as a whole.
+    pub(super) fn get(&self, index: usize) -> Option<Option<&NestedProjection>> {
+        self.fields
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, projection)| projection.as_ref())
+    }
+
+    /// Returns an error iff an index of `fields` is out of bounds of the `len` fields it selects from.
+    pub(super) fn check_bounds(&self, len: usize) -> Result<()> {
+        if let Some((index, _)) = self.fields.iter().find(|(index, _)| *index >= len) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The projection selects the field {} of a struct with {} fields",
+                index, len
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns `field` with the [`DataType`] of the array read from it with `projection`.
+/// # Errors
+/// This function errors iff `projection` can not be applied to `field`.
+pub(super) fn project_field(field: &Field, projection: Option<&NestedProjection>) -> Result<Field> {
+    let projection = match projection {
+        Some(projection) => projection,
+        None => return Ok(field.clone()),
+    };
+    let project_child = |child: &Field| project_field(child, Some(projection)).map(Box::new);
+
+    let data_type = match field.data_type.to_physical_type() {
+        PhysicalType::List => DataType::List(project_child(ListArray::<i32>::get_child_field(
+            &field.data_type,
+        ))?),
+        PhysicalType::LargeList => DataType::LargeList(project_child(
+            ListArray::<i64>::get_child_field(&field.data_type),
+        )?),
+        PhysicalType::FixedSizeList => {
+            let (child, size) = FixedSizeListArray::get_child_and_size(&field.data_type);
+            DataType::FixedSizeList(project_child(child)?, size)
+        }
+        PhysicalType::Struct => {
+            let fields = StructArray::get_fields(&field.data_type);
+            projection.check_bounds(fields.len())?;
+            let fields = fields
+                .iter()
+                .enumerate()
+                .filter_map(|(index, field)| {
+                    projection
+                        .get(index)
+                        .map(|projection| project_field(field, projection))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            DataType::Struct(fields)
+        }
+        _ => return Err(unsupported_projection(field)),
+    };
+    Ok(Field {
+        data_type,
+        ..field.clone()
+    })
+}
+
+fn unsupported_projection(field: &Field) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "A nested projection can only be applied to structs, lists and fixed-size lists, but the field \"{}\" is of type {:?}",
+        field.name, field.data_type
+    ))
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn read<R: Read + Seek>(
@@ -27,10 +131,21 @@ pub fn read<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<BodyCompressionRef>,
     version: MetadataVersion,
+    projection: Option<&NestedProjection>,
+    options: &ReadOptions,
 ) -> Result<Arc<dyn Array>> {
     use PhysicalType::*;
     let data_type = field.data_type.clone();
 
+    if projection.is_some()
+        && !matches!(
+            data_type.to_physical_type(),
+            List | LargeList | FixedSizeList | Struct
+        )
+    {
+        return Err(unsupported_projection(field));
+    }
+
     match data_type.to_physical_type() {
         Null => read_null(field_nodes, data_type).map(|x| Arc::new(x) as Arc<dyn Array>),
         Boolean => read_boolean(
@@ -41,6 +156,7 @@ pub fn read<R: Read + Seek>(
             block_offset,
             is_little_endian,
             compression,
+            options,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
@@ -52,6 +168,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                options,
             )
             .map(|x| Arc::new(x) as Arc<dyn Array>)
         }),
@@ -64,6 +181,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                options,
             )?;
             Ok(Arc::new(array))
         }
@@ -76,6 +194,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                options,
             )?;
             Ok(Arc::new(array))
         }
@@ -88,6 +207,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                options,
             )?;
             Ok(Arc::new(array))
         }
@@ -100,6 +220,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                options,
             )?;
             Ok(Arc::new(array))
         }
@@ -112,6 +233,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                options,
             )?;
             Ok(Arc::new(array))
         }
@@ -121,11 +243,16 @@ pub fn read<R: Read + Seek>(
             ipc_field,
             buffers,
             reader,
-            dictionaries,
-            block_offset,
-            is_little_endian,
-            compression,
-            version,
+            ListArgs {
+                dictionaries,
+                block_offset,
+                is_little_endian,
+                compression,
+                version,
+                projection,
+                options,
+                rows: None,
+            },
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         LargeList => read_list::<i64, _>(
@@ -134,11 +261,16 @@ pub fn read<R: Read + Seek>(
             ipc_field,
             buffers,
             reader,
-            dictionaries,
-            block_offset,
-            is_little_endian,
-            compression,
-            version,
+            ListArgs {
+                dictionaries,
+                block_offset,
+                is_little_endian,
+                compression,
+                version,
+                projection,
+                options,
+                rows: None,
+            },
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         FixedSizeList => read_fixed_size_list(
@@ -152,6 +284,8 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            projection,
+            options,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Struct => read_struct(
@@ -165,6 +299,8 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            projection,
+            options,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Dictionary(key_type) => {
@@ -178,6 +314,7 @@ pub fn read<R: Read + Seek>(
                     block_offset,
                     compression,
                     is_little_endian,
+                    options,
                 )
                 .map(|x| Arc::new(x) as Arc<dyn Array>)
             })
@@ -193,6 +330,7 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            options,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Map => read_map(
@@ -206,11 +344,225 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            options,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
     }
 }
 
+/// Reads a single [`Array`] described by `field` and `ipc_field` from `reader`.
+///
+/// `field_nodes` and `buffers` are the nodes and buffers of an IPC `RecordBatch` message
+/// (e.g. [`arrow_format::ipc::RecordBatchRef::nodes`]). The ones used by this array
+/// (including the ones of its children) are consumed from their front, so that
+/// consecutive calls read consecutive arrays.
+///
+/// This is the function used by [`read_record_batch`](super::read_record_batch) to read
+/// each of its columns, exposed to support protocols built on top of Arrow's buffers.
+#[allow(clippy::too_many_arguments)]
+pub fn read_array<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<BodyCompressionRef>,
+    version: MetadataVersion,
+    options: &ReadOptions,
+) -> Result<Box<dyn Array>> {
+    read(
+        field_nodes,
+        field,
+        ipc_field,
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+        None,
+        options,
+    )
+    .map(|x| x.to_boxed())
+}
+
+/// Same as [`read_array`], but also returns the number of bytes of the body spanned by the
+/// buffers of the array (from `block_offset` to the end of its last buffer).
+///
+/// This does not depend on the position of `reader`, and can be used to detect under or
+/// over-reads against the body length declared by the IPC `Block` of a message.
+#[allow(clippy::too_many_arguments)]
+pub fn read_array_consumed<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<BodyCompressionRef>,
+    version: MetadataVersion,
+    options: &ReadOptions,
+) -> Result<(Box<dyn Array>, u64)> {
+    let remaining = buffers.clone();
+    let array = read_array(
+        field_nodes,
+        field,
+        ipc_field,
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+        options,
+    )?;
+    let consumed = remaining.len() - buffers.len();
+    let (_, end) = buffers_range(remaining.iter().take(consumed))?;
+    Ok((array, end))
+}
+
+/// Same as [`read_array`], but only reads the nested fields selected by `projection`;
+/// the buffers of the remaining fields are skipped without being read nor decompressed.
+///
+/// The [`DataType`] of the returned array only contains the selected nested fields.
+/// # Errors
+/// This function errors iff `projection` references a field that does not exist or
+/// selects no field of a struct, in addition to the errors of [`read_array`].
+#[allow(clippy::too_many_arguments)]
+pub fn read_projected_array<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<BodyCompressionRef>,
+    version: MetadataVersion,
+    projection: &NestedProjection,
+    options: &ReadOptions,
+) -> Result<Box<dyn Array>> {
+    read(
+        field_nodes,
+        field,
+        ipc_field,
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+        Some(projection),
+        options,
+    )
+    .map(|x| x.to_boxed())
+}
+
+/// Same as [`read_array`] for a list ([`DataType::List`] or [`DataType::LargeList`]), but only
+/// reads its `rows.1` rows starting at the row `rows.0` (e.g. a page of it), without reading
+/// the whole list to then slice it.
+///
+/// The validity and all offsets of the list are still read and scanned, since they are
+/// needed to find the range of values spanned by the rows. Of the values, only this range is
+/// read when they are primitive (and not compressed) or lists themselves; other values are
+/// read as a whole and sliced.
+/// # Errors
+/// This function errors iff `field` is not a list or the rows are out of its bounds, in
+/// addition to the errors of [`read_array`].
+#[allow(clippy::too_many_arguments)]
+pub fn read_list_rows<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<BodyCompressionRef>,
+    version: MetadataVersion,
+    options: &ReadOptions,
+    rows: (usize, usize),
+) -> Result<Box<dyn Array>> {
+    let data_type = field.data_type.clone();
+    match data_type.to_physical_type() {
+        PhysicalType::List => read_list::<i32, _>(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            reader,
+            ListArgs {
+                dictionaries,
+                block_offset,
+                is_little_endian,
+                compression,
+                version,
+                projection: None,
+                options,
+                rows: Some(rows),
+            },
+        )
+        .map(|x| x.to_boxed()),
+        PhysicalType::LargeList => read_list::<i64, _>(
+            field_nodes,
+            data_type,
+            ipc_field,
+            buffers,
+            reader,
+            ListArgs {
+                dictionaries,
+                block_offset,
+                is_little_endian,
+                compression,
+                version,
+                projection: None,
+                options,
+                rows: Some(rows),
+            },
+        )
+        .map(|x| x.to_boxed()),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Only the rows of lists can be read, but the field is of type {:?}",
+            field.data_type
+        ))),
+    }
+}
+
+/// Returns the number of field nodes spanned by `data_type` in a record batch: one for itself
+/// and one for each of its (nested) children.
+pub fn count_nodes(data_type: &DataType) -> usize {
+    use PhysicalType::*;
+    1 + match data_type.to_physical_type() {
+        List => count_nodes(ListArray::<i32>::get_child_type(data_type)),
+        LargeList => count_nodes(ListArray::<i64>::get_child_type(data_type)),
+        FixedSizeList => count_nodes(
+            FixedSizeListArray::get_child_and_size(data_type)
+                .0
+                .data_type(),
+        ),
+        Struct => StructArray::get_fields(data_type)
+            .iter()
+            .map(|field| count_nodes(field.data_type()))
+            .sum(),
+        Union => UnionArray::get_fields(data_type)
+            .iter()
+            .map(|field| count_nodes(field.data_type()))
+            .sum(),
+        Map => count_nodes(MapArray::get_field(data_type).data_type()),
+        _ => 0,
+    }
+}
+
 pub fn skip(
     field_nodes: &mut VecDeque<Node>,
     data_type: &DataType,
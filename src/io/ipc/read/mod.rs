@@ -24,8 +24,20 @@ pub mod stream_async;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_read_async")))]
 pub mod file_async;
 
-pub use common::{read_dictionary, read_record_batch};
-pub use reader::{read_file_metadata, FileMetadata, FileReader};
+#[cfg(feature = "io_ipc_read_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_read_async")))]
+pub use array::read_list_async;
+pub use common::{
+    read_dictionary, read_dictionary_with_options, read_projected_record_batch, read_record_batch,
+    read_record_batch_with_options, ReadOptions,
+};
+pub use deserialize::{
+    read_array, read_array_consumed, read_list_rows, read_projected_array, NestedProjection,
+};
+pub use reader::{
+    read_batch, read_batch_with_options, read_file_metadata, read_projected_batch, FileMetadata,
+    FileReader,
+};
 pub use schema::deserialize_schema;
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
 
@@ -1,13 +1,15 @@
 use std::io::{Read, Seek, SeekFrom};
 use std::{collections::VecDeque, convert::TryInto};
 
+use arrow_format::ipc::CompressionType;
+
 use crate::buffer::Buffer;
 use crate::error::{ArrowError, Result};
-use crate::{bitmap::Bitmap, types::NativeType};
+use crate::{array::Offset, bitmap::Bitmap, types::NativeType};
 
 use super::super::compression;
 use super::super::endianess::is_native_little_endian;
-use super::{Compression, IpcBuffer, Node};
+use super::{Compression, IpcBuffer, Node, ReadOptions};
 
 fn read_swapped<T: NativeType, R: Read + Seek>(
     reader: &mut R,
@@ -78,6 +80,66 @@ fn read_uncompressed_buffer<T: NativeType, R: Read + Seek>(
     Ok(buffer)
 }
 
+/// Decompresses an IPC buffer into `out`. As per the specification, a compressed buffer
+/// is prefixed by its uncompressed length as a little-endian `i64`, where `-1` declares
+/// that the remaining bytes are not compressed.
+fn decompress(compressed: &[u8], out: &mut [u8], codec: CompressionType) -> Result<()> {
+    if compressed.is_empty() && out.is_empty() {
+        return Ok(());
+    }
+    if compressed.len() < 8 {
+        return Err(ArrowError::oos(
+            "IPC: a compressed buffer must start with its uncompressed length",
+        ));
+    }
+    let (length, data) = compressed.split_at(8);
+    let length = i64::from_le_bytes(length.try_into().unwrap());
+
+    if length == -1 {
+        if data.len() < out.len() {
+            return Err(ArrowError::oos(format!(
+                "IPC: the buffer has {} bytes but {} bytes are required",
+                data.len(),
+                out.len()
+            )));
+        }
+        out.copy_from_slice(&data[..out.len()]);
+        return Ok(());
+    }
+    if length < out.len() as i64 {
+        return Err(ArrowError::oos(format!(
+            "IPC: the uncompressed buffer has {} bytes but {} bytes are required",
+            length,
+            out.len()
+        )));
+    }
+
+    match codec {
+        CompressionType::Lz4Frame => compression::decompress_lz4(data, out),
+        CompressionType::Zstd => compression::decompress_zstd(data, out),
+    }
+}
+
+/// Errors iff `compression` declares a codec that this crate was compiled without, naming the
+/// codec and the (first) `buffer` it was found on.
+pub fn check_compression(compression: Option<Compression>, buffer: &str) -> Result<()> {
+    let codec = match compression {
+        Some(compression) => compression.codec()?,
+        None => return Ok(()),
+    };
+    if cfg!(feature = "io_ipc_compression") {
+        return Ok(());
+    }
+    let codec = match codec {
+        CompressionType::Lz4Frame => "LZ4_FRAME",
+        CompressionType::Zstd => "ZSTD",
+    };
+    Err(ArrowError::NotYetImplemented(format!(
+        "IPC {} buffer uses {} compression but the 'io_ipc_compression' feature is disabled",
+        buffer, codec
+    )))
+}
+
 fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     buffer_length: usize,
@@ -102,16 +164,8 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
 
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], out_slice)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], out_slice)?;
-            Ok(buffer)
-        }
-    }
+    decompress(&slice, out_slice, compression.codec()?)?;
+    Ok(buffer)
 }
 
 pub fn read_buffer<T: NativeType, R: Read + Seek>(
@@ -121,16 +175,20 @@ pub fn read_buffer<T: NativeType, R: Read + Seek>(
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<Compression>,
+    options: &ReadOptions,
 ) -> Result<Buffer<T>> {
     let buf = buf
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
+    check_buffer_options(&buf, options)?;
 
     reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
 
     let buffer_length = buf.length() as usize;
 
     if let Some(compression) = compression {
+        // the decompressed buffer is allocated from `length`, not from the declared length
+        check_decompressed_length(length.saturating_mul(std::mem::size_of::<T>()), options)?;
         Ok(
             read_compressed_buffer(reader, buffer_length, length, is_little_endian, compression)?
                 .into(),
@@ -140,6 +198,233 @@ pub fn read_buffer<T: NativeType, R: Read + Seek>(
     }
 }
 
+/// Same as [`read_buffer`] for a buffer of `length` slots, but only returns the `rows.1` slots
+/// starting at the slot `rows.0`. Only these are read from `reader` when the buffer is not
+/// compressed; a compressed buffer is decompressed as a whole.
+#[allow(clippy::too_many_arguments)]
+pub fn read_buffer_rows<T: NativeType, R: Read + Seek>(
+    buf: &mut VecDeque<IpcBuffer>,
+    length: usize, // in slots
+    rows: (usize, usize),
+    reader: &mut R,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    options: &ReadOptions,
+) -> Result<Buffer<T>> {
+    let (offset, take) = rows;
+    if offset.saturating_add(take) > length {
+        return Err(ArrowError::oos(format!(
+            "IPC: the rows {}..{} are out of bounds of a buffer of {} slots",
+            offset,
+            offset.saturating_add(take),
+            length
+        )));
+    }
+    if compression.is_some() {
+        let buffer = read_buffer::<T, _>(
+            buf,
+            length,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            options,
+        )?;
+        return Ok(buffer.slice(offset, take));
+    }
+    let buf = buf
+        .pop_front()
+        .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
+    check_buffer_options(&buf, options)?;
+
+    let buffer_length = buf.length() as usize;
+    let bytes = length.saturating_mul(std::mem::size_of::<T>());
+    if bytes > buffer_length {
+        return Err(ArrowError::oos(format!(
+            "IPC: {} slots require {} bytes, but the buffer only has {} bytes",
+            length, bytes, buffer_length
+        )));
+    }
+    let skipped = offset * std::mem::size_of::<T>();
+    reader.seek(SeekFrom::Start(
+        block_offset + buf.offset() as u64 + skipped as u64,
+    ))?;
+    Ok(read_uncompressed_buffer(reader, buffer_length - skipped, take, is_little_endian)?.into())
+}
+
+/// Number of offsets read at a time by [`read_offsets`].
+const OFFSETS_CHUNK_LENGTH: usize = 64 * 1024;
+
+/// Errors iff the IPC buffer `buf` declares more bytes than `options.max_buffer_bytes` or does
+/// not start at a multiple of `options.alignment` (when set).
+fn check_buffer_options(buf: &IpcBuffer, options: &ReadOptions) -> Result<()> {
+    if let Some(alignment) = options.alignment {
+        check_buffer_alignment(buf, alignment)?;
+    }
+    if let Some(max_buffer_bytes) = options.max_buffer_bytes {
+        if buf.length() as u64 > max_buffer_bytes as u64 {
+            return Err(ArrowError::oos(format!(
+                "IPC: a buffer declares {} bytes, more than the maximum of {} bytes per buffer",
+                buf.length(),
+                max_buffer_bytes
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Errors iff a compressed buffer requires more than `options.max_buffer_bytes` (when set)
+/// once decompressed, before these `bytes` are allocated.
+fn check_decompressed_length(bytes: usize, options: &ReadOptions) -> Result<()> {
+    if let Some(max_buffer_bytes) = options.max_buffer_bytes {
+        if bytes > max_buffer_bytes {
+            return Err(ArrowError::oos(format!(
+                "IPC: a compressed buffer requires {} bytes once decompressed, more than the maximum of {} bytes per buffer",
+                bytes, max_buffer_bytes
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Errors iff the IPC buffer `buf` declares more bytes than the ones available in `reader`
+/// from its position, i.e. the maximum number of bytes a buffer can legitimately have, or
+/// than `options.max_buffer_bytes` (when set).
+/// This prevents a malicious or corrupted declared length from triggering an arbitrarily
+/// large allocation.
+pub fn check_buffer_length<R: Seek>(
+    buf: &IpcBuffer,
+    reader: &mut R,
+    block_offset: u64,
+    options: &ReadOptions,
+) -> Result<()> {
+    check_buffer_options(buf, options)?;
+    let end = reader.seek(SeekFrom::End(0))?;
+    let available = end.saturating_sub(block_offset.saturating_add(buf.offset() as u64));
+    if buf.length() as u64 > available {
+        return Err(ArrowError::oos(format!(
+            "IPC: a buffer declares {} bytes, but only {} bytes are available. The file or stream is corrupted.",
+            buf.length(),
+            available
+        )));
+    }
+    Ok(())
+}
+
+/// Errors iff the offset of `buf` is not a multiple of `alignment` (in bytes).
+fn check_buffer_alignment(buf: &IpcBuffer, alignment: usize) -> Result<()> {
+    if alignment == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "IPC: the alignment of buffers must be larger than zero".to_string(),
+        ));
+    }
+    if buf.offset() % alignment as i64 != 0 {
+        return Err(ArrowError::oos(format!(
+            "IPC: a buffer starts at offset {}, which is not a multiple of the alignment {}",
+            buf.offset(),
+            alignment
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the range of bytes (relative to the start of the body of a message) spanned by
+/// `buffers`, from the start of the first buffer to the end of the last one, or `(0, 0)` when
+/// there are no buffers.
+pub fn buffers_range<'a, 'b: 'a, I: Iterator<Item = &'a IpcBuffer<'b>>>(
+    mut buffers: I,
+) -> Result<(u64, u64)> {
+    let (start, end) = buffers.try_fold((u64::MAX, 0u64), |(start, end), buffer| {
+        let offset: u64 = buffer
+            .offset()
+            .try_into()
+            .map_err(|_| ArrowError::oos("IPC: a buffer's offset must be non-negative"))?;
+        let length: u64 = buffer
+            .length()
+            .try_into()
+            .map_err(|_| ArrowError::oos("IPC: a buffer's length must be non-negative"))?;
+        Result::Ok((start.min(offset), end.max(offset.saturating_add(length))))
+    })?;
+    Ok((start.min(end), end))
+}
+
+/// Errors iff `offsets` is not monotonically increasing from `previous`.
+/// Returns the last offset.
+fn check_offsets<O: Offset>(offsets: &[O], previous: O) -> Result<O> {
+    offsets.iter().try_fold(previous, |previous, &offset| {
+        if offset < previous {
+            Err(ArrowError::oos(
+                "IPC: offsets must be non-negative and monotonically increasing",
+            ))
+        } else {
+            Ok(offset)
+        }
+    })
+}
+
+/// Reads `length` offsets of a variable-length array (e.g. a list).
+///
+/// The declared length of the buffer is validated against the bytes available in `reader`
+/// and `options.max_buffer_bytes` (also once decompressed) and, when the buffer is not
+/// compressed, the offsets are read in chunks and validated incrementally, so that invalid
+/// offsets are rejected before the whole buffer is read.
+pub fn read_offsets<O: Offset, R: Read + Seek>(
+    buf: &mut VecDeque<IpcBuffer>,
+    length: usize, // in slots
+    reader: &mut R,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    options: &ReadOptions,
+) -> Result<Buffer<O>> {
+    let buffer = buf
+        .front()
+        .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
+    check_buffer_length(buffer, reader, block_offset, options)?;
+
+    if compression.is_some() {
+        let offsets = read_buffer(
+            buf,
+            length,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            options,
+        )?;
+        check_offsets(&offsets, O::default())?;
+        return Ok(offsets);
+    }
+    let buffer = buf.pop_front().unwrap();
+
+    let buffer_length = buffer.length() as usize;
+    let bytes = length.saturating_mul(std::mem::size_of::<O>());
+    if bytes > buffer_length {
+        return Err(ArrowError::oos(format!(
+            "IPC: {} offsets require {} bytes, but the buffer only has {} bytes",
+            length, bytes, buffer_length
+        )));
+    }
+
+    reader.seek(SeekFrom::Start(block_offset + buffer.offset() as u64))?;
+
+    let mut offsets = Vec::<O>::new();
+    let mut previous = O::default();
+    while offsets.len() < length {
+        let chunk_length = (length - offsets.len()).min(OFFSETS_CHUNK_LENGTH);
+        let chunk = read_uncompressed_buffer::<O, _>(
+            reader,
+            chunk_length * std::mem::size_of::<O>(),
+            chunk_length,
+            is_little_endian,
+        )?;
+        previous = check_offsets(&chunk, previous)?;
+        offsets.extend_from_slice(&chunk);
+    }
+    Ok(offsets.into())
+}
+
 fn read_uncompressed_bitmap<R: Read + Seek>(
     length: usize,
     bytes: usize,
@@ -175,16 +460,8 @@ fn read_compressed_bitmap<R: Read + Seek>(
     let mut slice = vec![0u8; bytes];
     reader.read_exact(&mut slice)?;
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-    }
+    decompress(&slice, &mut buffer, compression.codec()?)?;
+    Ok(buffer)
 }
 
 pub fn read_bitmap<R: Read + Seek>(
@@ -194,16 +471,19 @@ pub fn read_bitmap<R: Read + Seek>(
     block_offset: u64,
     _: bool,
     compression: Option<Compression>,
+    options: &ReadOptions,
 ) -> Result<Bitmap> {
     let buf = buf
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
+    check_buffer_options(&buf, options)?;
 
     reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
 
     let bytes = buf.length() as usize;
 
     let buffer = if let Some(compression) = compression {
+        check_decompressed_length((length + 7) / 8, options)?;
         read_compressed_bitmap(length, bytes, compression, reader)
     } else {
         read_uncompressed_bitmap(length, bytes, reader)
@@ -219,6 +499,7 @@ pub fn read_validity<R: Read + Seek>(
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<Compression>,
+    options: &ReadOptions,
 ) -> Result<Option<Bitmap>> {
     Ok(if field_node.null_count() > 0 {
         Some(read_bitmap(
@@ -228,11 +509,57 @@ pub fn read_validity<R: Read + Seek>(
             block_offset,
             is_little_endian,
             compression,
+            options,
         )?)
     } else {
-        let _ = buffers.pop_front().ok_or_else(|| {
+        let buffer = buffers.pop_front().ok_or_else(|| {
             ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted.")
         })?;
+        check_buffer_options(&buffer, options)?;
         None
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompress_uncompressed() {
+        let mut data = (-1i64).to_le_bytes().to_vec();
+        data.extend_from_slice(&[1, 2, 3, 4]);
+
+        let mut out = vec![0; 4];
+        decompress(&data, &mut out, CompressionType::Zstd).unwrap();
+        assert_eq!(out, vec![1, 2, 3, 4]);
+
+        let mut out = vec![0; 5];
+        assert!(decompress(&data, &mut out, CompressionType::Zstd).is_err());
+    }
+
+    #[test]
+    fn decompress_empty() {
+        let mut out = vec![];
+        decompress(&[], &mut out, CompressionType::Lz4Frame).unwrap();
+
+        let mut out = vec![0; 1];
+        assert!(decompress(&[], &mut out, CompressionType::Lz4Frame).is_err());
+    }
+
+    #[cfg(feature = "io_ipc_compression")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // ZSTD uses foreign calls that miri does not support
+    fn decompress_zstd() {
+        let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
+        let mut compressed = (data.len() as i64).to_le_bytes().to_vec();
+        compression::compress_zstd(&data, &mut compressed).unwrap();
+
+        let mut out = vec![0; 200];
+        decompress(&compressed, &mut out, CompressionType::Zstd).unwrap();
+        assert_eq!(out, data);
+
+        // the declared uncompressed length is too small
+        let mut out = vec![0; 201];
+        assert!(decompress(&compressed, &mut out, CompressionType::Zstd).is_err());
+    }
+}
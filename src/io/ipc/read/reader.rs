@@ -11,6 +11,7 @@ use crate::io::ipc::IpcSchema;
 
 use super::super::{ARROW_MAGIC, CONTINUATION_MARKER};
 use super::common::*;
+use super::deserialize::{project_field, NestedProjection};
 use super::schema::fb_to_schema;
 use super::Dictionaries;
 use arrow_format::ipc::planus::ReadAsRoot;
@@ -41,7 +42,9 @@ pub struct FileReader<R: Read + Seek> {
     dictionaries: Option<Dictionaries>,
     current_block: usize,
     projection: Option<(Vec<usize>, HashMap<usize, usize>, Schema)>,
+    nested_projection: Option<(NestedProjection, Schema)>,
     buffer: Vec<u8>,
+    options: ReadOptions,
 }
 
 fn read_dictionary_message<R: Read + Seek>(
@@ -70,6 +73,7 @@ pub(crate) fn read_dictionaries<R: Read + Seek>(
     fields: &[Field],
     ipc_schema: &IpcSchema,
     blocks: &[arrow_format::ipc::Block],
+    options: &ReadOptions,
 ) -> Result<Dictionaries> {
     let mut dictionaries = Default::default();
     let mut data = vec![];
@@ -90,13 +94,14 @@ pub(crate) fn read_dictionaries<R: Read + Seek>(
         match header {
             arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
                 let block_offset = offset + length;
-                read_dictionary(
+                read_dictionary_with_options(
                     batch,
                     fields,
                     ipc_schema,
                     &mut dictionaries,
                     reader,
                     block_offset,
+                    options,
                 )?;
             }
             t => {
@@ -217,15 +222,14 @@ pub(super) fn get_serialized_batch<'a>(
     }
 }
 
-/// Read a batch from the reader.
-pub fn read_batch<R: Read + Seek>(
+/// Reads the message of the block `block` of `metadata` into `block_data`, returning
+/// it and the offset of its body.
+fn read_block_message<'a, R: Read + Seek>(
     reader: &mut R,
-    dictionaries: &Dictionaries,
     metadata: &FileMetadata,
-    projection: Option<&[usize]>,
     block: usize,
-    block_data: &mut Vec<u8>,
-) -> Result<Chunk<Arc<dyn Array>>> {
+    block_data: &'a mut Vec<u8>,
+) -> Result<(arrow_format::ipc::MessageRef<'a>, u64)> {
     let block = metadata.blocks[block];
 
     // read length
@@ -241,13 +245,47 @@ pub fn read_batch<R: Read + Seek>(
     block_data.clear();
     block_data.resize(meta_len, 0);
     reader.read_exact(block_data)?;
+    let block_data: &'a Vec<u8> = block_data;
 
     let message = arrow_format::ipc::MessageRef::read_as_root(&block_data[..])
         .map_err(|err| ArrowError::oos(format!("Unable parse message: {:?}", err)))?;
+    Ok((message, block.offset as u64 + block.meta_data_length as u64))
+}
 
+/// Read a batch from the reader.
+pub fn read_batch<R: Read + Seek>(
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    block: usize,
+    block_data: &mut Vec<u8>,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    read_batch_with_options(
+        reader,
+        dictionaries,
+        metadata,
+        projection,
+        block,
+        block_data,
+        &ReadOptions::default(),
+    )
+}
+
+/// Same as [`read_batch`], but reads the arrays as declared by `options`.
+pub fn read_batch_with_options<R: Read + Seek>(
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    block: usize,
+    block_data: &mut Vec<u8>,
+    options: &ReadOptions,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let (message, block_offset) = read_block_message(reader, metadata, block, block_data)?;
     let batch = get_serialized_batch(&message)?;
 
-    read_record_batch(
+    read_record_batch_with_options(
         batch,
         &metadata.schema.fields,
         &metadata.ipc_schema,
@@ -255,7 +293,38 @@ pub fn read_batch<R: Read + Seek>(
         dictionaries,
         message.version()?,
         reader,
-        block.offset as u64 + block.meta_data_length as u64,
+        block_offset,
+        options,
+    )
+}
+
+/// Same as [`read_batch`], but only reads the columns (and their nested fields) selected by
+/// `projection`, as per [`read_projected_record_batch`].
+/// # Errors
+/// This function errors iff `projection` references a column or field that does not exist
+/// or can not be projected, in addition to the errors of [`read_batch`].
+pub fn read_projected_batch<R: Read + Seek>(
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    metadata: &FileMetadata,
+    projection: &NestedProjection,
+    block: usize,
+    block_data: &mut Vec<u8>,
+    options: &ReadOptions,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let (message, block_offset) = read_block_message(reader, metadata, block, block_data)?;
+    let batch = get_serialized_batch(&message)?;
+
+    read_projected_record_batch(
+        batch,
+        &metadata.schema.fields,
+        &metadata.ipc_schema,
+        projection,
+        dictionaries,
+        message.version()?,
+        reader,
+        block_offset,
+        options,
     )
 }
 
@@ -277,13 +346,56 @@ impl<R: Read + Seek> FileReader<R> {
             metadata,
             dictionaries: Default::default(),
             projection,
+            nested_projection: None,
             current_block: 0,
             buffer: vec![],
+            options: Default::default(),
         }
     }
 
+    /// Creates a new [`FileReader`] that only reads the columns (and their nested fields)
+    /// selected by `projection`, whose top-level indices are the indices of the columns of the
+    /// file. The columns are read in increasing order of their index.
+    /// # Errors
+    /// This function errors iff `projection` references a column or field that does not exist
+    /// or can not be projected (see [`NestedProjection`]).
+    pub fn try_new_nested(
+        reader: R,
+        metadata: FileMetadata,
+        projection: NestedProjection,
+    ) -> Result<Self> {
+        let fields = &metadata.schema.fields;
+        projection.check_bounds(fields.len())?;
+        let fields = fields
+            .iter()
+            .enumerate()
+            .filter_map(|(index, field)| {
+                projection
+                    .get(index)
+                    .map(|projection| project_field(field, projection))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = Schema {
+            fields,
+            metadata: metadata.schema.metadata.clone(),
+        };
+        Ok(Self {
+            reader,
+            metadata,
+            dictionaries: Default::default(),
+            projection: None,
+            nested_projection: Some((projection, schema)),
+            current_block: 0,
+            buffer: vec![],
+            options: Default::default(),
+        })
+    }
+
     /// Return the schema of the file
     pub fn schema(&self) -> &Schema {
+        if let Some((_, schema)) = &self.nested_projection {
+            return schema;
+        }
         self.projection
             .as_ref()
             .map(|x| &x.2)
@@ -295,6 +407,11 @@ impl<R: Read + Seek> FileReader<R> {
         &self.metadata
     }
 
+    /// Sets the [`ReadOptions`] used to read the next batches
+    pub fn set_options(&mut self, options: ReadOptions) {
+        self.options = options;
+    }
+
     /// Consumes this FileReader, returning the underlying reader
     pub fn into_inner(self) -> R {
         self.reader
@@ -311,6 +428,7 @@ impl<R: Read + Seek> FileReader<R> {
                     &self.metadata.schema.fields,
                     &self.metadata.ipc_schema,
                     blocks,
+                    &self.options,
                 )?;
                 self.dictionaries = Some(dictionaries);
             }
@@ -340,13 +458,26 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
         let block = self.current_block;
         self.current_block += 1;
 
-        let chunk = read_batch(
+        if let Some((projection, _)) = &self.nested_projection {
+            return Some(read_projected_batch(
+                &mut self.reader,
+                self.dictionaries.as_ref().unwrap(),
+                &self.metadata,
+                projection,
+                block,
+                &mut self.buffer,
+                &self.options,
+            ));
+        }
+
+        let chunk = read_batch_with_options(
             &mut self.reader,
             self.dictionaries.as_ref().unwrap(),
             &self.metadata,
             self.projection.as_ref().map(|x| x.0.as_ref()),
             block,
             &mut self.buffer,
+            &self.options,
         );
 
         let chunk = if let Some((projection, map, _)) = &self.projection {
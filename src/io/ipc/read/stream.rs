@@ -87,6 +87,7 @@ fn read_next<R: Read>(
     dictionaries: &mut Dictionaries,
     message_buffer: &mut Vec<u8>,
     data_buffer: &mut Vec<u8>,
+    options: &ReadOptions,
 ) -> Result<Option<StreamState>> {
     // determine metadata length
     let mut meta_length: [u8; 4] = [0; 4];
@@ -140,7 +141,7 @@ fn read_next<R: Read>(
 
             let mut reader = std::io::Cursor::new(data_buffer);
 
-            read_record_batch(
+            read_record_batch_with_options(
                 batch,
                 &metadata.schema.fields,
                 &metadata.ipc_schema,
@@ -149,6 +150,7 @@ fn read_next<R: Read>(
                 metadata.version,
                 &mut reader,
                 0,
+                options,
             )
             .map(|x| Some(StreamState::Some(x)))
         }
@@ -159,17 +161,25 @@ fn read_next<R: Read>(
 
             let mut dict_reader = std::io::Cursor::new(buf);
 
-            read_dictionary(
+            read_dictionary_with_options(
                 batch,
                 &metadata.schema.fields,
                 &metadata.ipc_schema,
                 dictionaries,
                 &mut dict_reader,
                 0,
+                options,
             )?;
 
             // read the next message until we encounter a RecordBatch message
-            read_next(reader, metadata, dictionaries, message_buffer, data_buffer)
+            read_next(
+                reader,
+                metadata,
+                dictionaries,
+                message_buffer,
+                data_buffer,
+                options,
+            )
         }
         t => Err(ArrowError::OutOfSpec(format!(
             "Reading types other than record batches not yet supported, unable to read {:?} ",
@@ -191,6 +201,7 @@ pub struct StreamReader<R: Read> {
     finished: bool,
     data_buffer: Vec<u8>,
     message_buffer: Vec<u8>,
+    options: ReadOptions,
 }
 
 impl<R: Read> StreamReader<R> {
@@ -207,6 +218,7 @@ impl<R: Read> StreamReader<R> {
             finished: false,
             data_buffer: vec![],
             message_buffer: vec![],
+            options: Default::default(),
         }
     }
 
@@ -215,6 +227,11 @@ impl<R: Read> StreamReader<R> {
         &self.metadata
     }
 
+    /// Sets the [`ReadOptions`] used to read the next batches
+    pub fn set_options(&mut self, options: ReadOptions) {
+        self.options = options;
+    }
+
     /// Check if the stream is finished
     pub fn is_finished(&self) -> bool {
         self.finished
@@ -230,6 +247,7 @@ impl<R: Read> StreamReader<R> {
             &mut self.dictionaries,
             &mut self.message_buffer,
             &mut self.data_buffer,
+            &self.options,
         )?;
         if batch.is_none() {
             self.finished = true;
@@ -1,5 +1,5 @@
 use arrow2::{
-    array::{Array, BinaryArray},
+    array::{Array, BinaryArray, BinaryFormat},
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::DataType,
@@ -140,5 +140,56 @@ fn value_unchecked_with_wrong_offsets_panics() {
 fn debug() {
     let array = BinaryArray::<i32>::from(&[Some([1, 2].as_ref()), Some(&[]), None]);
 
-    assert_eq!(format!("{:?}", array), "BinaryArray[[1, 2], [], None]");
+    assert_eq!(format!("{:?}", array), "BinaryArray[0x0102, 0x, None]");
+}
+
+#[test]
+fn debug_escaped() {
+    let array = BinaryArray::<i64>::from([Some(b"a\"\x00".as_ref()), Some(&[]), None]);
+
+    assert_eq!(
+        format!("{:?}", array.debug_as(BinaryFormat::Escaped)),
+        r#"LargeBinaryArray[b"a\"\x00", b"", None]"#
+    );
+    assert_eq!(
+        format!("{:?}", array.debug_as(BinaryFormat::Hex)),
+        "LargeBinaryArray[0x612200, 0x, None]"
+    );
+}
+
+#[test]
+fn debug_long_values() {
+    let long = b"Hello, world! ".repeat(10);
+    let max = [7u8; 64];
+    let array = BinaryArray::<i32>::from([Some(b"Hi".as_ref()), Some(&long), None, Some(&max)]);
+
+    let expected = format!(
+        "BinaryArray[0x4869, <140 bytes: 48656c6c6f2c20776f726c6421204865...>, None, 0x{}]",
+        "07".repeat(64)
+    );
+    assert_eq!(format!("{:?}", array), expected);
+
+    let expected = format!(
+        r#"BinaryArray[b"Hi", <140 bytes: b"Hello, world! He"...>, None, b"{}"]"#,
+        "\\x07".repeat(64)
+    );
+    assert_eq!(
+        format!("{:?}", array.debug_as(BinaryFormat::Escaped)),
+        expected
+    );
+}
+
+#[test]
+fn debug_truncated() {
+    let array = BinaryArray::<i32>::from_iter_values((0..1000).map(|_| b"a"));
+
+    let expected = format!(
+        "BinaryArray[{}, ... (900 more)]",
+        vec!["0x61"; 100].join(", ")
+    );
+    assert_eq!(format!("{:?}", array), expected);
+
+    // the alternate flag writes all values
+    let expected = format!("BinaryArray[{}]", vec!["0x61"; 1000].join(", "));
+    assert_eq!(format!("{:#?}", array), expected);
 }
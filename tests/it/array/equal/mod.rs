@@ -4,6 +4,7 @@ mod dictionary;
 mod fixed_size_list;
 mod list;
 mod primitive;
+mod struct_;
 mod utf8;
 
 pub fn test_equal(lhs: &dyn Array, rhs: &dyn Array, expected: bool) {
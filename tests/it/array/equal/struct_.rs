@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
+
+use super::test_equal;
+
+fn struct_array(values: Vec<Arc<dyn Array>>, validity: Option<Bitmap>) -> StructArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    StructArray::from_data(DataType::Struct(fields), values, validity)
+}
+
+#[test]
+fn equal() {
+    let a = struct_array(
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2, 3])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+        ],
+        None,
+    );
+    // same logical content, built with a different capacity and from a sliced child
+    let mut ints = MutablePrimitiveArray::<i32>::with_capacity(100);
+    ints.extend_from_slice(&[1, 2, 3]);
+    let utf8 = Utf8Array::<i32>::from_slice(["z", "a", "b", "c"]).slice(1, 3);
+    let b = struct_array(vec![ints.as_arc(), Arc::new(utf8)], None);
+    test_equal(&a, &b, true);
+    assert_eq!(a, b);
+
+    let c = struct_array(
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2, 3])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "d"])),
+        ],
+        None,
+    );
+    test_equal(&a, &c, false);
+}
+
+#[test]
+fn equal_validity() {
+    let values = || -> Vec<Arc<dyn Array>> {
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2, 3])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+        ]
+    };
+    let a = struct_array(values(), Some(Bitmap::from([true, false, true])));
+
+    // the values of null rows are irrelevant
+    let b = struct_array(
+        vec![
+            Arc::new(Int32Array::from_slice([1, 5, 3])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "e", "c"])),
+        ],
+        Some(Bitmap::from([true, false, true])),
+    );
+    test_equal(&a, &b, true);
+
+    let b = struct_array(values(), Some(Bitmap::from([true, true, false])));
+    test_equal(&a, &b, false);
+
+    // a validity whose all slots are set is equal to no validity
+    let a = struct_array(values(), Some(Bitmap::from([true, true, true])));
+    let b = struct_array(values(), None);
+    test_equal(&a, &b, true);
+
+    let a = struct_array(values(), Some(Bitmap::from([true, false, true])));
+    test_equal(&a, &b, false);
+}
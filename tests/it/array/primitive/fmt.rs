@@ -10,6 +10,22 @@ fn debug_int32() {
     assert_eq!(format!("{:?}", array), "Int32[1, None, 2]");
 }
 
+#[test]
+fn debug_truncated() {
+    let array = Int32Array::from_vec(vec![1; 250]);
+
+    let expected = format!("Int32[{}, ... (150 more)]", vec!["1"; 100].join(", "));
+    assert_eq!(format!("{:?}", array), expected);
+
+    let expected = format!("Int32[{}]", vec!["1"; 250].join(", "));
+    assert_eq!(format!("{:#?}", array), expected);
+
+    // arrays of the maximum length are not truncated
+    let array = Int32Array::from_vec(vec![1; 100]);
+    let expected = format!("Int32[{}]", vec!["1"; 100].join(", "));
+    assert_eq!(format!("{:?}", array), expected);
+}
+
 #[test]
 fn debug_date32() {
     let array = Int32Array::from(&[Some(1), None, Some(2)]).to(DataType::Date32);
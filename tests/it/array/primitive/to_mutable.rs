@@ -51,3 +51,35 @@ fn array_to_mutable_validity() {
     let arr = PrimitiveArray::from_data(DataType::Int32, data.into(), Some(bitmap.clone()));
     assert!(matches!(arr.into_mut(), Either::Left(_)));
 }
+
+#[test]
+fn into_values_owned() {
+    let data = vec![1, 2, 3];
+    let ptr = data.as_ptr();
+    let arr = PrimitiveArray::from_vec(data);
+
+    let (values, validity) = arr.into_values();
+    assert_eq!(values, vec![1, 2, 3]);
+    assert_eq!(values.as_ptr(), ptr);
+    assert_eq!(validity, None);
+}
+
+#[test]
+fn into_values_shared() {
+    let arr = PrimitiveArray::from([Some(1), None, Some(3)]);
+    let arr2 = arr.clone();
+
+    let (values, validity) = arr2.slice(1, 2).into_values();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[1], 3);
+    assert_eq!(validity, Some(Bitmap::from([false, true])));
+    assert_ne!(values.as_ptr(), arr.values().as_ptr());
+}
+
+#[test]
+fn into_values_sliced_from_start() {
+    let arr = PrimitiveArray::from_vec(vec![1, 2, 3]).slice(0, 2);
+
+    let (values, _) = arr.into_values();
+    assert_eq!(values, vec![1, 2]);
+}
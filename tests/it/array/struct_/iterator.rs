@@ -24,6 +24,42 @@ fn test_simple_iter() {
             new_scalar(boolean.as_ref(), i),
             new_scalar(int.as_ref(), i),
         ]);
-        assert_eq!(expected, item);
+        assert_eq!(expected, item.map(|row| row.to_vec()));
     }
 }
+
+#[test]
+fn test_iter_rows() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice([false, true, true])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from([Some(42), Some(28), None])) as Arc<dyn Array>;
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, true),
+    ];
+
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![boolean.clone(), int.clone()],
+        Some([true, false, true].into()),
+    );
+
+    let rows = array.iter().collect::<Vec<_>>();
+    assert_eq!(rows.len(), 3);
+    assert!(rows[1].is_none());
+
+    let row = rows[2].unwrap();
+    assert_eq!(row.index(), 2);
+    assert_eq!(row.len(), 2);
+    assert_eq!(row.get(0), new_scalar(boolean.as_ref(), 2));
+    assert_eq!(row.get(1), new_scalar(int.as_ref(), 2));
+    assert!(!row.get(1).is_valid());
+
+    let row = array.values_iter().rev().nth(1).unwrap();
+    assert_eq!(row.index(), 1);
+    assert_eq!(
+        row.to_vec(),
+        vec![new_scalar(boolean.as_ref(), 1), new_scalar(int.as_ref(), 1)]
+    );
+}
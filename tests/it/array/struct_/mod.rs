@@ -1,4 +1,5 @@
 mod iterator;
+mod mutable;
 
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
@@ -25,3 +26,637 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn validity_or_default() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice(&[42, 28, 19])) as Arc<dyn Array>;
+    let fields = vec![Field::new("c", DataType::Int32, false)];
+
+    let array = StructArray::from_data(DataType::Struct(fields), vec![int], None);
+    assert_eq!(
+        array.validity_or_default(),
+        Bitmap::from([true, true, true])
+    );
+
+    let array = array.ensure_validity();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, true, true])));
+    assert_eq!(array.null_count(), 0);
+
+    let validity = Bitmap::from([true, false, true]);
+    let array = array.with_validity(Some(validity.clone()));
+    assert_eq!(array.validity_or_default(), validity);
+    assert_eq!(array.ensure_validity().validity(), Some(&validity));
+}
+
+#[test]
+fn null_count() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice([42, 28, 19, 31])) as Arc<dyn Array>;
+    let fields = vec![Field::new("c", DataType::Int32, false)];
+
+    let array = StructArray::from_data(DataType::Struct(fields), vec![int], None);
+    assert_eq!(array.null_count(), 0);
+
+    // the null count is computed when the validity is created and updated when it is sliced
+    let array = array.with_validity(Some(Bitmap::from([false, true, false, false])));
+    assert_eq!(array.null_count(), 3);
+    assert_eq!(array.slice(1, 2).null_count(), 1);
+    assert_eq!(array.slice(1, 1).null_count(), 0);
+    assert_eq!(array.slice(2, 2).null_count(), 2);
+    // the original array is untouched
+    assert_eq!(array.null_count(), 3);
+}
+
+#[test]
+fn slice() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice([42, 28, 19, 31])) as Arc<dyn Array>;
+    let fields = vec![Field::new("c", DataType::Int32, false)];
+    let array = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![int],
+        Some(Bitmap::from([true, true, false, true])),
+    );
+
+    // slicing up to the end is valid
+    let sliced = array.slice(2, 2);
+    let expected = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(Int32Array::from_slice([19, 31]))],
+        Some(Bitmap::from([false, true])),
+    );
+    assert_eq!(sliced, expected);
+    assert_eq!(array.slice(4, 0).len(), 0);
+
+    // the whole array shares its children
+    let sliced = array.slice(0, 4);
+    assert_eq!(sliced, array);
+    assert!(Arc::ptr_eq(&sliced.values()[0], &array.values()[0]));
+}
+
+#[test]
+#[should_panic(expected = "offset + length may not exceed length of array")]
+fn slice_out_of_bounds() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice([42, 28])) as Arc<dyn Array>;
+    let fields = vec![Field::new("c", DataType::Int32, false)];
+    let array = StructArray::from_data(DataType::Struct(fields), vec![int], None);
+    let _ = array.slice(1, 2);
+}
+
+#[test]
+fn slice_keeps_metadata() {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    let metadata = BTreeMap::from([("unit".to_string(), "meters".to_string())]);
+    let extension = DataType::Extension("length".to_string(), Box::new(DataType::Int32), None);
+    let fields = vec![
+        Field::new("a", DataType::Int32, true).with_metadata(metadata.clone()),
+        Field::new("b", extension.clone(), true),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2, 3])),
+            Arc::new(Int32Array::from_slice([4, 5, 6]).to(extension.clone())),
+        ],
+        None,
+    );
+
+    let sliced = array.slice(1, 2);
+    assert_eq!(sliced.data_type(), &DataType::Struct(fields));
+    assert_eq!(sliced.fields()[0].metadata, metadata);
+    assert_eq!(sliced.values()[1].data_type(), &extension);
+
+    let sliced = unsafe { array.slice_unchecked(1, 1) };
+    assert_eq!(sliced.fields()[0].metadata, metadata);
+}
+
+#[test]
+fn chunk() {
+    use arrow2::chunk::Chunk;
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice([false, true])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from_slice([42, 28])) as Arc<dyn Array>;
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, false),
+    ];
+
+    let chunk = Chunk::new(vec![boolean.clone(), int.clone()]);
+    let array = StructArray::from_chunk(chunk.clone(), fields.clone()).unwrap();
+    assert_eq!(array.fields(), fields.as_slice());
+    assert!(Arc::ptr_eq(&array.values()[0], &boolean));
+    assert!(Arc::ptr_eq(&array.values()[1], &int));
+    assert_eq!(array.validity(), None);
+
+    // misaligned fields and columns
+    assert!(StructArray::from_chunk(chunk.clone(), fields[..1].to_vec()).is_err());
+    let swapped = vec![fields[1].clone(), fields[0].clone()];
+    assert!(StructArray::from_chunk(chunk.clone(), swapped).is_err());
+
+    let result = array.clone().into_chunk().unwrap();
+    assert!(Arc::ptr_eq(&result.arrays()[0], &boolean));
+    assert_eq!(result, chunk);
+
+    // a validity without nulls is dropped
+    let all_valid = array.with_validity(Some(Bitmap::from([true, true])));
+    assert_eq!(all_valid.into_chunk().unwrap(), chunk);
+    // ... but nulls can't be represented
+    let with_nulls = array.with_validity(Some(Bitmap::from([true, false])));
+    assert!(with_nulls.into_chunk().is_err());
+}
+
+#[test]
+fn column_by_name() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice(&[false, true])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from_slice(&[42, 28])) as Arc<dyn Array>;
+    let other = Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>;
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, false),
+        Field::new("c", DataType::Int32, false),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![boolean.clone(), int.clone(), other],
+        None,
+    );
+
+    assert_eq!(array.index_of("b"), Some(0));
+    assert_eq!(array.column_by_name("b"), Some(&boolean));
+    // duplicated names resolve to the first match
+    assert_eq!(array.index_of("c"), Some(1));
+    assert_eq!(array.column_by_name("c"), Some(&int));
+    assert_eq!(array.index_of("d"), None);
+    assert!(array.column_by_name("d").is_none());
+}
+
+#[test]
+fn try_new_strict() {
+    use arrow2::error::ArrowError;
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice(&[42, 28])) as Arc<dyn Array>;
+    let other = Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>;
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ];
+    let array = StructArray::try_new_strict(
+        DataType::Struct(fields.clone()),
+        vec![int.clone(), other.clone()],
+        None,
+    )
+    .unwrap();
+    let expected = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![int.clone(), other.clone()],
+        None,
+    );
+    assert_eq!(array, expected);
+
+    // duplicated names are allowed by `try_new`, but not by `try_new_strict`
+    let fields = vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("a", DataType::Int32, false),
+    ];
+    let data_type = DataType::Struct(fields);
+    let values = vec![int, other];
+    assert!(StructArray::try_new(data_type.clone(), values.clone(), None).is_ok());
+    assert!(matches!(
+        StructArray::try_new_strict(data_type, values.clone(), None),
+        Err(ArrowError::OutOfSpec(_))
+    ));
+
+    // the errors of `try_new` remain
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+    assert!(StructArray::try_new_strict(data_type, values, None).is_err());
+}
+
+#[test]
+fn new_unchecked() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice([42, 28])) as Arc<dyn Array>;
+    let boolean = Arc::new(BooleanArray::from_slice([true, false])) as Arc<dyn Array>;
+
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Boolean, false),
+    ]);
+    let values = vec![int, boolean];
+    let validity = Some(Bitmap::from([true, false]));
+
+    let array =
+        unsafe { StructArray::new_unchecked(data_type.clone(), values.clone(), validity.clone()) };
+    assert_eq!(array, StructArray::new(data_type, values, validity));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "validity length")]
+fn new_unchecked_checked_in_debug() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice([42, 28])) as Arc<dyn Array>;
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+
+    let validity = Some(Bitmap::from([true]));
+    let _ = unsafe { StructArray::new_unchecked(data_type, vec![int], validity) };
+}
+
+#[test]
+fn project() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice(&[false, true])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from_slice(&[42, 28])) as Arc<dyn Array>;
+    let utf8 = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])) as Arc<dyn Array>;
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, false),
+        Field::new("d", DataType::Utf8, false),
+    ];
+    let validity = Some(Bitmap::from([true, false]));
+    let array = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![boolean.clone(), int.clone(), utf8.clone()],
+        validity.clone(),
+    );
+
+    let projected = array.project(&["d", "b"]).unwrap();
+    assert_eq!(projected.fields(), &[fields[2].clone(), fields[0].clone()]);
+    assert_eq!(projected.values(), &[utf8.clone(), boolean.clone()]);
+    assert!(Arc::ptr_eq(&projected.values()[0], &utf8));
+    assert_eq!(projected.validity(), validity.as_ref());
+
+    // the original array is untouched
+    assert_eq!(array.fields(), fields.as_slice());
+    assert_eq!(array.values(), &[boolean, int, utf8]);
+
+    assert!(array.project(&["b", "e"]).is_err());
+}
+
+#[test]
+fn reorder_to() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let utf8 = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])) as Arc<dyn Array>;
+    let int_field = Field::new("int", DataType::Int32, false);
+    let utf8_field = Field::new("utf8", DataType::Utf8, false);
+    let first = StructArray::from_data(
+        DataType::Struct(vec![int_field.clone(), utf8_field.clone()]),
+        vec![int, utf8],
+        None,
+    );
+
+    // a second source with the same fields in a different order
+    let int = Arc::new(Int32Array::from_slice([3])) as Arc<dyn Array>;
+    let utf8 = Arc::new(Utf8Array::<i32>::from_slice(["c"])) as Arc<dyn Array>;
+    let second = StructArray::from_data(
+        DataType::Struct(vec![utf8_field, int_field]),
+        vec![utf8.clone(), int.clone()],
+        None,
+    );
+
+    let reordered = second.reorder_to(&["int", "utf8"]).unwrap();
+    assert_eq!(reordered.data_type(), first.data_type());
+    assert!(Arc::ptr_eq(&reordered.values()[0], &int));
+    assert!(Arc::ptr_eq(&reordered.values()[1], &utf8));
+
+    let union = arrow2::compute::concatenate::concatenate(&[&first, &reordered]).unwrap();
+    let union = union.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        union.values()[0].as_ref(),
+        &Int32Array::from_slice([1, 2, 3]) as &dyn Array
+    );
+    assert_eq!(
+        union.values()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+
+    // names must be a permutation of the fields
+    assert!(second.reorder_to(&["int"]).is_err());
+    assert!(second.reorder_to(&["int", "int"]).is_err());
+    assert!(second.reorder_to(&["int", "other"]).is_err());
+
+    // fields sharing a name are matched by position
+    let other = Arc::new(Int32Array::from_slice([4])) as Arc<dyn Array>;
+    let int_field = Field::new("int", DataType::Int32, false);
+    let utf8_field = Field::new("utf8", DataType::Utf8, false);
+    let duplicated = StructArray::from_data(
+        DataType::Struct(vec![int_field.clone(), utf8_field, int_field]),
+        vec![int.clone(), utf8.clone(), other.clone()],
+        None,
+    );
+    let reordered = duplicated.reorder_to(&["int", "int", "utf8"]).unwrap();
+    assert!(Arc::ptr_eq(&reordered.values()[0], &int));
+    assert!(Arc::ptr_eq(&reordered.values()[1], &other));
+    assert!(Arc::ptr_eq(&reordered.values()[2], &utf8));
+    assert!(duplicated.reorder_to(&["int", "utf8", "utf8"]).is_err());
+}
+
+#[test]
+fn with_field() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice(&[false, true])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from_slice(&[42, 28])) as Arc<dyn Array>;
+
+    let fields = vec![Field::new("b", DataType::Boolean, false)];
+    let validity = Some(Bitmap::from([true, false]));
+    let array = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![boolean.clone()],
+        validity.clone(),
+    );
+
+    let field = Field::new("c", DataType::Int32, false);
+    let extended = array.with_field(field.clone(), int.clone()).unwrap();
+    assert_eq!(extended.fields(), &[fields[0].clone(), field.clone()]);
+    assert_eq!(extended.values(), &[boolean.clone(), int.clone()]);
+    assert_eq!(extended.validity(), validity.as_ref());
+    assert_eq!(array.fields(), fields.as_slice());
+
+    // wrong length or data type
+    let short = Arc::new(Int32Array::from_slice(&[42])) as Arc<dyn Array>;
+    assert!(array.with_field(field.clone(), short).is_err());
+    assert!(array.with_field(field, boolean.clone()).is_err());
+
+    let reduced = extended.without_field("b").unwrap();
+    assert_eq!(reduced.fields(), &[Field::new("c", DataType::Int32, false)]);
+    assert_eq!(reduced.values(), &[int]);
+    assert_eq!(reduced.validity(), validity.as_ref());
+
+    assert!(extended.without_field("d").is_err());
+    // a struct must have at least one field
+    assert!(reduced.without_field("c").is_err());
+}
+
+#[test]
+fn replace_field() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice([false, true])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from_slice([42, 28])) as Arc<dyn Array>;
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, false),
+    ];
+    let validity = Some(Bitmap::from([true, false]));
+    let array = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![boolean.clone(), int],
+        validity.clone(),
+    );
+
+    let other = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let replaced = array.replace_field("c", other.clone()).unwrap();
+    assert_eq!(replaced.fields(), fields.as_slice());
+    assert!(Arc::ptr_eq(&replaced.values()[0], &boolean));
+    assert!(Arc::ptr_eq(&replaced.values()[1], &other));
+    assert_eq!(replaced.validity(), validity.as_ref());
+
+    // absent name
+    assert!(array.replace_field("d", other).is_err());
+    // different data type
+    let utf8 = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])) as Arc<dyn Array>;
+    assert!(array.replace_field("c", utf8).is_err());
+    // different length
+    let int = Arc::new(Int32Array::from_slice([1])) as Arc<dyn Array>;
+    assert!(array.replace_field("c", int).is_err());
+}
+
+#[test]
+fn flatten() {
+    use std::sync::Arc;
+    // {a: int, b: {c: utf8, d: {e: bool}}}
+    let e = Arc::new(BooleanArray::from_slice(&[true, false, true])) as Arc<dyn Array>;
+    let d_fields = vec![Field::new("e", DataType::Boolean, false)];
+    let d = StructArray::from_data(
+        DataType::Struct(d_fields),
+        vec![e],
+        Some(Bitmap::from([true, true, false])),
+    );
+
+    let c = Arc::new(Utf8Array::<i32>::from([Some("x"), None, Some("z")])) as Arc<dyn Array>;
+    let b_fields = vec![
+        Field::new("c", DataType::Utf8, true),
+        Field::new("d", d.data_type().clone(), true),
+    ];
+    let b = StructArray::from_data(
+        DataType::Struct(b_fields),
+        vec![c, Arc::new(d)],
+        Some(Bitmap::from([false, true, true])),
+    );
+
+    let a = Arc::new(Int32Array::from_slice(&[1, 2, 3])) as Arc<dyn Array>;
+    let fields = vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", b.data_type().clone(), false),
+    ];
+    let validity = Some(Bitmap::from([true, true, false]));
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![a.clone(), Arc::new(b)],
+        validity.clone(),
+    );
+
+    let flattened = array.flatten().unwrap();
+    let expected_fields = vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b.c", DataType::Utf8, true),
+        Field::new("b.d.e", DataType::Boolean, true),
+    ];
+    assert_eq!(flattened.fields(), expected_fields.as_slice());
+    assert_eq!(flattened.validity(), validity.as_ref());
+
+    assert_eq!(flattened.values()[0], a);
+    let expected = Utf8Array::<i32>::from([None, None, Some("z")]);
+    assert_eq!(flattened.values()[1].as_ref(), &expected as &dyn Array);
+    let expected = BooleanArray::from([None, Some(false), None]);
+    assert_eq!(flattened.values()[2].as_ref(), &expected as &dyn Array);
+
+    // a struct without nested structs is unchanged
+    assert_eq!(flattened.flatten().unwrap(), flattened);
+}
+
+/// Returns a struct `{a: child}` with the validity `[true, false]`
+fn nullable_parent(child: std::sync::Arc<dyn Array>) -> StructArray {
+    use std::sync::Arc;
+    let fields = vec![Field::new("a", child.data_type().clone(), true)];
+    let a = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![child],
+        Some(Bitmap::from([true, false])),
+    );
+    StructArray::from_data(
+        DataType::Struct(vec![Field::new("b", a.data_type().clone(), true)]),
+        vec![Arc::new(a)],
+        None,
+    )
+}
+
+#[test]
+fn flatten_map() {
+    use std::sync::Arc;
+    let entries = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["x", "y"])) as Arc<dyn Array>,
+            Arc::new(Int32Array::from_slice([1, 2])),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.data_type().clone(), false)),
+        false,
+    );
+    let entries = Arc::new(entries) as Arc<dyn Array>;
+    let map = MapArray::new(
+        data_type.clone(),
+        vec![0, 1, 2].into(),
+        entries.clone(),
+        None,
+    );
+    let array = nullable_parent(Arc::new(map));
+
+    // the null of the parent is kept by the map
+    let flattened = array.flatten().unwrap();
+    let validity = Some(Bitmap::from([true, false]));
+    let expected = MapArray::new(data_type, vec![0, 1, 2].into(), entries, validity);
+    assert_eq!(flattened.values()[0].validity(), expected.validity());
+    // `MapArray` can not be formatted, so it is compared without `assert_eq`
+    assert!(flattened.values()[0].as_ref() == &expected as &dyn Array);
+}
+
+#[test]
+fn flatten_union() {
+    use arrow2::error::ArrowError;
+    use std::sync::Arc;
+    let data_type = DataType::Union(
+        vec![Field::new("a", DataType::Int32, true)],
+        None,
+        UnionMode::Sparse,
+    );
+    let fields = vec![Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>];
+    let union = UnionArray::from_data(data_type, vec![0, 0].into(), fields, None);
+
+    // unions have no validity to carry the nulls of the parent
+    let array = nullable_parent(Arc::new(union.clone()));
+    assert!(matches!(
+        array.flatten(),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+
+    // without nulls, unions are flattened as any other child
+    let array = array.with_validity(None);
+    let child = array.values()[0]
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .with_validity(None);
+    let array = StructArray::from_data(array.data_type().clone(), vec![Arc::new(child)], None);
+    let flattened = array.flatten().unwrap();
+    assert_eq!(flattened.values()[0].as_ref(), &union as &dyn Array);
+}
+
+#[test]
+fn try_from_fields() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice(&[false, true])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from([Some(42), None])) as Arc<dyn Array>;
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, true),
+    ];
+    let array = StructArray::try_from_fields(vec![
+        (fields[0].clone(), boolean.clone()),
+        (fields[1].clone(), int.clone()),
+    ])
+    .unwrap();
+    let expected = StructArray::from_data(DataType::Struct(fields), vec![boolean, int], None);
+    assert_eq!(array, expected);
+    assert_eq!(array.data_type(), expected.data_type());
+
+    assert!(StructArray::try_from_fields(vec![]).is_err());
+
+    let short = Arc::new(Int32Array::from_slice(&[42])) as Arc<dyn Array>;
+    let long = Arc::new(Int32Array::from_slice(&[42, 28])) as Arc<dyn Array>;
+    assert!(StructArray::try_from_fields(vec![
+        (Field::new("a", DataType::Int32, false), short),
+        (Field::new("b", DataType::Int32, false), long.clone()),
+    ])
+    .is_err());
+    assert!(
+        StructArray::try_from_fields(vec![(Field::new("a", DataType::Utf8, false), long)]).is_err()
+    );
+}
+
+#[test]
+fn validity_mut() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice(&[false, false, true, true])) as Arc<dyn Array>;
+    let fields = vec![Field::new("b", DataType::Boolean, false)];
+    let validity = Bitmap::from([true, true, false, true]);
+    let mut array = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![boolean.clone()],
+        Some(validity),
+    );
+
+    // not shared: mutated in place
+    let ptr = array.validity().unwrap().as_slice().0.as_ptr();
+    array.validity_mut(|validity| validity.set(0, false));
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from([false, true, false, true]))
+    );
+    assert_eq!(array.validity().unwrap().as_slice().0.as_ptr(), ptr);
+
+    // shared: copied
+    let clone = array.clone();
+    array.validity_mut(|validity| validity.set(2, true));
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from([false, true, true, true]))
+    );
+    assert_eq!(
+        clone.validity(),
+        Some(&Bitmap::from([false, true, false, true]))
+    );
+
+    // without validity: starts all-valid, and is dropped when it has no nulls
+    let mut array = StructArray::from_data(DataType::Struct(fields), vec![boolean], None);
+    array.validity_mut(|validity| validity.set(1, false));
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from([true, false, true, true]))
+    );
+    array.validity_mut(|validity| validity.set(1, true));
+    assert_eq!(array.validity(), None);
+
+    // sliced: only its own rows are exposed
+    let mut array = clone.slice(1, 2);
+    array.validity_mut(|validity| {
+        assert_eq!(validity.len(), 2);
+        validity.set(0, false)
+    });
+    assert_eq!(array.validity(), Some(&Bitmap::from([false, false])));
+}
+
+#[test]
+#[should_panic]
+fn validity_mut_length() {
+    use std::sync::Arc;
+    let boolean = Arc::new(BooleanArray::from_slice(&[false, true])) as Arc<dyn Array>;
+    let fields = vec![Field::new("b", DataType::Boolean, false)];
+    let mut array = StructArray::from_data(DataType::Struct(fields), vec![boolean], None);
+    array.validity_mut(|validity| validity.push(true));
+}
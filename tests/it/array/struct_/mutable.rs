@@ -0,0 +1,129 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field, Schema};
+
+fn data_type() -> DataType {
+    DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ])
+}
+
+#[test]
+fn push() {
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    let mut array = MutableStructArray::new(data_type(), values);
+
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(1));
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some("a"));
+    array.push_valid();
+    array.push_null();
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(None);
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some("c"));
+    array.push_valid();
+    assert!(array.value::<MutableUtf8Array<i32>>(0).is_none());
+    assert_eq!(array.len(), 3);
+
+    let array: StructArray = array.into();
+    let expected = StructArray::from_data(
+        data_type(),
+        vec![
+            std::sync::Arc::new(Int32Array::from([Some(1), None, None])),
+            std::sync::Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("c")])),
+        ],
+        Some(Bitmap::from([true, false, true])),
+    );
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn try_new() {
+    let values: Vec<Box<dyn MutableArray>> = vec![Box::new(MutablePrimitiveArray::<i32>::new())];
+    assert!(MutableStructArray::try_new(data_type(), values, None).is_err());
+
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i64>::new()),
+    ];
+    assert!(MutableStructArray::try_new(data_type(), values, None).is_err());
+}
+
+#[test]
+#[should_panic]
+fn different_lengths() {
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    let mut array = MutableStructArray::new(data_type(), values);
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(1));
+    array.push_valid();
+    array.as_box();
+}
+
+#[test]
+fn from_schema() {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new(
+            "c",
+            DataType::Struct(vec![Field::new("d", DataType::Boolean, true)]),
+            true,
+        ),
+    ]);
+    let mut array = MutableStructArray::from_schema(&schema, 2).unwrap();
+    assert_eq!(array.data_type(), &DataType::Struct(schema.fields.clone()));
+    assert_eq!(array.len(), 0);
+
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(1));
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some("a"));
+    let inner = array.value::<MutableStructArray>(2).unwrap();
+    inner
+        .value::<MutableBooleanArray>(0)
+        .unwrap()
+        .push(Some(true));
+    inner.push_valid();
+    array.push_valid();
+    array.push_null();
+    assert_eq!(array.len(), 2);
+
+    let array: StructArray = array.into();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false])));
+}
+
+#[test]
+fn from_schema_unsupported() {
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        true,
+    )]);
+    assert!(MutableStructArray::from_schema(&schema, 2).is_err());
+
+    assert!(MutableStructArray::from_schema(&Schema::default(), 2).is_err());
+}
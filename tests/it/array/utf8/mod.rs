@@ -176,6 +176,20 @@ fn debug() {
     assert_eq!(format!("{:?}", array), "Utf8Array[aa, , None]");
 }
 
+#[test]
+fn debug_truncated() {
+    let array = Utf8Array::<i64>::from_iter_values((0..101).map(|_| "a"));
+
+    let expected = format!(
+        "LargeUtf8Array[{}, ... (1 more)]",
+        vec!["a"; 100].join(", ")
+    );
+    assert_eq!(format!("{:?}", array), expected);
+
+    let expected = format!("LargeUtf8Array[{}]", vec!["a"; 101].join(", "));
+    assert_eq!(format!("{:#?}", array), expected);
+}
+
 #[test]
 fn into_mut_1() {
     let offsets = Buffer::from_slice([0, 1]);
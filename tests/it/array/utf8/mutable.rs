@@ -127,6 +127,21 @@ fn test_extend_trusted_len() {
     );
 }
 
+#[test]
+fn reserve() {
+    let mut array = MutableUtf8Array::<i32>::new();
+    array.reserve(3, 10);
+    let offsets = array.offsets().as_ptr();
+    let values = array.values().as_ptr();
+
+    array.extend_trusted_len(vec![Some("hello"), None, Some("world")].into_iter());
+    assert_eq!(array.offsets().as_ptr(), offsets);
+    assert_eq!(array.values().as_ptr(), values);
+
+    let array: Utf8Array<i32> = array.into();
+    assert_eq!(array, Utf8Array::from([Some("hello"), None, Some("world")]));
+}
+
 #[test]
 fn test_extend_values() {
     let mut array = MutableUtf8Array::<i32>::new();
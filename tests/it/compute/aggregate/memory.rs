@@ -1,4 +1,10 @@
-use arrow2::{array::*, compute::aggregate::estimated_bytes_size};
+use arrow2::{
+    array::*,
+    chunk::Chunk,
+    compute::aggregate::{
+        estimated_bytes_for_range, estimated_bytes_for_row_group, estimated_bytes_size,
+    },
+};
 
 #[test]
 fn primitive() {
@@ -17,3 +23,54 @@ fn utf8() {
     let a = Utf8Array::<i32>::from_slice(&["aaa"]);
     assert_eq!(3 + 2 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn utf8_sliced_range() {
+    let a = Utf8Array::<i32>::from_slice(&["a", "bb", "ccc", "dddd"]).slice(1, 3);
+    // "ccc" and "dddd" + 3 offsets; the values before the window are not counted
+    assert_eq!(
+        7 + 3 * std::mem::size_of::<i32>(),
+        estimated_bytes_for_range(&a, 1, 2)
+    );
+    assert_eq!(
+        3 + 2 * std::mem::size_of::<i32>(),
+        estimated_bytes_for_range(&a, 1, 1)
+    );
+}
+
+#[test]
+fn list_range() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4)]),
+    ];
+    let mut a = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(data).unwrap();
+    let a: ListArray<i32> = a.into();
+
+    let offsets = 2 * std::mem::size_of::<i32>();
+    let validity = 1;
+    assert_eq!(
+        3 * std::mem::size_of::<i32>() + offsets + validity,
+        estimated_bytes_for_range(&a, 0, 1)
+    );
+    assert_eq!(
+        std::mem::size_of::<i32>() + offsets + validity,
+        estimated_bytes_for_range(&a, 2, 1)
+    );
+}
+
+#[test]
+fn row_group() {
+    let a = Int32Array::from_slice(&[1, 2, 3]);
+    let b = Utf8Array::<i32>::from_slice(&["a", "bb", "ccc"]);
+    let chunk = Chunk::new(vec![&a as &dyn Array, &b]);
+    assert_eq!(
+        estimated_bytes_for_row_group(&chunk, 1, 2),
+        vec![
+            2 * std::mem::size_of::<i32>(),
+            5 + 3 * std::mem::size_of::<i32>()
+        ]
+    );
+}
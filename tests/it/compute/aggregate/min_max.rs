@@ -1,7 +1,8 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max, max_binary, max_boolean, max_float, max_primitive, max_string, max_with_options, min,
+    min_binary, min_boolean, min_float, min_primitive, min_string, min_with_options, MinMaxOptions,
 };
+use arrow2::scalar::{PrimitiveScalar, Scalar};
 use arrow2::{array::*, datatypes::DataType};
 
 #[test]
@@ -82,8 +83,71 @@ fn min_max_f64_large() {
 #[test]
 fn min_max_f64_nan_only() {
     let a = Float64Array::from(&[None, Some(f64::NAN)]);
-    assert!(min_primitive(&a).unwrap().is_nan());
-    assert!(max_primitive(&a).unwrap().is_nan());
+    assert_eq!(None, min_primitive(&a));
+    assert_eq!(None, max_primitive(&a));
+
+    let options = MinMaxOptions { include_nan: true };
+    assert!(min_float(&a, options).unwrap().is_nan());
+    assert!(max_float(&a, options).unwrap().is_nan());
+}
+
+#[test]
+fn min_max_f64_nan_skipped() {
+    let a = Float64Array::from_slice([1.0, f64::NAN, 3.0]);
+    assert_eq!(Some(1.0), min_primitive(&a));
+    assert_eq!(Some(3.0), max_primitive(&a));
+
+    let options = MinMaxOptions { include_nan: true };
+    assert_eq!(Some(1.0), min_float(&a, options));
+    assert!(max_float(&a, options).unwrap().is_nan());
+}
+
+#[test]
+fn min_max_f32_nan_large() {
+    let mut values = vec![f32::NAN; 40];
+    values[3] = 2.0;
+    values[33] = -1.0;
+    let a = Float32Array::from_vec(values);
+    assert_eq!(Some(-1.0), min_primitive(&a));
+    assert_eq!(Some(2.0), max_primitive(&a));
+}
+
+#[test]
+fn min_max_f64_nan_null_not_included() {
+    // the NaN is in a null slot and must not be considered
+    let a = Float64Array::new(
+        DataType::Float64,
+        vec![1.0, f64::NAN].into(),
+        Some([true, false].into()),
+    );
+    let options = MinMaxOptions { include_nan: true };
+    assert_eq!(Some(1.0), max_float(&a, options));
+}
+
+#[test]
+fn min_max_dyn_nan() {
+    let a = Float64Array::from_slice([1.0, f64::NAN, 3.0]);
+    let expected = PrimitiveScalar::<f64>::new(DataType::Float64, Some(1.0));
+    assert_eq!(min(&a).unwrap().as_ref(), &expected as &dyn Scalar);
+    let expected = PrimitiveScalar::<f64>::new(DataType::Float64, Some(3.0));
+    assert_eq!(max(&a).unwrap().as_ref(), &expected as &dyn Scalar);
+
+    let options = MinMaxOptions { include_nan: true };
+    let result = max_with_options(&a, options).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<f64>>()
+        .unwrap();
+    assert!(result.value().unwrap().is_nan());
+    let expected = PrimitiveScalar::<f64>::new(DataType::Float64, Some(1.0));
+    assert_eq!(
+        min_with_options(&a, options).unwrap().as_ref(),
+        &expected as &dyn Scalar
+    );
+
+    let a = Float64Array::from_slice([f64::NAN]);
+    let expected = PrimitiveScalar::<f64>::new(DataType::Float64, None);
+    assert_eq!(min(&a).unwrap().as_ref(), &expected as &dyn Scalar);
 }
 
 #[test]
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
 use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
 use arrow2::datatypes::*;
@@ -489,6 +491,80 @@ fn consistency() {
     }
 }
 
+#[test]
+fn struct_to_struct() {
+    let array = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+            Arc::new(Utf8Array::<i32>::from(&[Some("x"), Some("y"), None])),
+        ],
+        Some([true, false, true].into()),
+    );
+
+    let to_type = DataType::Struct(vec![
+        Field::new("c", DataType::Int64, true),
+        Field::new("d", DataType::LargeUtf8, true),
+    ]);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let expected = StructArray::from_data(
+        to_type,
+        vec![
+            Arc::new(Int64Array::from(&[Some(1), None, Some(3)])),
+            Arc::new(Utf8Array::<i64>::from(&[Some("x"), Some("y"), None])),
+        ],
+        Some([true, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn nested_struct_to_struct() {
+    let inner = StructArray::from_data(
+        DataType::Struct(vec![Field::new("b", DataType::Int8, true)]),
+        vec![Arc::new(Int8Array::from(&[Some(1), Some(2)]))],
+        None,
+    );
+    let array = StructArray::from_data(
+        DataType::Struct(vec![Field::new("a", inner.data_type().clone(), true)]),
+        vec![Arc::new(inner)],
+        Some([false, true].into()),
+    );
+
+    let inner_type = DataType::Struct(vec![Field::new("b", DataType::Float64, true)]);
+    let to_type = DataType::Struct(vec![Field::new("a", inner_type.clone(), true)]);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let inner = StructArray::from_data(
+        inner_type,
+        vec![Arc::new(Float64Array::from(&[Some(1.0), Some(2.0)]))],
+        None,
+    );
+    let expected =
+        StructArray::from_data(to_type, vec![Arc::new(inner)], Some([false, true].into()));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // a child that can't be cast names its field
+    let to_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    let error = cast(&array, &to_type, CastOptions::default()).unwrap_err();
+    assert!(error.to_string().contains("casting struct field \"a\""));
+
+    // the number of fields must match
+    let to_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Int32, true),
+    ]);
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+}
+
 fn test_primitive_to_primitive<I: NativeType, O: NativeType>(
     lhs: &[I],
     lhs_type: DataType,
@@ -696,6 +772,74 @@ fn list_to_list() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn list_to_list_child_cast() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+    ];
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let to_type = DataType::List(Box::new(Field::new("item", DataType::Float64, true)));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+
+    assert_eq!(result.data_type(), &to_type);
+    assert_eq!(result.offsets(), array.offsets());
+    assert_eq!(result.validity(), array.validity());
+    assert_eq!(
+        result.values().as_ref(),
+        &Float64Array::from(&[Some(1.0), Some(2.0), Some(3.0), Some(4.0), None, Some(6.0)])
+            as &dyn Array
+    );
+}
+
+#[test]
+fn nested_list_to_list() {
+    let data = vec![Some(vec![Some(1i32), None]), Some(vec![Some(3)])];
+    let mut inner = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    inner.try_extend(data).unwrap();
+    let inner: ListArray<i32> = inner.into();
+    let array = ListArray::<i32>::new(
+        DataType::List(Box::new(Field::new(
+            "outer",
+            inner.data_type().clone(),
+            true,
+        ))),
+        vec![0, 1, 2].into(),
+        Arc::new(inner),
+        None,
+    );
+
+    let to_type = DataType::List(Box::new(Field::new(
+        "outer",
+        DataType::List(Box::new(Field::new("item", DataType::Int64, true))),
+        true,
+    )));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &to_type);
+
+    // casting to an unsupported child type names the path of the failing field
+    let to_type = DataType::List(Box::new(Field::new(
+        "outer",
+        DataType::List(Box::new(Field::new(
+            "item",
+            DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+            true,
+        ))),
+        true,
+    )));
+    let error = cast(&array, &to_type, CastOptions::default())
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("\"outer\""));
+    assert!(error.contains("\"item\""));
+}
+
 #[test]
 fn timestamp_with_tz_to_utf8() {
     let tz = "-02:00".to_string();
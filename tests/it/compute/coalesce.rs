@@ -0,0 +1,60 @@
+use arrow2::array::*;
+use arrow2::compute::coalesce::coalesce;
+use arrow2::error::Result;
+
+#[test]
+fn three_arrays() -> Result<()> {
+    let a = Int32Array::from([Some(1), None, None, None, Some(5), None]);
+    let b = Int32Array::from([Some(10), Some(20), None, None, None, Some(60)]);
+    let c = Int32Array::from([None, Some(200), Some(300), None, Some(500), Some(600)]);
+
+    let result = coalesce(&[&a, &b, &c])?;
+    let expected = Int32Array::from([Some(1), Some(20), Some(300), None, Some(5), Some(60)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    let a = Utf8Array::<i32>::from([None, Some("a"), None]);
+    let b = Utf8Array::<i32>::from([Some("b"), Some("c"), None]);
+    let c = Utf8Array::<i32>::from([Some("d"), None, Some("e")]);
+
+    let result = coalesce(&[&a, &b, &c])?;
+    let expected = Utf8Array::<i32>::from([Some("b"), Some("a"), Some("e")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let a = Int32Array::from([Some(1), None, None, Some(4)]).slice(1, 3);
+    let b = Int32Array::from([None, Some(20), None]);
+
+    let result = coalesce(&[&a, &b])?;
+    let expected = Int32Array::from([None, Some(20), Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn no_nulls() -> Result<()> {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from([None, Some(20)]);
+
+    let result = coalesce(&[&a, &b])?;
+    assert_eq!(result.as_ref(), &a as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    assert!(coalesce(&[]).is_err());
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int64Array::from_slice([1, 2]);
+    assert!(coalesce(&[&a, &b]).is_err());
+
+    let b = Int32Array::from_slice([1]);
+    assert!(coalesce(&[&a, &b]).is_err());
+}
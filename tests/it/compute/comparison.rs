@@ -1,8 +1,15 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+
 use arrow2::array::*;
-use arrow2::compute::comparison::{self, boolean::*};
-use arrow2::datatypes::{DataType::*, IntervalUnit};
-use arrow2::datatypes::{IntegerType, TimeUnit};
-use arrow2::scalar::new_scalar;
+use arrow2::bitmap::{Bitmap, MutableBitmap};
+use arrow2::compute::comparison::{self, boolean::*, Inclusivity};
+use arrow2::datatypes::{DataType, DataType::*, IntervalUnit, UnionMode};
+use arrow2::datatypes::{Field, IntegerType, TimeUnit};
+use arrow2::error::ArrowError;
+use arrow2::scalar::{
+    new_scalar, BinaryScalar, BooleanScalar, DictionaryScalar, PrimitiveScalar, Scalar, Utf8Scalar,
+};
 
 #[test]
 fn consistency() {
@@ -37,11 +44,27 @@ fn consistency() {
         LargeUtf8,
         Binary,
         LargeBinary,
+        FixedSizeBinary(2),
         Duration(TimeUnit::Second),
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
         Dictionary(IntegerType::Int32, Box::new(LargeBinary), false),
+        Struct(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", Utf8, true),
+        ]),
+        List(Box::new(Field::new("item", Int32, true))),
+        LargeList(Box::new(Field::new(
+            "item",
+            Struct(vec![Field::new("a", Utf8, true)]),
+            true,
+        ))),
+        Struct(vec![Field::new(
+            "a",
+            List(Box::new(Field::new("item", Utf8, true))),
+            true,
+        )]),
     ];
 
     // array <> array
@@ -53,6 +76,20 @@ fn consistency() {
         if can_lt_eq(&d1) {
             lt_eq(array.as_ref(), array.as_ref());
         }
+        if can_between(&d1) {
+            between(
+                array.as_ref(),
+                array.as_ref(),
+                array.as_ref(),
+                Inclusivity::Both,
+            );
+            not_between(
+                array.as_ref(),
+                array.as_ref(),
+                array.as_ref(),
+                Inclusivity::Left,
+            );
+        }
     });
 
     // array <> scalar
@@ -65,9 +102,1788 @@ fn consistency() {
         if can_lt_eq_scalar(&d1) {
             lt_eq_scalar(array.as_ref(), scalar.as_ref());
         }
+        if can_between_scalar(&d1) {
+            between_scalar(
+                array.as_ref(),
+                scalar.as_ref(),
+                scalar.as_ref(),
+                Inclusivity::Both,
+            );
+            not_between_scalar(
+                array.as_ref(),
+                scalar.as_ref(),
+                scalar.as_ref(),
+                Inclusivity::Neither,
+            );
+        }
     });
 }
 
+#[test]
+fn is_null_any_type() {
+    let array = Utf8Array::<i32>::from([Some(""), None, Some("a")]);
+    let result = comparison::is_null(&array);
+    assert_eq!(result, BooleanArray::from_slice([false, true, false]));
+    let result = comparison::is_not_null(&array);
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+
+    let array = Int32Array::from_slice([1, 2]);
+    let result = comparison::is_null(&array);
+    assert_eq!(result, BooleanArray::from_slice([false, false]));
+}
+
+#[test]
+fn is_null_null_array() {
+    let array = NullArray::new_null(Null, 3);
+    let result = comparison::is_null(&array);
+    assert_eq!(result, BooleanArray::from_slice([true, true, true]));
+    let result = comparison::is_not_null(&array);
+    assert_eq!(result, BooleanArray::from_slice([false, false, false]));
+}
+
+#[test]
+fn null_array() {
+    let nulls = NullArray::new_null(Null, 3);
+    let ints = Int32Array::from([Some(1), None, Some(3)]);
+    let expected = BooleanArray::new_null(DataType::Boolean, 3);
+
+    assert_eq!(comparison::eq(&nulls, &nulls), expected);
+    assert_eq!(comparison::neq(&nulls, &ints), expected);
+    assert_eq!(comparison::lt(&ints, &nulls), expected);
+    assert_eq!(comparison::gt_eq_total(&nulls, &ints), expected);
+
+    let scalar = new_scalar(&ints, 0);
+    assert_eq!(comparison::eq_scalar(&nulls, scalar.as_ref()), expected);
+    let scalar = new_scalar(&nulls, 0);
+    assert_eq!(comparison::lt_scalar(&ints, scalar.as_ref()), expected);
+
+    // including validities, nulls are equal to each other and differ from values
+    assert_eq!(
+        comparison::eq_and_validity(&nulls, &ints),
+        BooleanArray::from_slice([false, true, false])
+    );
+    assert_eq!(
+        comparison::neq_and_validity(&ints, &nulls),
+        BooleanArray::from_slice([true, false, true])
+    );
+    assert_eq!(
+        comparison::eq_null_safe(&ints, &nulls),
+        BooleanArray::from_slice([false, true, false])
+    );
+
+    assert!(comparison::can_eq(&Null));
+    assert!(comparison::can_lt(&Null));
+    assert!(comparison::can_eq_scalar(&Null));
+    assert!(comparison::can_lt_scalar(&Null));
+
+    // nested nulls are compared as any other child
+    let data_type = Struct(vec![Field::new("a", Null, true)]);
+    let array = new_null_array(data_type.clone(), 2);
+    assert!(comparison::can_eq(&data_type));
+    assert_eq!(
+        comparison::eq(array.as_ref(), array.as_ref()),
+        BooleanArray::new_null(DataType::Boolean, 2)
+    );
+    let data_type = List(Box::new(Field::new("item", Null, true)));
+    let array = ListArray::<i32>::from_data(
+        data_type.clone(),
+        vec![0, 1, 3].into(),
+        Arc::new(NullArray::new_null(Null, 3)),
+        None,
+    );
+    assert!(comparison::can_eq(&data_type));
+    assert_eq!(
+        comparison::eq(&array, &array),
+        BooleanArray::new_null(DataType::Boolean, 2)
+    );
+}
+
+#[test]
+fn eq_identical_arrays() {
+    let a = Int32Array::from([Some(1), None, Some(3), Some(4)]).slice(1, 3);
+    let b = a.clone();
+    let result = comparison::eq(&a, &b);
+    assert_eq!(result, BooleanArray::from([None, Some(true), Some(true)]));
+    // the validity is re-used from the input, i.e. no values were compared
+    let (validity, offset, _) = result.validity().unwrap().as_slice();
+    let (expected, expected_offset, _) = a.validity().unwrap().as_slice();
+    assert_eq!(offset, expected_offset);
+    assert!(std::ptr::eq(validity.as_ptr(), expected.as_ptr()));
+
+    // different regions of the same buffer are compared by value
+    let a = Int32Array::from_slice([1, 1, 2]);
+    let result = comparison::eq(&a.slice(0, 2), &a.slice(1, 2));
+    assert_eq!(result, BooleanArray::from_slice([true, false]));
+
+    // NaN != NaN
+    let a = Float32Array::from_slice([f32::NAN, 1.0]);
+    let result = comparison::eq(&a, &a.clone());
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+}
+
+#[test]
+fn edges() {
+    let a = Int32Array::from_slice([1, 1, 2, 2, 2, 3]);
+    assert_eq!(comparison::edges(&a), UInt32Array::from_slice([2, 5]));
+
+    let a = Int32Array::from([Some(1), None, None, Some(1), Some(1)]);
+    assert_eq!(comparison::edges(&a), UInt32Array::from_slice([1, 3]));
+
+    // sliced
+    let a = Utf8Array::<i32>::from_slice(["a", "b", "b", "c"]).slice(1, 3);
+    assert_eq!(comparison::edges(&a), UInt32Array::from_slice([2]));
+
+    let a = Int32Array::from_slice([1]);
+    assert_eq!(comparison::edges(&a), UInt32Array::from_slice([]));
+
+    let a = NullArray::new(Null, 3);
+    assert_eq!(comparison::edges(&a), UInt32Array::from_slice([]));
+}
+
+fn struct_array(
+    a: Vec<Option<i32>>,
+    b: Vec<Option<&str>>,
+    validity: Option<Bitmap>,
+) -> StructArray {
+    let fields = vec![Field::new("a", Int32, true), Field::new("b", Utf8, true)];
+    StructArray::new(
+        Struct(fields),
+        vec![
+            Arc::new(Int32Array::from(a)),
+            Arc::new(Utf8Array::<i32>::from(b)),
+        ],
+        validity,
+    )
+}
+
+#[test]
+fn struct_eq() {
+    let lhs = struct_array(
+        vec![Some(1), Some(2), Some(3), Some(4)],
+        vec![Some("a"), Some("b"), Some("c"), Some("d")],
+        Some([true, true, true, false].into()),
+    );
+    let rhs = struct_array(
+        vec![Some(1), Some(2), Some(0), Some(4)],
+        vec![Some("a"), Some("c"), Some("c"), Some("d")],
+        None,
+    );
+
+    let result = comparison::eq(&lhs, &rhs);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), Some(false), None])
+    );
+
+    let result = comparison::neq(&lhs, &rhs);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(true), Some(true), None])
+    );
+}
+
+#[test]
+fn struct_eq_null_fields() {
+    let lhs = struct_array(vec![Some(1), None], vec![Some("a"), Some("b")], None);
+    let rhs = struct_array(vec![Some(1), Some(2)], vec![Some("a"), Some("b")], None);
+
+    let result = comparison::eq(&lhs, &rhs);
+    assert_eq!(result, BooleanArray::from([Some(true), None]));
+
+    let result = comparison::eq_and_validity(&lhs, &rhs);
+    assert_eq!(result, BooleanArray::from_slice([true, false]));
+
+    let result = comparison::neq_and_validity(&lhs, &rhs);
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+}
+
+#[test]
+fn struct_eq_fields() {
+    let lhs = struct_array(
+        vec![Some(1), Some(2), Some(3), None],
+        vec![Some("a"), Some("b"), Some("c"), Some("d")],
+        Some([true, true, true, false].into()),
+    );
+    let rhs = struct_array(
+        vec![Some(1), Some(2), Some(0), Some(4)],
+        vec![Some("a"), Some("c"), Some("c"), Some("d")],
+        None,
+    );
+
+    let result = comparison::eq_fields(&lhs, &rhs);
+    let expected = vec![
+        (
+            "a".to_string(),
+            BooleanArray::from([Some(true), Some(true), Some(false), None]),
+        ),
+        (
+            "b".to_string(),
+            BooleanArray::from([Some(true), Some(false), Some(true), Some(true)]),
+        ),
+        (
+            comparison::struct_::VALIDITY_FIELD_NAME.to_string(),
+            BooleanArray::from_slice([true, true, true, false]),
+        ),
+    ];
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_eq_struct() {
+    let data_type = Struct(vec![Field::new("a", Int32, true)]);
+    assert!(comparison::can_eq(&data_type));
+    assert!(comparison::can_neq(&data_type));
+    assert!(!comparison::can_lt(&data_type));
+
+    let data_type = Struct(vec![Field::new(
+        "a",
+        Struct(vec![Field::new(
+            "b",
+            Union(vec![Field::new("a", Int32, true)], None, UnionMode::Dense),
+            true,
+        )]),
+        true,
+    )]);
+    assert!(!comparison::can_eq(&data_type));
+}
+
+fn int_list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn list_eq() {
+    let lhs = int_list(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![]),
+    ]);
+    let rhs = int_list(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), Some(3)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        Some(vec![]),
+    ]);
+
+    let expected = BooleanArray::from([Some(true), Some(false), Some(false), None, Some(true)]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from([Some(false), Some(true), Some(true), None, Some(false)]);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([true, false, false, false, true]);
+    assert_eq!(comparison::eq_and_validity(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([false, true, true, true, false]);
+    assert_eq!(comparison::neq_and_validity(&lhs, &rhs), expected);
+}
+
+#[test]
+fn list_eq_null_elements() {
+    let lhs = int_list(vec![
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1), None]),
+        Some(vec![None]),
+    ]);
+    let rhs = int_list(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(2), Some(2)]),
+        Some(vec![None]),
+    ]);
+
+    // a null element makes the slot null unless other elements are different
+    let expected = BooleanArray::from([None, Some(false), None]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([false, false, true]);
+    assert_eq!(comparison::eq_and_validity(&lhs, &rhs), expected);
+}
+
+fn map_array(
+    offsets: Vec<i32>,
+    keys: &[&str],
+    values: &[Option<i32>],
+    validity: Option<Bitmap>,
+) -> MapArray {
+    let fields = vec![
+        Field::new("key", Utf8, false),
+        Field::new("value", Int32, true),
+    ];
+    let entries = StructArray::from_data(
+        Struct(fields),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(keys)),
+            Arc::new(Int32Array::from(values)),
+        ],
+        None,
+    );
+    let field = Field::new("entries", entries.data_type().clone(), false);
+    MapArray::from_data(
+        Map(Box::new(field), false),
+        offsets.into(),
+        Arc::new(entries),
+        validity,
+    )
+}
+
+#[test]
+fn map_eq() {
+    // [{a: 1, b: 2}, {a: 1}, {a: null}, {b: 2, a: 1}, null, {}]
+    let lhs = map_array(
+        vec![0, 2, 3, 4, 6, 6, 6],
+        &["a", "b", "a", "a", "b", "a"],
+        &[Some(1), Some(2), Some(1), None, Some(2), Some(1)],
+        Some(Bitmap::from([true, true, true, true, false, true])),
+    );
+    // [{a: 1, b: 2}, {a: 2}, {a: 1}, {a: 1, b: 2}, {a: 1}, {}]
+    let rhs = map_array(
+        vec![0, 2, 3, 4, 6, 7, 7],
+        &["a", "b", "a", "a", "a", "b", "a"],
+        &[
+            Some(1),
+            Some(2),
+            Some(2),
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(1),
+        ],
+        None,
+    );
+    assert!(comparison::can_eq(lhs.data_type()));
+
+    // entries are compared in order
+    let expected =
+        BooleanArray::from([Some(true), Some(false), None, Some(false), None, Some(true)]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let expected =
+        BooleanArray::from([Some(false), Some(true), None, Some(true), None, Some(false)]);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([true, false, false, false, false, true]);
+    assert_eq!(comparison::eq_and_validity(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([false, true, true, true, true, false]);
+    assert_eq!(comparison::neq_and_validity(&lhs, &rhs), expected);
+
+    // sliced
+    let expected = BooleanArray::from([Some(false), None]);
+    assert_eq!(comparison::eq(&lhs.slice(1, 2), &rhs.slice(1, 2)), expected);
+}
+
+#[test]
+fn large_list_utf8_eq() {
+    let data = vec![
+        Some(vec![Some("a"), Some("bc")]),
+        Some(vec![Some("a")]),
+        Some(vec![Some("d")]),
+    ];
+    let mut lhs = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    lhs.try_extend(data).unwrap();
+    let lhs: ListArray<i64> = lhs.into();
+
+    let data = vec![
+        Some(vec![Some("a"), Some("bc")]),
+        Some(vec![Some("b")]),
+        Some(vec![Some("d")]),
+    ];
+    let mut rhs = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    rhs.try_extend(data).unwrap();
+    let rhs: ListArray<i64> = rhs.into();
+
+    // sliced arrays compare the elements of their own window
+    let expected = BooleanArray::from_slice([false, true]);
+    assert_eq!(comparison::eq(&lhs.slice(1, 2), &rhs.slice(1, 2)), expected);
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+}
+
+#[test]
+fn can_eq_list() {
+    let data_type = List(Box::new(Field::new("item", Int32, true)));
+    assert!(comparison::can_eq(&data_type));
+    assert!(comparison::can_neq(&data_type));
+    assert!(!comparison::can_lt(&data_type));
+
+    let data_type = LargeList(Box::new(Field::new(
+        "item",
+        Union(vec![Field::new("a", Int32, true)], None, UnionMode::Dense),
+        true,
+    )));
+    assert!(!comparison::can_eq(&data_type));
+}
+
+#[test]
+fn can_eq_nested() {
+    let list = |inner: DataType| List(Box::new(Field::new("item", inner, true)));
+    let struct_ = |inner: DataType| Struct(vec![Field::new("a", inner, true)]);
+
+    // comparable iff all the (nested) children are
+    let data_type = list(struct_(list(Utf8)));
+    assert!(comparison::can_eq(&data_type));
+    assert!(!comparison::can_lt(&data_type));
+    let data_type = struct_(list(struct_(Int32)));
+    assert!(comparison::can_eq(&data_type));
+    assert!(!comparison::can_lt(&data_type));
+
+    let unsupported = || Union(vec![Field::new("a", Int32, true)], None, UnionMode::Dense);
+    assert!(!comparison::can_eq(&list(struct_(list(unsupported())))));
+    assert!(!comparison::can_eq(&struct_(list(struct_(unsupported())))));
+}
+
+fn vectors(values: &[[Option<f32>; 3]], validity: Option<Bitmap>) -> FixedSizeListArray {
+    let data_type = FixedSizeList(Box::new(Field::new("item", Float32, true)), 3);
+    let values = Float32Array::from(values.concat());
+    FixedSizeListArray::new(data_type, Arc::new(values), validity)
+}
+
+#[test]
+fn fixed_size_list_eq() {
+    let lhs = vectors(
+        &[
+            [Some(1.0), Some(2.0), Some(3.0)],
+            [Some(1.0), Some(2.0), Some(3.0)],
+            [Some(1.0), None, Some(3.0)],
+            [Some(1.0), None, Some(3.0)],
+            [Some(1.0), Some(2.0), Some(3.0)],
+        ],
+        Some(Bitmap::from([true, true, true, true, false])),
+    );
+    let rhs = vectors(
+        &[
+            [Some(1.0), Some(2.0), Some(3.0)],
+            [Some(1.0), Some(2.0), Some(4.0)],
+            [Some(1.0), Some(2.0), Some(3.0)],
+            [Some(0.0), Some(2.0), Some(3.0)],
+            [Some(1.0), Some(2.0), Some(3.0)],
+        ],
+        None,
+    );
+
+    // a null element is null unless another element differs; a null vector is null
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false), None]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(true), None]);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([true, false, false, false, false]);
+    assert_eq!(comparison::eq_and_validity(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([false, true, true, true, true]);
+    assert_eq!(comparison::neq_and_validity(&lhs, &rhs), expected);
+
+    // null elements are equal to each other when including validities
+    assert_eq!(
+        comparison::eq_and_validity(&lhs.slice(2, 2), &lhs.slice(2, 2)),
+        BooleanArray::from_slice([true, true])
+    );
+
+    // sliced arrays are compared by their own vectors
+    let expected = BooleanArray::from([Some(false), None]);
+    assert_eq!(comparison::eq(&lhs.slice(1, 2), &rhs.slice(1, 2)), expected);
+    assert_eq!(
+        comparison::fixed_size_list::eq(&lhs.slice(0, 1), &rhs.slice(4, 1)),
+        BooleanArray::from_slice([true])
+    );
+}
+
+#[test]
+fn can_eq_fixed_size_list() {
+    let data_type = FixedSizeList(Box::new(Field::new("item", Float32, true)), 3);
+    assert!(comparison::can_eq(&data_type));
+    assert!(comparison::can_neq(&data_type));
+    assert!(!comparison::can_lt(&data_type));
+    assert!(comparison::requires_materialization(&data_type));
+
+    let data_type = FixedSizeList(
+        Box::new(Field::new(
+            "item",
+            Union(vec![Field::new("a", Int32, true)], None, UnionMode::Dense),
+            true,
+        )),
+        3,
+    );
+    assert!(!comparison::can_eq(&data_type));
+}
+
+#[test]
+fn interval_lt() {
+    use arrow2::types::months_days_ns;
+    let a = MonthsDaysNsArray::from_slice([months_days_ns::new(1, 0, 0)]);
+    let b = MonthsDaysNsArray::from_slice([months_days_ns::new(0, 40, 0)]);
+    assert!(comparison::can_lt(a.data_type()));
+    assert_eq!(comparison::lt(&a, &b), BooleanArray::from_slice([false]));
+}
+
+#[test]
+fn interval_eq() {
+    use arrow2::types::{days_ms, months_days_ns};
+    // intervals are compared field by field: equal durations with different fields differ
+    let a = MonthsDaysNsArray::from_slice([
+        months_days_ns::new(1, 0, 0),
+        months_days_ns::new(0, 1, 0),
+        months_days_ns::new(1, 2, 3),
+    ]);
+    let b = MonthsDaysNsArray::from_slice([
+        months_days_ns::new(0, 30, 0),
+        months_days_ns::new(0, 0, 86_400_000_000_000),
+        months_days_ns::new(1, 2, 3),
+    ]);
+    assert!(comparison::can_eq(a.data_type()));
+    let expected = BooleanArray::from_slice([false, false, true]);
+    assert_eq!(comparison::eq(&a, &b), expected);
+    assert_eq!(
+        comparison::neq(&a, &b),
+        arrow2::compute::boolean::not(&expected)
+    );
+
+    let a = DaysMsArray::from_slice([days_ms::new(1, 0), days_ms::new(1, 2)]);
+    let b = DaysMsArray::from_slice([days_ms::new(0, 86_400_000), days_ms::new(1, 2)]);
+    assert_eq!(
+        comparison::eq(&a, &b),
+        BooleanArray::from_slice([false, true])
+    );
+}
+
+#[test]
+fn decimal_different_scales() {
+    // [1.0, 1.5, 2.0, null]
+    let lhs = Int128Array::from([Some(10), Some(15), Some(20), None]).to(Decimal(5, 1));
+    // [1.00, 1.49, 2.01, 1.00]
+    let rhs = Int128Array::from([Some(100), Some(149), Some(201), Some(100)]).to(Decimal(5, 2));
+
+    let expected = BooleanArray::from([Some(true), Some(false), Some(false), None]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), None]);
+    assert_eq!(comparison::lt(&lhs, &rhs), expected);
+    // irrespectively of the order of the arguments
+    assert_eq!(comparison::gt(&rhs, &lhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), Some(true), None]);
+    assert_eq!(comparison::neq(&rhs, &lhs), expected);
+}
+
+#[test]
+fn decimal_different_scales_overflow() {
+    // rescaling by 10^2 overflows `i128` for the first two values
+    let max = 10i128.pow(38) - 1;
+    let lhs = Int128Array::from_slice([max, -max, 1]).to(Decimal(38, 0));
+    let rhs = Int128Array::from_slice([max, max, 100]).to(Decimal(38, 2));
+
+    let expected = BooleanArray::from_slice([false, false, true]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([true, false, false]);
+    assert_eq!(comparison::gt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([false, true, false]);
+    assert_eq!(comparison::lt(&lhs, &rhs), expected);
+
+    // rescaling by 10^38 overflows `i128` for any value other than 0 and ±1
+    let lhs = Int128Array::from_slice([1, -1, 0]).to(Decimal(38, 0));
+    let rhs = Int128Array::from_slice([max, max, 0]).to(Decimal(38, 38));
+    let expected = BooleanArray::from_slice([true, false, false]);
+    assert_eq!(comparison::gt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([false, false, true]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+}
+
+#[test]
+fn decimal_extremes() {
+    // the largest and smallest values of the largest precision
+    let max = 10i128.pow(38) - 1;
+    let lhs = Int128Array::from_slice([max, -max, max, -max, 0]).to(Decimal(38, 10));
+    let rhs = Int128Array::from_slice([max, -max, -max, max, max]).to(Decimal(38, 10));
+
+    let expected = BooleanArray::from_slice([true, true, false, false, false]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([false, false, false, true, true]);
+    assert_eq!(comparison::lt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([true, true, true, false, false]);
+    assert_eq!(comparison::gt_eq(&lhs, &rhs), expected);
+
+    let scalar = PrimitiveScalar::<i128>::new(Decimal(38, 10), Some(-max));
+    let expected = BooleanArray::from_slice([true, false, true, false, true]);
+    assert_eq!(comparison::gt_scalar(&lhs, &scalar), expected);
+}
+
+#[test]
+fn eq_utf8_any() {
+    let utf8 = Utf8Array::<i32>::from(&[Some("a"), Some("bb"), None, Some("c")]);
+    let large = Utf8Array::<i64>::from(&[Some("a"), Some("b"), Some("c"), Some("c")]);
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+
+    assert_eq!(comparison::eq_utf8_any(&utf8, &large), expected);
+    assert_eq!(comparison::eq_utf8_any(&large, &utf8), expected);
+    assert_eq!(
+        comparison::eq_utf8_any(&utf8, &utf8),
+        comparison::eq(&utf8, &utf8)
+    );
+    assert_eq!(
+        comparison::eq_utf8_any(&large, &large),
+        comparison::eq(&large, &large)
+    );
+
+    // sliced arrays use their own offsets
+    let expected = BooleanArray::from(&[Some(false), None, Some(true)]);
+    assert_eq!(
+        comparison::eq_utf8_any(&utf8.slice(1, 3), &large.slice(1, 3)),
+        expected
+    );
+}
+
+#[test]
+#[should_panic]
+fn eq_utf8_any_other_type() {
+    let utf8 = Utf8Array::<i32>::from_slice(["a"]);
+    let binary = BinaryArray::<i32>::from_slice([b"a"]);
+    comparison::eq_utf8_any(&utf8, &binary);
+}
+
+#[test]
+fn between() {
+    let array = Int32Array::from([Some(1), Some(2), None, Some(4), Some(5)]);
+    let low = Int32Array::from([Some(1), Some(3), Some(0), None, Some(0)]);
+    let high = Int32Array::from([Some(1), Some(4), Some(9), Some(9), Some(4)]);
+    let expected = BooleanArray::from([Some(true), Some(false), None, None, Some(false)]);
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Both),
+        expected
+    );
+
+    let low = new_scalar(&Int32Array::from_slice([2]), 0);
+    let high = new_scalar(&Int32Array::from_slice([4]), 0);
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(true), Some(false)]);
+    assert_eq!(
+        comparison::between_scalar(&array, low.as_ref(), high.as_ref(), Inclusivity::Both),
+        expected
+    );
+    let null = new_scalar(&Int32Array::from([None]), 0);
+    assert_eq!(
+        comparison::between_scalar(&array, low.as_ref(), null.as_ref(), Inclusivity::Both),
+        BooleanArray::new_null(DataType::Boolean, 5)
+    );
+
+    let array = Utf8Array::<i64>::from_slice(["a", "b", "c"]);
+    let low = Utf8Array::<i64>::from_slice(["a", "c", "a"]);
+    let high = Utf8Array::<i64>::from_slice(["b", "d", "b"]);
+    let expected = BooleanArray::from_slice([true, false, false]);
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Both),
+        expected
+    );
+
+    let array = BooleanArray::from_slice([false, true, false, true]);
+    let low = BooleanArray::from_slice([false, false, true, true]);
+    let high = BooleanArray::from_slice([true, true, true, false]);
+    let expected = BooleanArray::from_slice([true, true, false, false]);
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Both),
+        expected
+    );
+    let low = new_scalar(&BooleanArray::from_slice([true]), 0);
+    let high = new_scalar(&BooleanArray::from_slice([true]), 0);
+    let expected = BooleanArray::from_slice([false, true, false, true]);
+    assert_eq!(
+        comparison::between_scalar(&array, low.as_ref(), high.as_ref(), Inclusivity::Both),
+        expected
+    );
+
+    assert!(comparison::can_between(&Utf8));
+    assert!(!comparison::can_between(&List(Box::new(Field::new(
+        "item", Int32, true
+    )))));
+}
+
+#[test]
+fn between_dictionary() {
+    let dictionary = |data: &[Option<&str>]| {
+        let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        array.try_extend(data.iter().copied()).unwrap();
+        DictionaryArray::<i32>::from(array)
+    };
+    let array = dictionary(&[Some("b"), Some("a"), None, Some("d"), Some("b")]);
+    let low = dictionary(&[Some("a"), Some("b"), Some("a"), Some("a"), Some("c")]);
+    let high = dictionary(&[Some("b"), Some("c"), Some("c"), Some("c"), None]);
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false), None]);
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Both),
+        expected
+    );
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Left),
+        BooleanArray::from([Some(false), Some(false), None, Some(false), None])
+    );
+
+    let low = new_scalar(&dictionary(&[Some("b")]), 0);
+    let high = new_scalar(&dictionary(&[Some("c")]), 0);
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false), Some(true)]);
+    assert_eq!(
+        comparison::between_scalar(&array, low.as_ref(), high.as_ref(), Inclusivity::Both),
+        expected
+    );
+    assert!(comparison::can_between(array.data_type()));
+    assert!(comparison::can_between_scalar(array.data_type()));
+}
+
+#[test]
+fn between_decimal_scales() {
+    // 1.00, 1.50 and 2.50
+    let array = Int128Array::from_slice([100, 150, 250]).to(Decimal(5, 2));
+    // 1.0, 1.6 and 2.0
+    let low = Int128Array::from_slice([10, 16, 20]).to(Decimal(5, 1));
+    // 1.000, 2.000 and 3.000
+    let high = Int128Array::from_slice([1000, 2000, 3000]).to(Decimal(5, 3));
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Both),
+        expected
+    );
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Neither),
+        BooleanArray::from_slice([false, false, true])
+    );
+
+    // scalars are not rescaled, as by `gt_eq_scalar`
+    let low_scalar = new_scalar(&low, 0);
+    let high_scalar = new_scalar(&high, 0);
+    assert!(matches!(
+        comparison::try_between_scalar(
+            &array,
+            low_scalar.as_ref(),
+            high_scalar.as_ref(),
+            Inclusivity::Both
+        ),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    // both `array` and `low` overflow when rescaled to the scale of `high`
+    let array = Int128Array::from_slice([-(10i128.pow(37))]).to(Decimal(38, 0));
+    let low = Int128Array::from_slice([-(10i128.pow(38) - 1)]).to(Decimal(38, 1));
+    let high = Int128Array::from_slice([0]).to(Decimal(38, 3));
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Both),
+        BooleanArray::from_slice([false])
+    );
+}
+
+#[test]
+fn try_between() {
+    let array = Int32Array::from_slice([1, 2]);
+    let low = Int32Array::from_slice([1]);
+    assert!(matches!(
+        comparison::try_between(&array, &low, &array, Inclusivity::Both),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    let low = Int64Array::from_slice([1, 2]);
+    assert!(matches!(
+        comparison::try_between(&array, &low, &array, Inclusivity::Left),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let data_type = List(Box::new(Field::new("item", Int32, true)));
+    let array = new_null_array(data_type, 2);
+    assert!(matches!(
+        comparison::try_between(
+            array.as_ref(),
+            array.as_ref(),
+            array.as_ref(),
+            Inclusivity::Both
+        ),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    let scalar = new_scalar(array.as_ref(), 0);
+    assert!(matches!(
+        comparison::try_between_scalar(
+            array.as_ref(),
+            scalar.as_ref(),
+            scalar.as_ref(),
+            Inclusivity::Both
+        ),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+}
+
+#[test]
+fn between_is_gt_eq_and_lt_eq() {
+    let array = Float64Array::from_iter((0..19).map(|x| Some(x as f64 / 2.0)));
+    let low = Float64Array::from_iter((0..19).map(|x| Some((x % 5) as f64)));
+    let high = Float64Array::from_iter((0..19).map(|x| Some((x % 7) as f64)));
+
+    let expected = arrow2::compute::boolean::and(
+        &comparison::gt_eq(&array, &low),
+        &comparison::lt_eq(&array, &high),
+    )
+    .unwrap();
+    assert_eq!(
+        comparison::between(&array, &low, &high, Inclusivity::Both),
+        expected
+    );
+}
+
+#[test]
+fn between_buffer_boundary() {
+    // the result ends in an incomplete byte that fills the stack buffer of the kernels
+    let length = 511;
+    let array = Int32Array::from_vec((0..length).collect());
+    let low = Int32Array::from_vec((0..length).map(|x| x - x % 3).collect());
+    let high = Int32Array::from_vec((0..length).map(|x| x - x % 2).collect());
+
+    let expected = BooleanArray::from_slice((0..length).map(|x| x % 2 == 0).collect::<Vec<_>>());
+    assert_eq!(
+        comparison::primitive::between(&array, &low, &high),
+        expected
+    );
+
+    let expected = BooleanArray::from_slice(
+        (0..length)
+            .map(|x| (10..=500).contains(&x))
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(
+        comparison::primitive::between_scalar(&array, 10, 500),
+        expected
+    );
+}
+
+#[test]
+fn between_inclusivity() {
+    let array = Int32Array::from([Some(1), Some(2), Some(3), Some(4), Some(5), None]);
+    let low = Int32Array::from([Some(2); 6].to_vec());
+    let high = Int32Array::from([Some(4), Some(4), Some(4), Some(4), Some(4), Some(4)]);
+    let low_scalar = new_scalar(&low, 0);
+    let high_scalar = new_scalar(&high, 0);
+
+    let cases = [
+        (Inclusivity::Both, [false, true, true, true, false]),
+        (Inclusivity::Left, [false, true, true, false, false]),
+        (Inclusivity::Right, [false, false, true, true, false]),
+        (Inclusivity::Neither, [false, false, true, false, false]),
+    ];
+    for (inclusivity, values) in cases {
+        let expected = BooleanArray::from_iter(values.iter().map(|x| Some(*x)).chain([None]));
+        let result = comparison::between(&array, &low, &high, inclusivity);
+        assert_eq!(result, expected, "{:?}", inclusivity);
+        let result = comparison::between_scalar(
+            &array,
+            low_scalar.as_ref(),
+            high_scalar.as_ref(),
+            inclusivity,
+        );
+        assert_eq!(result, expected, "{:?}", inclusivity);
+
+        // the negation keeps the nulls
+        let expected = BooleanArray::from_iter(values.iter().map(|x| Some(!*x)).chain([None]));
+        let result = comparison::not_between(&array, &low, &high, inclusivity);
+        assert_eq!(result, expected, "{:?}", inclusivity);
+        let result = comparison::not_between_scalar(
+            &array,
+            low_scalar.as_ref(),
+            high_scalar.as_ref(),
+            inclusivity,
+        );
+        assert_eq!(result, expected, "{:?}", inclusivity);
+    }
+
+    assert_eq!(
+        comparison::between_scalar_left_open(&array, low_scalar.as_ref(), high_scalar.as_ref()),
+        comparison::between_scalar(
+            &array,
+            low_scalar.as_ref(),
+            high_scalar.as_ref(),
+            Inclusivity::Right
+        )
+    );
+    assert_eq!(
+        comparison::between_scalar_right_open(&array, low_scalar.as_ref(), high_scalar.as_ref()),
+        comparison::between_scalar(
+            &array,
+            low_scalar.as_ref(),
+            high_scalar.as_ref(),
+            Inclusivity::Left
+        )
+    );
+
+    // a null bound results in nulls
+    let null = new_scalar(&Int32Array::from([None]), 0);
+    let result = comparison::not_between_scalar(
+        &array,
+        null.as_ref(),
+        high_scalar.as_ref(),
+        Inclusivity::Left,
+    );
+    assert_eq!(result, BooleanArray::new_null(DataType::Boolean, 6));
+
+    assert!(comparison::can_not_between(&Utf8));
+    assert!(comparison::can_not_between_scalar(&Int32));
+}
+
+#[test]
+fn output_capacity() {
+    // the values of the result are allocated once with the exact number of bytes
+    let capacity = |result: BooleanArray| result.into_mut().right().unwrap().values().capacity();
+    let length = 1001;
+    let bits = (length + 7) / 8 * 8;
+
+    let lhs = Int32Array::from_vec((0..length as i32).collect());
+    let rhs = Int32Array::from_vec((0..length as i32).rev().collect());
+    assert_eq!(capacity(comparison::primitive::lt(&lhs, &rhs)), bits);
+    assert_eq!(capacity(comparison::primitive::eq_scalar(&lhs, 1)), bits);
+
+    let lhs = Utf8Array::<i32>::from_iter_values((0..length).map(|x| x.to_string()));
+    let rhs = Utf8Array::<i32>::from_iter_values((0..length).rev().map(|x| x.to_string()));
+    assert_eq!(capacity(comparison::utf8::lt(&lhs, &rhs)), bits);
+    assert_eq!(capacity(comparison::utf8::eq(&lhs, &rhs)), bits);
+    assert_eq!(capacity(comparison::utf8::gt_eq_scalar(&lhs, "5")), bits);
+}
+
+#[test]
+fn primitive_buffer_boundaries() {
+    // lengths whose last (incomplete) byte fills the stack buffer of the kernels
+    for length in [505, 511, 512, 513, 1017, 1023, 1024] {
+        let lhs = Int32Array::from_vec((0..length as i32).collect());
+        let rhs = Int32Array::from_vec((0..length as i32).map(|x| x - x % 2).collect());
+
+        let result = comparison::primitive::eq(&lhs, &rhs);
+        let expected =
+            BooleanArray::from_slice((0..length).map(|x| x % 2 == 0).collect::<Vec<_>>());
+        assert_eq!(result, expected, "{}", length);
+
+        let result = comparison::primitive::lt_scalar(&lhs, 3);
+        let expected = BooleanArray::from_slice((0..length).map(|x| x < 3).collect::<Vec<_>>());
+        assert_eq!(result, expected, "{}", length);
+
+        let mut out = MutableBitmap::new();
+        comparison::primitive::eq_into(&lhs, &rhs, &mut out);
+        comparison::primitive::eq_scalar_into(&lhs, 0, &mut out);
+        assert_eq!(out.len(), 2 * length, "{}", length);
+    }
+}
+
+#[test]
+fn broadcast() {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    let one = Int32Array::from_slice([2]);
+
+    let expected = BooleanArray::from([Some(true), None, Some(false)]);
+    assert_eq!(comparison::lt(&array, &one), expected);
+    assert_eq!(comparison::gt(&one, &array), expected);
+    let expected = BooleanArray::from([Some(false), None, Some(true)]);
+    assert_eq!(comparison::gt_eq(&array, &one), expected);
+    assert_eq!(comparison::lt_eq(&one, &array), expected);
+
+    let array = Utf8Array::<i32>::from_slice(["a", "b", "a"]);
+    let one = Utf8Array::<i32>::from_slice(["a"]);
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(comparison::eq(&array, &one), expected);
+    assert_eq!(comparison::eq(&one, &array), expected);
+    assert_eq!(
+        comparison::neq(&one, &array),
+        BooleanArray::from_slice([false, true, false])
+    );
+
+    // a null is broadcast as a null scalar
+    let null = Utf8Array::<i32>::from([None::<&str>]);
+    let expected = BooleanArray::new_null(DataType::Boolean, 3);
+    assert_eq!(comparison::eq(&null, &array), expected);
+
+    // two arrays of length 1 are compared as usual
+    let expected = BooleanArray::from_slice([true]);
+    assert_eq!(comparison::eq(&one, &one), expected);
+}
+
+#[test]
+#[should_panic]
+fn broadcast_different_lengths() {
+    let lhs = Int32Array::from_slice([1, 2, 3]);
+    let rhs = Int32Array::from_slice([1, 2]);
+    comparison::eq(&lhs, &rhs);
+}
+
+#[test]
+fn requires_materialization() {
+    use comparison::requires_materialization;
+    assert!(!requires_materialization(&Int32));
+    assert!(!requires_materialization(&Utf8));
+    let dictionary = Dictionary(IntegerType::Int32, Box::new(Utf8), false);
+    assert!(requires_materialization(&dictionary));
+    assert!(requires_materialization(&Struct(vec![Field::new(
+        "a", Int32, true
+    )])));
+    assert!(requires_materialization(&List(Box::new(Field::new(
+        "item", Int32, true
+    )))));
+}
+
+#[test]
+fn estimated_output_bytes() {
+    use arrow2::compute::aggregate::estimated_bytes_size;
+    use comparison::estimated_output_bytes;
+
+    let lhs = Int64Array::from([Some(1), None, Some(3)].repeat(100));
+    let rhs = Int64Array::from([Some(1), Some(2), None].repeat(100));
+    let result = comparison::eq(&lhs, &rhs);
+    let estimated = estimated_output_bytes(&Int64, lhs.len());
+    assert_eq!(estimated, estimated_bytes_size(&result));
+
+    // dictionaries additionally materialize their values
+    let values = Arc::new(Int64Array::from_slice([1, 2, 3]));
+    let keys = Int32Array::from([Some(0), None, Some(2)].repeat(100));
+    let dictionary = DictionaryArray::<i32>::from_data(keys, values);
+    let data_type = dictionary.data_type().clone();
+    let materialized = comparison::eq(&dictionary, &dictionary);
+    assert_eq!(materialized, comparison::eq(&lhs, &lhs));
+    let dictionary_estimated = estimated_output_bytes(&data_type, lhs.len());
+    assert_eq!(
+        dictionary_estimated,
+        estimated + 2 * estimated_bytes_size(&lhs)
+    );
+}
+
+fn rank_array() -> Utf8Array<i32> {
+    Utf8Array::<i32>::from([
+        Some("b"),
+        None,
+        Some("a"),
+        Some("c"),
+        Some("b"),
+        None,
+        Some("b"),
+    ])
+}
+
+#[test]
+fn rank_min() {
+    let result = comparison::rank(&rank_array(), comparison::RankMethod::Min).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([2, 6, 1, 5, 2, 6, 2]));
+}
+
+#[test]
+fn rank_max() {
+    let result = comparison::rank(&rank_array(), comparison::RankMethod::Max).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([4, 7, 1, 5, 4, 7, 4]));
+}
+
+#[test]
+fn rank_average() {
+    let result = comparison::rank(&rank_array(), comparison::RankMethod::Average).unwrap();
+    // the nulls tie at 6 and 7, whose average (6.5) is rounded down
+    assert_eq!(result, UInt32Array::from_slice([3, 6, 1, 5, 3, 6, 3]));
+}
+
+#[test]
+fn rank_dense() {
+    let result = comparison::rank(&rank_array(), comparison::RankMethod::Dense).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([2, 4, 1, 3, 2, 4, 2]));
+}
+
+#[test]
+fn rank_float() {
+    // NaN ranks after any other value and equal to itself
+    let array = Float64Array::from_slice([f64::NAN, 1.0, f64::NAN, -0.0, 0.0]);
+    let result = comparison::rank(&array, comparison::RankMethod::Min).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([4, 3, 4, 1, 2]));
+
+    let array = Float64Array::new_empty(Float64);
+    let result = comparison::rank(&array, comparison::RankMethod::Min).unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn rank_unsupported() {
+    let array = NullArray::new(Null, 2);
+    assert!(comparison::rank(&array, comparison::RankMethod::Min).is_err());
+}
+
+#[test]
+fn is_in_primitive() {
+    let array = Int64Array::from([Some(1), None, Some(3), Some(40)]);
+
+    let values = Int64Array::from([Some(3), None, Some(4)]);
+    let expected = BooleanArray::from([Some(false), None, Some(true), Some(false)]);
+    assert_eq!(comparison::is_in(&array, &values), expected);
+
+    // large sets are hashed
+    let values = Int64Array::from_vec((10..100).collect());
+    let expected = BooleanArray::from([Some(false), None, Some(false), Some(true)]);
+    assert_eq!(comparison::is_in(&array, &values), expected);
+}
+
+#[test]
+fn is_in_utf8() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+
+    let values = Utf8Array::<i32>::from_slice(["c", "d"]);
+    let expected = BooleanArray::from([Some(false), None, Some(true)]);
+    assert_eq!(comparison::is_in(&array, &values), expected);
+
+    let values = (0..100)
+        .map(|x| Some(x.to_string()))
+        .chain(std::iter::once(Some("a".to_string())))
+        .collect::<Utf8Array<i32>>();
+    let expected = BooleanArray::from([Some(true), None, Some(false)]);
+    assert_eq!(comparison::is_in(&array, &values), expected);
+}
+
+#[test]
+fn is_in_dictionary() {
+    let data = vec![Some("a"), None, Some("b"), Some("a")];
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let values = Utf8Array::<i32>::from_slice(["a"]);
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    assert_eq!(comparison::is_in(&array, &values), expected);
+}
+
+#[test]
+fn try_is_in() {
+    let array = Int32Array::from_slice([1, 2]);
+    let values = Int64Array::from_slice([1]);
+    assert!(matches!(
+        comparison::try_is_in(&array, &values),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let array = BooleanArray::from_slice([true, false]);
+    let values = BooleanArray::from_slice([true]);
+    assert!(matches!(
+        comparison::try_is_in(&array, &values),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+}
+
+#[test]
+#[should_panic(expected = "not yet supported")]
+fn is_in_not_supported() {
+    let array = BooleanArray::from_slice([true, false]);
+    let values = BooleanArray::from_slice([true]);
+    comparison::is_in(&array, &values);
+}
+
+#[test]
+fn can_is_in() {
+    assert!(comparison::can_is_in(&Int32));
+    assert!(comparison::can_is_in(&LargeUtf8));
+    assert!(comparison::can_is_in(&Dictionary(
+        IntegerType::Int32,
+        Box::new(Utf8),
+        false
+    )));
+    assert!(comparison::can_is_in(&Float32));
+    assert!(!comparison::can_is_in(&Null));
+}
+
+#[test]
+fn is_in_float() {
+    let array = Float64Array::from([Some(1.0), Some(-0.0), Some(f64::NAN), None, Some(2.0)]);
+
+    let values = Float64Array::from([Some(0.0), Some(f64::NAN), None, Some(1.0)]);
+    let expected = BooleanArray::from([Some(true), Some(true), Some(false), None, Some(false)]);
+    assert_eq!(comparison::is_in(&array, &values), expected);
+
+    // large sets are hashed
+    let values = (10..100)
+        .map(|x| Some(x as f64))
+        .chain([Some(0.0), Some(f64::NAN), None, Some(1.0)])
+        .collect::<Float64Array>();
+    assert_eq!(comparison::is_in(&array, &values), expected);
+
+    let array = Float32Array::from([Some(1.0), Some(2.0)]);
+    let values = Float32Array::from_slice([2.0]);
+    let expected = BooleanArray::from_slice([false, true]);
+    assert_eq!(comparison::is_in(&array, &values), expected);
+}
+
+#[test]
+fn is_in_consistency() {
+    let datatypes = vec![
+        Null,
+        Boolean,
+        UInt8,
+        Int64,
+        Float32,
+        Float64,
+        Decimal(10, 2),
+        Interval(IntervalUnit::YearMonth),
+        Interval(IntervalUnit::MonthDayNano),
+        Interval(IntervalUnit::DayTime),
+        Timestamp(TimeUnit::Second, None),
+        Date32,
+        Utf8,
+        LargeUtf8,
+        Binary,
+        LargeBinary,
+        FixedSizeBinary(2),
+        Dictionary(IntegerType::Int32, Box::new(LargeBinary), false),
+        Dictionary(IntegerType::UInt8, Box::new(Float32), false),
+        Dictionary(IntegerType::Int32, Box::new(Boolean), false),
+        Struct(vec![Field::new("a", Int32, true)]),
+        List(Box::new(Field::new("item", Int32, true))),
+    ];
+
+    datatypes.into_iter().for_each(|data_type| {
+        let array = new_null_array(data_type.clone(), 10);
+        let values = match &data_type {
+            Dictionary(_, values, _) => new_null_array(values.as_ref().clone(), 2),
+            _ => new_null_array(data_type.clone(), 2),
+        };
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            comparison::is_in(array.as_ref(), values.as_ref())
+        }));
+        assert_eq!(
+            result.is_ok(),
+            comparison::can_is_in(&data_type),
+            "{:?}",
+            data_type
+        );
+    });
+}
+
+#[test]
+fn compare() {
+    let lhs = Int32Array::from([Some(1), Some(2), Some(3), None]);
+    let rhs = Int32Array::from([Some(2), Some(2), Some(1), Some(1)]);
+    let expected = Int8Array::from([Some(-1), Some(0), Some(1), None]);
+    assert_eq!(comparison::compare(&lhs, &rhs).unwrap(), expected);
+
+    let lhs = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+    let rhs = Utf8Array::<i32>::from([Some("b"), Some("b"), Some("a")]);
+    let expected = Int8Array::from([Some(-1), Some(0), None]);
+    assert_eq!(comparison::compare(&lhs, &rhs).unwrap(), expected);
+}
+
+#[test]
+fn compare_float_total_order() {
+    let lhs = Float32Array::from_slice([f32::NAN, f32::NAN, -0.0, 1.0]);
+    let rhs = Float32Array::from_slice([f32::NAN, f32::INFINITY, 0.0, f32::NAN]);
+    let expected = Int8Array::from_slice([0, 1, -1, -1]);
+    assert_eq!(comparison::compare(&lhs, &rhs).unwrap(), expected);
+}
+
+#[test]
+fn compare_scalar() {
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    let lhs = Int64Array::from([Some(1), Some(2), None]);
+    let rhs = PrimitiveScalar::<i64>::new(Int64, Some(2));
+    let expected = Int8Array::from([Some(-1), Some(0), None]);
+    assert_eq!(comparison::compare_scalar(&lhs, &rhs).unwrap(), expected);
+
+    let rhs = PrimitiveScalar::<i64>::new(Int64, None);
+    let expected = Int8Array::from([None, None, None]);
+    assert_eq!(comparison::compare_scalar(&lhs, &rhs).unwrap(), expected);
+
+    let data = vec![Some("a"), None, Some("c")];
+    let mut lhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    lhs.try_extend(data).unwrap();
+    let lhs: DictionaryArray<i32> = lhs.into();
+    let rhs = Utf8Scalar::<i32>::new(Some("b"));
+    let expected = Int8Array::from([Some(-1), None, Some(1)]);
+    assert_eq!(comparison::compare_scalar(&lhs, &rhs).unwrap(), expected);
+
+    assert!(comparison::can_compare(&Float64));
+    assert!(!comparison::can_compare(&Null));
+    let lhs = NullArray::new(Null, 1);
+    assert!(matches!(
+        comparison::compare(&lhs, &lhs),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    assert!(matches!(
+        comparison::compare_scalar(&Int32Array::from_slice([1]), &rhs),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    let lhs = Int128Array::from_slice([1]).to(Decimal(10, 2));
+    let rhs = Int128Array::from_slice([1]).to(Decimal(10, 3));
+    assert!(matches!(
+        comparison::compare(&lhs, &rhs),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    assert!(comparison::can_compare_scalar(&Dictionary(
+        IntegerType::Int32,
+        Box::new(Utf8),
+        false
+    )));
+}
+
+#[test]
+fn lt_total() {
+    // sorting `[NaN, 1.0, -0.0]` with `lt_total` is deterministic: -0.0 < 1.0 < NaN
+    let values = [f64::NAN, 1.0, -0.0];
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| {
+        let a = Float64Array::from_slice([*a]);
+        let b = Float64Array::from_slice([*b]);
+        if comparison::lt_total(&a, &b).value(0) {
+            std::cmp::Ordering::Less
+        } else if comparison::gt_total(&a, &b).value(0) {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    });
+    assert_eq!(sorted[0].to_bits(), (-0.0f64).to_bits());
+    assert_eq!(sorted[1], 1.0);
+    assert!(sorted[2].is_nan());
+
+    // other types are not affected
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([2, 2]);
+    assert_eq!(comparison::lt_eq_total(&a, &b), comparison::lt_eq(&a, &b));
+    assert_eq!(
+        comparison::gt_eq_total(&a, &b),
+        BooleanArray::from_slice([false, true])
+    );
+}
+
+#[test]
+fn fixed_size_binary() {
+    use arrow2::scalar::FixedSizeBinaryScalar;
+
+    let lhs = FixedSizeBinaryArray::from([Some(*b"aa"), Some(*b"ab"), None]);
+    let rhs = FixedSizeBinaryArray::from([Some(*b"aa"), Some(*b"aa"), Some(*b"aa")]);
+    assert!(comparison::can_eq(lhs.data_type()));
+    assert!(comparison::can_lt_scalar(lhs.data_type()));
+
+    let expected = BooleanArray::from([Some(true), Some(false), None]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from([Some(false), Some(true), None]);
+    assert_eq!(comparison::gt(&lhs, &rhs), expected);
+
+    let scalar = FixedSizeBinaryScalar::new(FixedSizeBinary(2), Some(b"ab".to_vec()));
+    let expected = BooleanArray::from([Some(true), Some(false), None]);
+    assert_eq!(comparison::lt_scalar(&lhs, &scalar), expected);
+
+    let expected = Int8Array::from([Some(0), Some(1), None]);
+    assert_eq!(comparison::compare(&lhs, &rhs).unwrap(), expected);
+}
+
+#[test]
+fn min_max() {
+    let lhs = Int32Array::from([Some(1), Some(4), None, Some(2), None]);
+    let rhs = Int32Array::from([Some(2), Some(3), Some(1), None, None]);
+
+    let expected = Int32Array::from([Some(1), Some(3), None, None, None]);
+    assert_eq!(expected, comparison::min(&lhs, &rhs).unwrap().as_ref());
+    let expected = Int32Array::from([Some(2), Some(4), None, None, None]);
+    assert_eq!(expected, comparison::max(&lhs, &rhs).unwrap().as_ref());
+
+    let lhs = Float64Array::from([Some(1.0), Some(f64::NAN), None]);
+    let rhs = Float64Array::from([Some(f64::NAN), Some(2.0), Some(2.0)]);
+    let result = comparison::min(&lhs, &rhs).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.value(0), 1.0);
+    assert_eq!(result.value(1), 2.0);
+    assert!(result.is_null(2));
+
+    let lhs = Utf8Array::<i32>::from([Some("b"), Some("a"), None]);
+    let rhs = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+    let expected = Utf8Array::<i32>::from([Some("a"), None, None]);
+    assert_eq!(expected, comparison::min(&lhs, &rhs).unwrap().as_ref());
+    let expected = Utf8Array::<i32>::from([Some("b"), None, None]);
+    assert_eq!(expected, comparison::max(&lhs, &rhs).unwrap().as_ref());
+
+    let lhs = BinaryArray::<i64>::from([Some(b"ab".as_ref()), Some(b"b"), None]);
+    let rhs = BinaryArray::<i64>::from([Some(b"b".as_ref()), Some(b"ab"), Some(b"a")]);
+    let expected = BinaryArray::<i64>::from([Some(b"b".as_ref()), Some(b"b"), None]);
+    assert_eq!(expected, comparison::max(&lhs, &rhs).unwrap().as_ref());
+
+    assert!(comparison::can_min(&Utf8));
+    assert!(comparison::can_max(&Float32));
+    assert!(!comparison::can_max(&Boolean));
+    let lhs = BooleanArray::from_slice([true]);
+    assert!(matches!(
+        comparison::min(&lhs, &lhs),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    assert!(matches!(
+        comparison::max(&lhs, &Int32Array::from_slice([1])),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+}
+
+#[test]
+fn min_max_scalar() {
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    let lhs = Int64Array::from([Some(1), Some(5), None]);
+    let rhs = PrimitiveScalar::<i64>::new(Int64, Some(3));
+    let expected = Int64Array::from([Some(1), Some(3), None]);
+    assert_eq!(
+        expected,
+        comparison::min_scalar(&lhs, &rhs).unwrap().as_ref()
+    );
+    let expected = Int64Array::from([Some(3), Some(5), None]);
+    assert_eq!(
+        expected,
+        comparison::max_scalar(&lhs, &rhs).unwrap().as_ref()
+    );
+
+    // a null scalar nulls every slot
+    let rhs = PrimitiveScalar::<i64>::new(Int64, None);
+    let expected = Int64Array::from([None, None, None]);
+    assert_eq!(
+        expected,
+        comparison::max_scalar(&lhs, &rhs).unwrap().as_ref()
+    );
+
+    let lhs = Utf8Array::<i32>::from([Some("a"), Some("c"), None]);
+    let rhs = Utf8Scalar::<i32>::new(Some("b"));
+    let expected = Utf8Array::<i32>::from([Some("b"), Some("c"), None]);
+    assert_eq!(
+        expected,
+        comparison::max_scalar(&lhs, &rhs).unwrap().as_ref()
+    );
+    assert!(matches!(
+        comparison::min_scalar(&lhs, &PrimitiveScalar::<i64>::new(Int64, Some(3))),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+}
+
+#[test]
+fn eq_null_safe() {
+    // all four null/non-null combinations, with equal and different values
+    let lhs = Int32Array::from([Some(1), Some(1), Some(1), None, None]);
+    let rhs = Int32Array::from([Some(1), Some(2), None, Some(1), None]);
+    let expected = BooleanArray::from_slice([true, false, false, false, true]);
+    assert_eq!(comparison::eq_null_safe(&lhs, &rhs), expected);
+    assert_eq!(comparison::eq_null_safe(&rhs, &lhs), expected);
+
+    // null slots are equal regardless of the values behind them
+    let lhs = Int32Array::from_slice([1, 2]).with_validity(Some(Bitmap::from([false, true])));
+    let rhs = Int32Array::from_slice([3, 2]).with_validity(Some(Bitmap::from([false, true])));
+    let expected = BooleanArray::from_slice([true, true]);
+    assert_eq!(comparison::eq_null_safe(&lhs, &rhs), expected);
+
+    // only one side with validity
+    let lhs = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let rhs = Utf8Array::<i32>::from([Some("a"), None]);
+    let expected = BooleanArray::from_slice([true, false]);
+    assert_eq!(comparison::eq_null_safe(&lhs, &rhs), expected);
+
+    let lhs = NullArray::new(Null, 2);
+    let expected = BooleanArray::from_slice([true, true]);
+    assert_eq!(comparison::eq_null_safe(&lhs, &lhs), expected);
+}
+
+#[test]
+fn eq_null_safe_nested() {
+    // valid slots whose children are null are not equal, even with equal values behind the nulls
+    let values =
+        Int32Array::from_slice([1, 2, 3]).with_validity(Some(Bitmap::from([true, false, true])));
+    let fields = vec![Field::new("a", Int32, true)];
+    let lhs = StructArray::from_data(Struct(fields.clone()), vec![Arc::new(values.clone())], None);
+    let rhs = StructArray::from_data(
+        Struct(fields),
+        vec![Arc::new(values)],
+        Some(Bitmap::from([true, true, false])),
+    );
+    let expected = BooleanArray::from_slice([true, false, false]);
+    assert_eq!(comparison::eq_null_safe(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(comparison::eq_null_safe(&lhs, &lhs), expected);
+
+    let lhs = vectors(
+        &[
+            [Some(1.0), Some(2.0), Some(3.0)],
+            [Some(1.0), None, Some(3.0)],
+            [Some(1.0), None, Some(3.0)],
+        ],
+        Some(Bitmap::from([true, true, false])),
+    );
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(comparison::eq_null_safe(&lhs, &lhs), expected);
+}
+
+#[test]
+fn eq_null_safe_scalar() {
+    use arrow2::scalar::PrimitiveScalar;
+
+    let lhs = Int32Array::from([Some(1), Some(2), None]);
+
+    let rhs = PrimitiveScalar::<i32>::new(Int32, Some(1));
+    let expected = BooleanArray::from_slice([true, false, false]);
+    assert_eq!(comparison::eq_null_safe_scalar(&lhs, &rhs), expected);
+
+    let rhs = PrimitiveScalar::<i32>::new(Int32, None);
+    let expected = BooleanArray::from_slice([false, false, true]);
+    assert_eq!(comparison::eq_null_safe_scalar(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_eq() {
+    // same logical values, different encodings
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"]));
+    let lhs = DictionaryArray::<i32>::from_data(
+        Int32Array::from([Some(0), Some(1), None, Some(2)]),
+        values,
+    );
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["c", "a", "d"]));
+    let rhs = DictionaryArray::<i8>::from_data(
+        Int8Array::from([Some(1), Some(2), Some(0), Some(0)]),
+        values,
+    );
+
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false)]);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false)]);
+    assert_eq!(comparison::lt(&lhs, &rhs), expected);
+
+    assert!(comparison::can_eq(lhs.data_type()));
+    assert!(comparison::can_lt(rhs.data_type()));
+    assert!(!comparison::can_eq(&Dictionary(
+        IntegerType::Int32,
+        Box::new(Union(
+            vec![Field::new("a", Int32, true)],
+            None,
+            UnionMode::Dense
+        )),
+        false
+    )));
+}
+
+type ScalarOp = fn(&dyn Array, &dyn Scalar) -> BooleanArray;
+
+/// Checks that the ordering comparisons of `keys` into `values` as an ordered
+/// dictionary equal those as an unordered dictionary, for every value of `scalars`.
+fn check_ordered_dictionary(keys: &[Option<i32>], values: Arc<dyn Array>, scalars: &dyn Array) {
+    let unordered = DictionaryArray::<i32>::from_data(Int32Array::from(keys), values);
+    let mut ordered = unordered.clone();
+    ordered.set_ordered(true);
+    assert_ne!(ordered.data_type(), unordered.data_type());
+
+    let ops: [ScalarOp; 4] = [
+        comparison::lt_scalar,
+        comparison::lt_eq_scalar,
+        comparison::gt_scalar,
+        comparison::gt_eq_scalar,
+    ];
+    for index in 0..scalars.len() {
+        let value: Arc<dyn Scalar> = new_scalar(scalars, index).into();
+        let rhs = DictionaryScalar::<i32>::new(ordered.data_type().clone(), Some(value.clone()));
+        let unordered_rhs =
+            DictionaryScalar::<i32>::new(unordered.data_type().clone(), Some(value));
+        for op in ops {
+            assert_eq!(op(&ordered, &rhs), op(&unordered, &unordered_rhs));
+            assert_eq!(
+                op(&ordered.slice(1, 3), &rhs),
+                op(&unordered.slice(1, 3), &unordered_rhs)
+            );
+        }
+    }
+}
+
+#[test]
+fn ordered_dictionary_scalar() {
+    let keys = [Some(0), Some(1), None, Some(2), Some(1), Some(0)];
+
+    // values in, between, before and after the values of the dictionary
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["b", "d", "f"]));
+    let scalars = Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e", "f", "g"]);
+    check_ordered_dictionary(&keys, values, &scalars);
+
+    // duplicated values
+    let values = Arc::new(Int64Array::from_slice([1, 3, 3]));
+    let scalars = Int64Array::from_slice([0, 1, 2, 3, 4]);
+    check_ordered_dictionary(&keys, values, &scalars);
+
+    // null values and floats are compared by their values
+    let values = Arc::new(Int64Array::from([Some(1), Some(3), None]));
+    check_ordered_dictionary(&keys, values, &scalars);
+    let values = Arc::new(Float64Array::from_slice([1.0, 3.0, f64::NAN]));
+    let scalars = Float64Array::from_slice([0.0, 1.0, 2.0, 3.0, f64::NAN]);
+    check_ordered_dictionary(&keys, values, &scalars);
+}
+
+#[test]
+fn ordered_dictionary_scalar_unsorted() {
+    // values of an ordered dictionary that are not sorted are still compared by their values
+    let keys = [Some(0), Some(1), None, Some(2), Some(1), Some(0)];
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "c", "b"]));
+    let scalars = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    check_ordered_dictionary(&keys, values.clone(), &scalars);
+
+    let mut lhs = DictionaryArray::<i32>::from_data(Int32Array::from_slice([0, 1, 2]), values);
+    lhs.set_ordered(true);
+    let value = Arc::new(Utf8Scalar::<i32>::from(Some("c"))) as Arc<dyn Scalar>;
+    let rhs = DictionaryScalar::<i32>::new(lhs.data_type().clone(), Some(value));
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(comparison::lt_scalar(&lhs, &rhs), expected);
+
+    // null scalars and keys are null
+    let mut lhs = DictionaryArray::<i32>::from_data(
+        Int32Array::from([Some(0), None]),
+        Arc::new(Utf8Array::<i32>::from_slice(["a"])),
+    );
+    lhs.set_ordered(true);
+    let rhs = DictionaryScalar::<i32>::new(lhs.data_type().clone(), None);
+    let expected = BooleanArray::from([None, None]);
+    assert_eq!(comparison::gt_scalar(&lhs, &rhs), expected);
+    let value = Arc::new(Utf8Scalar::<i32>::from(Some("0"))) as Arc<dyn Scalar>;
+    let rhs = DictionaryScalar::<i32>::new(lhs.data_type().clone(), Some(value));
+    let expected = BooleanArray::from([Some(true), None]);
+    assert_eq!(comparison::gt_scalar(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_scalar_invalid_keys() {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "a"]));
+    // key 5 is out of range of the 3 values; null keys are not checked
+    let keys = Int32Array::from([Some(0), Some(5), None]);
+    let array = DictionaryArray::<i32>::from_data(keys, values.clone());
+    let scalar = new_scalar(
+        &DictionaryArray::<i32>::from_data(Int32Array::from_slice([1]), values),
+        0,
+    );
+
+    assert!(matches!(
+        comparison::try_eq_scalar(&array, scalar.as_ref()),
+        Err(ArrowError::OutOfSpec(_))
+    ));
+    assert!(matches!(
+        comparison::compare_scalar(&array, scalar.as_ref()),
+        Err(ArrowError::OutOfSpec(_))
+    ));
+    assert!(comparison::try_lt_scalar(&array, scalar.as_ref()).is_err());
+
+    let array = array.slice(0, 1);
+    let expected = BooleanArray::from_slice([false]);
+    assert_eq!(
+        comparison::try_eq_scalar(&array, scalar.as_ref()).unwrap(),
+        expected
+    );
+    // negative keys are out of range
+    let keys = Int32Array::from_slice([-1]);
+    let array =
+        DictionaryArray::<i32>::from_data(keys, Arc::new(Utf8Array::<i32>::from_slice(["a"])));
+    assert!(comparison::try_gt_scalar(&array, scalar.as_ref()).is_err());
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn dictionary_scalar_invalid_keys_panic() {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "a"]));
+    let array = DictionaryArray::<i32>::from_data(Int32Array::from_slice([0, 5]), values.clone());
+    let scalar = new_scalar(
+        &DictionaryArray::<i32>::from_data(Int32Array::from_slice([1]), values),
+        0,
+    );
+    comparison::lt_scalar(&array, scalar.as_ref());
+}
+
+#[test]
+fn dictionary_eq_scalar() {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"]));
+    let lhs = DictionaryArray::<i32>::from_data(
+        Int32Array::from([Some(0), Some(1), None, Some(2), Some(1)]),
+        values,
+    );
+    // the scalar of a dictionary array
+    let rhs = new_scalar(&lhs, 1);
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false), Some(true)]);
+    assert_eq!(comparison::eq_scalar(&lhs, rhs.as_ref()), expected);
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false), Some(false)]);
+    assert_eq!(comparison::lt_scalar(&lhs, rhs.as_ref()), expected);
+
+    // a value that is not in the dictionary
+    let value = Arc::new(Utf8Scalar::<i32>::from(Some("d"))) as Arc<dyn Scalar>;
+    let rhs = DictionaryScalar::<i32>::new(lhs.data_type().clone(), Some(value));
+    let expected = BooleanArray::from([Some(false), Some(false), None, Some(false), Some(false)]);
+    assert_eq!(comparison::eq_scalar(&lhs, &rhs), expected);
+
+    // duplicated and null values
+    let values = Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("a")]));
+    let lhs = DictionaryArray::<i32>::from_data(
+        Int32Array::from([Some(0), Some(1), None, Some(2)]),
+        values,
+    );
+    let rhs = new_scalar(&lhs, 0);
+    let expected = BooleanArray::from([Some(true), None, None, Some(true)]);
+    assert_eq!(comparison::eq_scalar(&lhs, rhs.as_ref()), expected);
+}
+
+#[test]
+fn scalar_lhs() {
+    let array = Int32Array::from([Some(1), None, Some(5), Some(7)]);
+    let scalar = PrimitiveScalar::from(Some(5i32));
+
+    // `5 op array`
+    let expected = BooleanArray::from([Some(false), None, Some(true), Some(false)]);
+    assert_eq!(comparison::eq_scalar_lhs(&scalar, &array), expected);
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    assert_eq!(comparison::neq_scalar_lhs(&scalar, &array), expected);
+    let expected = BooleanArray::from([Some(false), None, Some(false), Some(true)]);
+    assert_eq!(comparison::lt_scalar_lhs(&scalar, &array), expected);
+    let expected = BooleanArray::from([Some(false), None, Some(true), Some(true)]);
+    assert_eq!(comparison::lt_eq_scalar_lhs(&scalar, &array), expected);
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(false)]);
+    assert_eq!(comparison::gt_scalar_lhs(&scalar, &array), expected);
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(false)]);
+    assert_eq!(comparison::gt_eq_scalar_lhs(&scalar, &array), expected);
+
+    // a null scalar results in nulls
+    let scalar = PrimitiveScalar::<i32>::from(None);
+    let expected = BooleanArray::new_null(DataType::Boolean, 4);
+    assert_eq!(comparison::lt_scalar_lhs(&scalar, &array), expected);
+}
+
+#[test]
+fn eq_nan() {
+    let lhs = Float64Array::from([Some(f64::NAN), Some(-0.0), Some(f64::NAN), None]);
+    let rhs = Float64Array::from([Some(f64::NAN), Some(0.0), Some(1.0), Some(1.0)]);
+    let expected = BooleanArray::from([Some(true), Some(true), Some(false), None]);
+    assert_eq!(comparison::eq_nan(&lhs, &rhs), expected);
+
+    let scalar = PrimitiveScalar::from(Some(f64::NAN));
+    let expected = BooleanArray::from([Some(true), Some(false), Some(true), None]);
+    assert_eq!(comparison::eq_nan_scalar(&lhs, &scalar), expected);
+
+    let scalar = PrimitiveScalar::<f64>::from(None);
+    let expected = BooleanArray::new_null(DataType::Boolean, 4);
+    assert_eq!(comparison::eq_nan_scalar(&lhs, &scalar), expected);
+
+    // other types are compared as in `eq`
+    let lhs = Int32Array::from([Some(1), None]);
+    let rhs = Int32Array::from([Some(1), Some(2)]);
+    assert_eq!(comparison::eq_nan(&lhs, &rhs), comparison::eq(&lhs, &rhs));
+}
+
+#[test]
+fn xor() {
+    let lhs = BooleanArray::from([Some(true), Some(true), Some(false), Some(false), None]);
+    let rhs = BooleanArray::from([Some(true), Some(false), Some(true), Some(false), Some(true)]);
+    let expected = BooleanArray::from([Some(false), Some(true), Some(true), Some(false), None]);
+    assert!(comparison::can_xor(lhs.data_type()));
+    assert_eq!(comparison::xor(&lhs, &rhs).unwrap(), expected);
+
+    let scalar = BooleanScalar::from(Some(true));
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), Some(true), None]);
+    assert_eq!(comparison::xor_scalar(&lhs, &scalar).unwrap(), expected);
+    let scalar = BooleanScalar::from(Some(false));
+    assert_eq!(comparison::xor_scalar(&lhs, &scalar).unwrap(), lhs);
+    let scalar = BooleanScalar::from(None);
+    let expected = BooleanArray::new_null(DataType::Boolean, 5);
+    assert_eq!(comparison::xor_scalar(&lhs, &scalar).unwrap(), expected);
+
+    assert!(!comparison::can_xor(&Int32));
+    assert!(!comparison::can_xor_scalar(&Utf8));
+    let array = Int32Array::from_slice([1]);
+    assert!(matches!(
+        comparison::xor(&array, &array),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    assert!(matches!(
+        comparison::xor_scalar(&array, &scalar),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+}
+
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
@@ -116,6 +1932,17 @@ mod tests {
         cmp_bool_scalar!(eq_scalar, &[false, true], true, &[false, true]);
     }
 
+    #[test]
+    fn test_eq_scalar_false_validity() {
+        // sliced at an unaligned offset, with a validity
+        let a = BooleanArray::from([Some(true), Some(false), None, Some(true), Some(false)]);
+        let a = a.slice(1, 4);
+        let expected = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+        assert_eq!(eq_scalar(&a, false), expected);
+        assert_eq!(neq_scalar(&a, true), expected);
+        assert_eq!(eq_scalar(&a, true), a);
+    }
+
     #[test]
     fn test_eq_with_slice() {
         let a = BooleanArray::from_slice(&[true, true, false]);
@@ -327,4 +2154,356 @@ mod tests {
         let out = comparison::boolean::neq_and_validity(&a, &b);
         check_mask(&out, &[false, true, true]);
     }
+
+    #[test]
+    fn compare_no_propagating_nulls_sliced() {
+        // validities at unaligned offsets, crossing a byte boundary
+        let a = Int32Array::from_iter((0..20).map(|x| if x % 3 == 0 { None } else { Some(x) }));
+        let b = Int32Array::from_iter((0..20).map(|x| if x % 4 == 0 { None } else { Some(x % 5) }));
+        let (a, b) = (a.slice(3, 14), b.slice(5, 14));
+
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| a == b)
+            .collect::<Vec<_>>();
+        let out = comparison::primitive::eq_and_validity(&a, &b);
+        check_mask(&out, &expected);
+        let out = comparison::primitive::neq_and_validity(&a, &b);
+        let expected = expected.iter().map(|x| !x).collect::<Vec<_>>();
+        check_mask(&out, &expected);
+
+        // a validity without nulls is the same as no validity
+        let all_valid = b.with_validity(Some(Bitmap::from_iter(std::iter::repeat(true).take(14))));
+        let expected = a
+            .iter()
+            .zip(all_valid.iter())
+            .map(|(a, b)| a == b)
+            .collect::<Vec<_>>();
+        let out = comparison::primitive::eq_and_validity(&a, &all_valid);
+        check_mask(&out, &expected);
+        let out = comparison::primitive::neq_and_validity(&all_valid, &a);
+        let expected = expected.iter().map(|x| !x).collect::<Vec<_>>();
+        check_mask(&out, &expected);
+    }
+}
+
+#[test]
+fn starts_with() {
+    let array = Utf8Array::<i64>::from([Some("apple"), None, Some("banana"), Some("ap")]);
+    let prefix = Utf8Scalar::<i64>::new(Some("ap"));
+    let result = comparison::starts_with(&array, &prefix).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), None, Some(false), Some(true)])
+    );
+
+    let array = BinaryArray::<i32>::from([Some(b"\x89PNG".as_ref()), Some(b"GIF8"), None]);
+    let prefix = BinaryScalar::<i32>::new(Some(b"\x89P".as_ref()));
+    let result = comparison::starts_with(&array, &prefix).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+
+    // a null prefix results in nulls
+    let prefix = BinaryScalar::<i32>::new(None::<&[u8]>);
+    let result = comparison::starts_with(&array, &prefix).unwrap();
+    assert_eq!(result, BooleanArray::new_null(DataType::Boolean, 3));
+
+    // the prefix must have the type of the array
+    let prefix = Utf8Scalar::<i32>::new(Some("G"));
+    assert!(comparison::starts_with(&array, &prefix).is_err());
+
+    assert!(comparison::can_starts_with(&LargeUtf8));
+    assert!(!comparison::can_starts_with(&Int32));
+    let array = Int32Array::from_slice([1]);
+    let prefix = PrimitiveScalar::<i32>::new(Int32, Some(1));
+    assert!(matches!(
+        comparison::starts_with(&array, &prefix),
+        Err(arrow2::error::ArrowError::NotYetImplemented(_))
+    ));
+}
+
+#[test]
+fn try_comparisons() {
+    let lhs = Int32Array::from([Some(1), None, Some(3)]);
+    let rhs = Int32Array::from([Some(1), Some(2), Some(2)]);
+    assert_eq!(
+        comparison::try_eq(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        comparison::try_gt(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(false), None, Some(true)])
+    );
+    assert_eq!(
+        comparison::try_neq_and_validity(&lhs, &rhs).unwrap(),
+        BooleanArray::from_slice([false, true, true])
+    );
+    // broadcasting
+    let one = Int32Array::from_slice([2]);
+    assert_eq!(
+        comparison::try_lt_eq(&lhs, &one).unwrap(),
+        BooleanArray::from([Some(true), None, Some(false)])
+    );
+    // decimals of different scales
+    let lhs = Int128Array::from_slice([100]).to(Decimal(5, 2));
+    let rhs = Int128Array::from_slice([10]).to(Decimal(5, 1));
+    assert_eq!(
+        comparison::try_eq(&lhs, &rhs).unwrap(),
+        BooleanArray::from_slice([true])
+    );
+}
+
+#[test]
+fn try_comparisons_errors() {
+    let lhs = Int32Array::from_slice([1, 2, 3]);
+
+    // different lengths, neither of length 1
+    let rhs = Int32Array::from_slice([1, 2]);
+    assert!(matches!(
+        comparison::try_eq(&lhs, &rhs),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    // arrays of length 1 are not broadcast when validities are compared
+    let rhs = Int32Array::from_slice([1]);
+    assert!(matches!(
+        comparison::try_eq_and_validity(&lhs, &rhs),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    // different logical types
+    let rhs = Int64Array::from_slice([1, 2, 3]);
+    assert!(matches!(
+        comparison::try_lt(&lhs, &rhs),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    let scalar = PrimitiveScalar::<i64>::new(Int64, Some(1));
+    assert!(matches!(
+        comparison::try_lt_scalar(&lhs, &scalar),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    // unsupported logical types
+    let values = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let list = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(Int32),
+        vec![0, 1, 2].into(),
+        values,
+        None,
+    );
+    assert!(comparison::try_eq(&list, &list).is_ok());
+    assert!(matches!(
+        comparison::try_gt_eq(&list, &list),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    let scalar = new_scalar(&list, 0);
+    assert!(matches!(
+        comparison::try_neq_scalar(&list, scalar.as_ref()),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+}
+
+#[test]
+fn try_comparisons_errors_other_kernels() {
+    let lhs = Float64Array::from_slice([1.0, 2.0, 3.0]);
+    let shorter = Float64Array::from_slice([1.0, 2.0]);
+    let other_type = Int64Array::from_slice([1, 2, 3]);
+    type ArrayOp = fn(&dyn Array, &dyn Array) -> arrow2::error::Result<BooleanArray>;
+    let ops: [ArrayOp; 6] = [
+        comparison::try_eq_null_safe,
+        comparison::try_eq_nan,
+        comparison::try_lt_total,
+        comparison::try_lt_eq_total,
+        comparison::try_gt_total,
+        comparison::try_gt_eq_total,
+    ];
+    for op in ops {
+        assert!(matches!(
+            op(&lhs, &shorter),
+            Err(ArrowError::InvalidArgumentError(_))
+        ));
+        assert!(matches!(
+            op(&lhs, &other_type),
+            Err(ArrowError::InvalidArgumentError(_))
+        ));
+        assert!(op(&lhs, &lhs).is_ok());
+    }
+    assert!(matches!(
+        comparison::try_not_between(&lhs, &shorter, &lhs, Inclusivity::Both),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let utf8 = Utf8Array::<i32>::from_slice(["a", "b"]);
+    assert!(matches!(
+        comparison::try_eq_utf8_any(&utf8, &BinaryArray::<i32>::from_slice([b"a", b"b"])),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    assert!(matches!(
+        comparison::try_eq_utf8_any(&utf8, &Utf8Array::<i64>::from_slice(["a"])),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let scalar = PrimitiveScalar::<i64>::new(Int64, Some(1));
+    type ScalarOp = fn(&dyn Array, &dyn Scalar) -> arrow2::error::Result<BooleanArray>;
+    let ops: [ScalarOp; 4] = [
+        comparison::try_eq_scalar_and_validity,
+        comparison::try_neq_scalar_and_validity,
+        comparison::try_eq_null_safe_scalar,
+        comparison::try_eq_nan_scalar,
+    ];
+    for op in ops {
+        assert!(matches!(
+            op(&lhs, &scalar),
+            Err(ArrowError::InvalidArgumentError(_))
+        ));
+    }
+    type ScalarLhsOp = fn(&dyn Scalar, &dyn Array) -> arrow2::error::Result<BooleanArray>;
+    let ops: [ScalarLhsOp; 6] = [
+        comparison::try_eq_scalar_lhs,
+        comparison::try_neq_scalar_lhs,
+        comparison::try_lt_scalar_lhs,
+        comparison::try_lt_eq_scalar_lhs,
+        comparison::try_gt_scalar_lhs,
+        comparison::try_gt_eq_scalar_lhs,
+    ];
+    for op in ops {
+        assert!(matches!(
+            op(&scalar, &lhs),
+            Err(ArrowError::InvalidArgumentError(_))
+        ));
+    }
+    assert!(matches!(
+        comparison::try_between_scalar_left_open(&lhs, &scalar, &scalar),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    assert!(matches!(
+        comparison::try_between_scalar_right_open(&lhs, &scalar, &scalar),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    assert!(matches!(
+        comparison::try_not_between_scalar(&lhs, &scalar, &scalar, Inclusivity::Neither),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    // unsupported logical types
+    let list = new_null_array(List(Box::new(Field::new("item", Int32, true))), 1);
+    let scalar = new_scalar(list.as_ref(), 0);
+    assert!(matches!(
+        comparison::try_eq_scalar_lhs(scalar.as_ref(), list.as_ref()),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+}
+
+#[test]
+#[should_panic(expected = "same logical type")]
+fn different_types_panic() {
+    let lhs = Int32Array::from_slice([1]);
+    let rhs = Int64Array::from_slice([1]);
+    comparison::eq(&lhs, &rhs);
+}
+
+/// Asserts that `result` is `array` filtered by `predicate`.
+fn check_filter(result: Box<dyn Array>, predicate: BooleanArray, array: &dyn Array) {
+    let expected = arrow2::compute::filter::filter(array, &predicate).unwrap();
+    assert_eq!(result.as_ref(), expected.as_ref());
+    assert_eq!(result.null_count(), 0);
+}
+
+#[test]
+fn filter_scalar() {
+    let array =
+        Int32Array::from_iter((0..37).map(|x| if x % 5 == 0 { None } else { Some(x % 11) }));
+    let scalar: &dyn Scalar = &PrimitiveScalar::<i32>::from(Some(6));
+    // unaligned slices cover the validity's partial bytes
+    for (offset, length) in [(0, 37), (3, 30), (5, 2), (0, 0)] {
+        let array = array.slice(offset, length);
+        check_filter(
+            comparison::filter_lt_scalar(&array, scalar).unwrap(),
+            comparison::lt_scalar(&array, scalar),
+            &array,
+        );
+        check_filter(
+            comparison::filter_lt_eq_scalar(&array, scalar).unwrap(),
+            comparison::lt_eq_scalar(&array, scalar),
+            &array,
+        );
+        check_filter(
+            comparison::filter_gt_scalar(&array, scalar).unwrap(),
+            comparison::gt_scalar(&array, scalar),
+            &array,
+        );
+        check_filter(
+            comparison::filter_gt_eq_scalar(&array, scalar).unwrap(),
+            comparison::gt_eq_scalar(&array, scalar),
+            &array,
+        );
+    }
+
+    // without validity
+    let array = Float64Array::from_slice([1.0, 4.0, f64::NAN, 2.5]);
+    let result =
+        comparison::filter_gt_eq_scalar(&array, &PrimitiveScalar::from(Some(2.5f64))).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Float64Array::from_slice([4.0, 2.5]) as &dyn Array
+    );
+
+    // a null scalar filters out every element
+    let result = comparison::filter_lt_scalar(&array, &PrimitiveScalar::<f64>::from(None)).unwrap();
+    assert_eq!(result.len(), 0);
+    assert_eq!(result.data_type(), &Float64);
+
+    assert!(comparison::can_filter_scalar(&Decimal(10, 2)));
+    assert!(!comparison::can_filter_scalar(&Utf8));
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(matches!(
+        comparison::filter_lt_scalar(&array, &Utf8Scalar::<i32>::from(Some("b"))),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    assert!(matches!(
+        comparison::filter_lt_scalar(&array, &PrimitiveScalar::from(Some(1i32))),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+}
+
+#[test]
+fn active_lanes() {
+    use arrow2::compute::comparison::simd::active_lanes;
+    use arrow2::types::days_ms;
+
+    let expected = if cfg!(feature = "simd") { 8 } else { 1 };
+    assert_eq!(active_lanes::<i32>(), expected);
+    assert_eq!(active_lanes::<f64>(), expected);
+    // types without a SIMD representation are compared one by one
+    assert_eq!(active_lanes::<i128>(), 1);
+    assert_eq!(active_lanes::<days_ms>(), 1);
+}
+
+#[test]
+fn eq_values() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4), Some(5)]);
+    let values = [Some(1), Some(2), None, Some(5), Some(5)];
+
+    let result = comparison::primitive::eq_values(&array, values);
+    let expected = BooleanArray::from(&[Some(true), None, None, Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    // a lazily computed bound per row, on a sliced array
+    let array = Int32Array::from_slice([0, 2, 4, 6, 8]).slice(1, 4);
+    let result = comparison::primitive::eq_values(&array, (1..5).map(|i| Some(i * 2)));
+    assert_eq!(result, BooleanArray::from_slice([true, true, true, true]));
+    assert_eq!(result.validity(), None);
+}
+
+#[test]
+#[should_panic(expected = "fewer")]
+fn eq_values_too_few() {
+    let array = Int32Array::from_slice([1, 2]);
+    comparison::primitive::eq_values(&array, [Some(1)]);
+}
+
+#[test]
+#[should_panic(expected = "more")]
+fn eq_values_too_many() {
+    let array = Int32Array::from_slice([1, 2]);
+    comparison::primitive::eq_values(&array, [Some(1), Some(2), Some(3)]);
 }
@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
 use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::{DataType, Field};
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +119,56 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+fn struct_array(
+    a: Vec<Option<i32>>,
+    b: Vec<Option<&str>>,
+    validity: Option<Bitmap>,
+) -> StructArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Int32Array::from(a)),
+            Arc::new(Utf8Array::<i32>::from(b)),
+        ],
+        validity,
+    )
+}
+
+#[test]
+fn struct_arrays() -> Result<()> {
+    let arr = concatenate(&[
+        &struct_array(vec![Some(1), None], vec![Some("a"), Some("b")], None),
+        &struct_array(
+            vec![Some(3), Some(4), None],
+            vec![None, Some("d"), Some("e")],
+            Some([true, false, true].into()),
+        ),
+        &struct_array(vec![Some(6)], vec![Some("f")], None).slice(0, 1),
+    ])?;
+
+    let expected_output = struct_array(
+        vec![Some(1), None, Some(3), Some(4), None, Some(6)],
+        vec![Some("a"), Some("b"), None, Some("d"), Some("e"), Some("f")],
+        Some([true, true, true, false, true, true].into()),
+    );
+
+    assert_eq!(&expected_output as &dyn Array, arr.as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn incompatible_struct_datatypes() {
+    let other = StructArray::from_data(
+        DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+        vec![Arc::new(Int32Array::from_slice([1]))],
+        None,
+    );
+    let re = concatenate(&[&struct_array(vec![Some(1)], vec![Some("a")], None), &other]);
+    assert!(re.is_err());
+}
@@ -1,7 +1,11 @@
-use arrow2::array::new_null_array;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
 use arrow2::compute::hash::*;
 use arrow2::datatypes::DataType::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{Field, TimeUnit};
 
 #[test]
 fn consistency() {
@@ -36,6 +40,11 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        Struct(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", Utf8, true),
+        ]),
+        Struct(vec![Field::new("a", Float32, true)]),
     ];
 
     datatypes.into_iter().for_each(|d1| {
@@ -47,3 +56,95 @@ fn consistency() {
         }
     });
 }
+
+fn struct_array(
+    a: Vec<Option<i32>>,
+    b: Vec<Option<&str>>,
+    validity: Option<Bitmap>,
+) -> StructArray {
+    let fields = vec![Field::new("a", Int32, true), Field::new("b", Utf8, true)];
+    StructArray::from_data(
+        Struct(fields),
+        vec![
+            Arc::new(Int32Array::from(a)),
+            Arc::new(Utf8Array::<i32>::from(b)),
+        ],
+        validity,
+    )
+}
+
+#[test]
+fn struct_equal_rows() {
+    let array = struct_array(
+        vec![Some(1), Some(1), None, Some(2), None, Some(1)],
+        vec![Some("a"), Some("a"), Some("a"), Some("a"), None, Some("b")],
+        Some(Bitmap::from([true, true, true, true, true, false])),
+    );
+    let result = hash_struct(&array).unwrap();
+    assert_eq!(result.validity(), None);
+    let values = result.values();
+
+    assert_eq!(values[0], values[1]);
+    // rows differing in a value or in the nullness of a value hash differently
+    assert_ne!(values[0], values[2]);
+    assert_ne!(values[0], values[3]);
+    assert_ne!(values[2], values[4]);
+    // null rows hash to the sentinel, regardless of their values
+    assert_eq!(values[5], NULL_HASH);
+
+    // the hash of a row does not depend on its position
+    let sliced = hash_struct(&array.slice(1, 2)).unwrap();
+    assert_eq!(sliced.values().as_slice(), &values[1..3]);
+    assert_eq!(hash(&array).unwrap(), result);
+}
+
+#[test]
+fn struct_field_order() {
+    let lhs = struct_array(vec![Some(1)], vec![Some("2")], None);
+    let rhs = struct_array(vec![Some(2)], vec![Some("1")], None);
+    assert_ne!(hash_struct(&lhs).unwrap(), hash_struct(&rhs).unwrap());
+}
+
+#[test]
+fn struct_nested() {
+    let inner = struct_array(
+        vec![Some(1), Some(1), Some(1), Some(2)],
+        vec![Some("a"), Some("a"), Some("a"), Some("a")],
+        Some(Bitmap::from([true, true, false, true])),
+    );
+    let fields = vec![Field::new("inner", inner.data_type().clone(), true)];
+    let array = StructArray::from_data(Struct(fields), vec![Arc::new(inner)], None);
+
+    let values = hash_struct(&array).unwrap().values().clone();
+    assert_eq!(values[0], values[1]);
+    assert_ne!(values[0], values[2]);
+    assert_ne!(values[0], values[3]);
+    // a null nested row differs from a null row
+    assert_ne!(values[2], NULL_HASH);
+}
+
+#[test]
+fn struct_distribution() {
+    let length = 10_000;
+    let a = (0..length as i32).map(|x| Some(x % 100)).collect();
+    let b = (0..length)
+        .map(|x| Some((x / 100).to_string()))
+        .collect::<Vec<_>>();
+    let b = b.iter().map(|x| x.as_deref()).collect();
+    let array = struct_array(a, b, None);
+
+    // all rows are different: their hashes are expected to be
+    let result = hash_struct(&array).unwrap();
+    let hashes = result.values().iter().collect::<HashSet<_>>();
+    assert_eq!(hashes.len(), length);
+
+    // and so are their low bits, e.g. to use them as the buckets of a hash table
+    let buckets = result
+        .values()
+        .iter()
+        .fold(vec![0usize; 16], |mut buckets, x| {
+            buckets[(x % 16) as usize] += 1;
+            buckets
+        });
+    assert!(buckets.iter().all(|x| *x > length / 16 / 2));
+}
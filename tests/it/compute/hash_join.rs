@@ -0,0 +1,230 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::hash::hash_struct;
+use arrow2::compute::hash_join::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::{ArrowError, Result};
+
+fn keys(ids: &[Option<i32>], names: &[Option<&str>], validity: Option<Bitmap>) -> StructArray {
+    let fields = vec![
+        Field::new("id", DataType::Int32, true),
+        Field::new("name", DataType::Utf8, true),
+    ];
+    let values: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Int32Array::from(ids)),
+        Arc::new(Utf8Array::<i32>::from(names)),
+    ];
+    StructArray::from_data(DataType::Struct(fields), values, validity)
+}
+
+fn probe(index: &KeyIndex, probe_keys: &StructArray) -> Vec<Vec<usize>> {
+    let prober = index.prober(probe_keys);
+    (0..probe_keys.len())
+        .map(|row| prober.probe(row).collect())
+        .collect()
+}
+
+// every row hashes to one of two buckets, so that most rows collide
+fn colliding_hash(keys: &StructArray) -> Result<PrimitiveArray<u64>> {
+    let ids = keys.values()[0]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    Ok(ids
+        .iter()
+        .map(|id| Some(id.copied().unwrap_or_default() as u64 % 2))
+        .collect())
+}
+
+fn constant_hash(keys: &StructArray) -> Result<PrimitiveArray<u64>> {
+    Ok(PrimitiveArray::from_vec(vec![0; keys.len()]))
+}
+
+#[test]
+fn basics() -> Result<()> {
+    let build = keys(
+        &[Some(1), Some(2), Some(1), Some(3)],
+        &[Some("a"), Some("b"), Some("a"), Some("c")],
+        None,
+    );
+    let index = build_key_index(&build)?;
+    assert_eq!(index.keys(), &build);
+
+    let probe_keys = keys(
+        &[Some(1), Some(3), Some(2), Some(4)],
+        &[Some("a"), Some("c"), Some("a"), Some("a")],
+        None,
+    );
+    assert_eq!(
+        probe(&index, &probe_keys),
+        vec![vec![0, 2], vec![3], vec![], vec![]]
+    );
+    Ok(())
+}
+
+#[test]
+fn colliding_keys() -> Result<()> {
+    let build = keys(
+        &[Some(1), Some(3), Some(5), Some(1), Some(2), Some(4)],
+        &[
+            Some("a"),
+            Some("a"),
+            Some("b"),
+            Some("b"),
+            Some("a"),
+            Some("a"),
+        ],
+        None,
+    );
+    let probe_keys = keys(
+        &[Some(1), Some(3), Some(5), Some(1), Some(4), Some(7)],
+        &[
+            Some("a"),
+            Some("a"),
+            Some("b"),
+            Some("b"),
+            Some("a"),
+            Some("a"),
+        ],
+        None,
+    );
+    let expected = vec![vec![0], vec![1], vec![2], vec![3], vec![5], vec![]];
+
+    for hasher in [colliding_hash as RowHasher, constant_hash] {
+        let index = build_key_index_with(&build, hasher)?;
+        assert_eq!(probe(&index, &probe_keys), expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn nulls_do_not_match() -> Result<()> {
+    let build = keys(
+        &[Some(1), None, Some(2), Some(3)],
+        &[Some("a"), Some("a"), None, Some("c")],
+        Some(Bitmap::from([true, true, true, false])),
+    );
+    // the same rows, which would match if nulls matched nulls
+    let probe_keys = build.clone();
+
+    for hasher in [hash_struct as RowHasher, constant_hash] {
+        let index = build_key_index_with(&build, hasher)?;
+        assert_eq!(
+            probe(&index, &probe_keys),
+            vec![vec![0], vec![], vec![], vec![]]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let build = keys(
+        &[Some(1), Some(2), Some(1), Some(3)],
+        &[Some("a"), Some("b"), Some("a"), Some("c")],
+        None,
+    )
+    .slice(1, 3);
+    let index = build_key_index_with(&build, constant_hash)?;
+
+    let probe_keys = keys(&[Some(0), Some(1)], &[Some("z"), Some("a")], None).slice(1, 1);
+    assert_eq!(probe(&index, &probe_keys), vec![vec![1]]);
+    Ok(())
+}
+
+#[test]
+fn nested_keys() -> Result<()> {
+    let list = ListArray::<i32>::from_data(
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        vec![0, 2, 3, 5, 7, 9].into(),
+        Arc::new(Int32Array::from([
+            Some(1),
+            Some(2),
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(1),
+            None,
+            Some(1),
+            None,
+        ])),
+        None,
+    );
+    let mut dictionary = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(["a", "a", "a", "b", "a"].iter().map(Some))?;
+    let dictionary: DictionaryArray<u8> = dictionary.into();
+    let fields = vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("dictionary", dictionary.data_type().clone(), true),
+    ];
+    let build = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(list), Arc::new(dictionary)],
+        None,
+    );
+
+    let index = build_key_index_with(&build, constant_hash)?;
+    // rows differ by the length or the values of their lists or by their dictionary values,
+    // and lists with null items are not equal
+    assert_eq!(
+        probe(&index, &build),
+        vec![vec![0, 2], vec![1], vec![0, 2], vec![], vec![]]
+    );
+    Ok(())
+}
+
+#[test]
+fn float_keys() -> Result<()> {
+    let build = StructArray::from_data(
+        DataType::Struct(vec![Field::new("a", DataType::Float64, true)]),
+        vec![Arc::new(Float64Array::from_slice([0.0, f64::NAN, 1.0]))],
+        None,
+    );
+    let probe_keys = StructArray::from_data(
+        DataType::Struct(vec![Field::new("a", DataType::Float64, true)]),
+        vec![Arc::new(Float64Array::from_slice([-0.0, f64::NAN, 2.0]))],
+        None,
+    );
+
+    // as in the comparison kernels, `NaN` is equal to nothing and `-0.0` equals `0.0`
+    let index = build_key_index_with(&build, constant_hash)?;
+    assert_eq!(probe(&index, &probe_keys), vec![vec![0], vec![], vec![]]);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let build = keys(&[Some(1)], &[Some("a")], None);
+
+    let wrong_length: RowHasher = |_| Ok(PrimitiveArray::from_vec(vec![0, 1]));
+    assert!(matches!(
+        build_key_index_with(&build, wrong_length),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    // lists can be compared but not hashed
+    let item = Field::new("item", DataType::Int32, true);
+    let list = ListArray::<i32>::new_empty(DataType::List(Box::new(item)));
+    let build = StructArray::from_data(
+        DataType::Struct(vec![Field::new("a", list.data_type().clone(), true)]),
+        vec![Arc::new(list)],
+        None,
+    );
+    assert!(build_key_index(&build).is_err());
+}
+
+#[test]
+#[should_panic]
+fn probe_different_type() {
+    let build = keys(&[Some(1)], &[Some("a")], None);
+    let index = build_key_index(&build).unwrap();
+
+    let probe_keys = StructArray::from_data(
+        DataType::Struct(vec![Field::new("id", DataType::Int32, true)]),
+        vec![Arc::new(Int32Array::from_slice([1]))],
+        None,
+    );
+    index.prober(&probe_keys);
+}
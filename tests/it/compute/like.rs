@@ -57,3 +57,111 @@ fn test_nlike_binary_scalar() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_like_utf8_escape() -> Result<()> {
+    let strings = Utf8Array::<i32>::from_slice(&["10%", "100", "a_b", "acb", "a.c", "abc"]);
+    let patterns =
+        Utf8Array::<i32>::from_slice(&["10\\%", "10\\%", "a\\_b", "a\\_b", "a.c", "a.c"]);
+    let result = like_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, true, false, true, false])
+    );
+
+    let result = like_utf8_scalar(&strings, "%\\%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, false, false, false, false])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_multiline() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(&["a\nb"]);
+    let result = like_utf8_scalar(&array, "a_b")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true]));
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8() -> Result<()> {
+    let strings = Utf8Array::<i32>::from(&[Some("Arrow"), Some("ARROW"), None, Some("Parquet")]);
+    let patterns = Utf8Array::<i32>::from(&[Some("arrow"), Some("a%w"), Some("a"), None]);
+    let result = ilike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(true), Some(true), None, None])
+    );
+
+    let result = nilike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(false), Some(false), None, None])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8_scalar() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[Some("Arrow"), Some("arrow"), None, Some("BA")]);
+
+    let result = ilike_utf8_scalar(&array, "ARROW")?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(true), Some(true), None, Some(false)])
+    );
+
+    let result = nilike_utf8_scalar(&array, "%a")?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(true), Some(true), None, Some(false)])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8_scalar_literal() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[
+        Some("Arrow"),
+        Some("ARROWS"),
+        None,
+        Some("Ärrow"),
+        Some("parrow"),
+    ]);
+
+    // equality
+    let result = ilike_utf8_scalar(&array, "aRrOw")?;
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+    let result = ilike_utf8_scalar(&array, "ärROW")?;
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    // a trailing `%`
+    let result = ilike_utf8_scalar(&array, "ARROW%")?;
+    let expected = BooleanArray::from(&[Some(true), Some(true), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    // a leading `%`
+    let result = nilike_utf8_scalar(&array, "%ROW")?;
+    let expected = BooleanArray::from(&[Some(false), Some(true), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    // escapes are not literals: they match like any other pattern
+    let array = Utf8Array::<i32>::from_slice(&["10%", "10%A", "100"]);
+    let result = ilike_utf8_scalar(&array, "10\\%")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+    let result = ilike_utf8_scalar(&array, "10\\%a")?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, true, false]));
+    Ok(())
+}
+
+#[test]
+fn test_can_like() {
+    use arrow2::datatypes::DataType;
+    assert!(can_like(&DataType::Utf8));
+    assert!(can_like(&DataType::LargeBinary));
+    assert!(!can_like(&DataType::Int32));
+}
@@ -10,6 +10,8 @@ mod boolean;
 mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_coalesce")]
+mod coalesce;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
@@ -20,6 +22,8 @@ mod contains;
 mod filter;
 #[cfg(feature = "compute_hash")]
 mod hash;
+#[cfg(feature = "compute_hash_join")]
+mod hash_join;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
 #[cfg(feature = "compute_length")]
@@ -55,3 +55,30 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+#[test]
+fn test_nulls() {
+    let values = Utf8Array::<i32>::from(&[Some("arrow"), None, Some("parquet")]);
+    let result = regex_match_scalar(&values, "ar").unwrap();
+    assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(true)]));
+
+    let regex = Utf8Array::<i32>::from(&[None, Some("ar"), Some("^ar")]);
+    let result = regex_match(&values, &regex).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, None, Some(false)]));
+}
+
+#[test]
+fn test_invalid_regex() {
+    let values = Utf8Array::<i32>::from_slice(["arrow"]);
+    assert!(regex_match_scalar(&values, "(ar").is_err());
+
+    let regex = Utf8Array::<i32>::from_slice(["(ar"]);
+    assert!(regex_match(&values, &regex).is_err());
+}
+
+#[test]
+fn test_different_lengths() {
+    let values = Utf8Array::<i32>::from_slice(["arrow", "parquet"]);
+    let regex = Utf8Array::<i32>::from_slice(["ar"]);
+    assert!(regex_match(&values, &regex).is_err());
+}
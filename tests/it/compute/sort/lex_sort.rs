@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::sort::{lexicographic_min_index, lexsort, SortColumn, SortOptions};
+use arrow2::datatypes::{DataType, Field};
 
 fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
     let sorted = lexsort::<i32>(&input, None).unwrap();
@@ -73,6 +77,94 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 
+fn struct_array(
+    a: Vec<Option<i32>>,
+    b: Vec<Option<&str>>,
+    validity: Option<Bitmap>,
+) -> StructArray {
+    StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]),
+        vec![
+            Arc::new(Int32Array::from(a)),
+            Arc::new(Utf8Array::<i32>::from(b)),
+        ],
+        validity,
+    )
+}
+
+#[test]
+fn lexicographic_min_index_tie_breaking() {
+    let ascending = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let descending = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let array = struct_array(
+        vec![Some(3), Some(1), Some(1), Some(1), Some(2)],
+        vec![Some("a"), Some("c"), Some("b"), Some("c"), Some("a")],
+        None,
+    );
+
+    // the first field ties between 1, 2 and 3: the second breaks it
+    assert_eq!(
+        lexicographic_min_index(&array, &[ascending, ascending]).unwrap(),
+        Some(2)
+    );
+    // a full tie keeps the first row
+    assert_eq!(
+        lexicographic_min_index(&array, &[ascending, descending]).unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        lexicographic_min_index(&array, &[descending, ascending]).unwrap(),
+        Some(0)
+    );
+}
+
+#[test]
+fn lexicographic_min_index_nulls() {
+    let nulls_first = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let nulls_last = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let array = struct_array(
+        vec![Some(1), None, Some(1), Some(0)],
+        vec![Some("b"), Some("a"), None, Some("a")],
+        Some(Bitmap::from([true, true, true, false])),
+    );
+
+    // the last row is skipped, as it is null
+    assert_eq!(
+        lexicographic_min_index(&array, &[nulls_first, nulls_first]).unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        lexicographic_min_index(&array, &[nulls_last, nulls_first]).unwrap(),
+        Some(2)
+    );
+    assert_eq!(
+        lexicographic_min_index(&array, &[nulls_last, nulls_last]).unwrap(),
+        Some(0)
+    );
+
+    let empty = array.slice(0, 0);
+    assert_eq!(
+        lexicographic_min_index(&empty, &[nulls_last, nulls_last]).unwrap(),
+        None
+    );
+    assert!(lexicographic_min_index(&array, &[nulls_last]).is_err());
+}
+
 /*
     // test sort with nulls first
     let input = vec![
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use arrow2::compute::take::{can_take, take};
-use arrow2::datatypes::{DataType, Field, IntervalUnit};
+use arrow2::datatypes::{DataType, Field, IntegerType, IntervalUnit};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
 use arrow2::{bitmap::Bitmap, buffer::Buffer};
@@ -313,3 +313,53 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn dictionary_shares_values() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>;
+    let keys = Int32Array::from(&[Some(2), None, Some(0), Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, values.clone());
+
+    let indices = Int32Array::from(&[Some(3), None, Some(0), Some(1)]);
+    let result = take(&array, &indices)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(result.values(), &values));
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from(&[Some(1), None, Some(2), None])
+    );
+    assert_eq!(result.data_type(), array.data_type());
+    Ok(())
+}
+
+#[test]
+fn dictionary_ordered() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>;
+    let keys = Int32Array::from_slice([0, 1, 1, 2]);
+    let unordered = DictionaryArray::<i32>::from_data(keys, values);
+    let mut ordered = unordered.clone();
+    ordered.set_ordered(true);
+    assert_eq!(
+        ordered.data_type(),
+        &DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), true)
+    );
+
+    // non-decreasing indices, ignoring nulls, keep the ordering
+    let monotonic = Int32Array::from(&[Some(0), Some(1), None, Some(1), Some(3)]);
+    let result = take(&ordered, &monotonic)?;
+    assert_eq!(result.data_type(), ordered.data_type());
+    let result = take(&unordered, &monotonic)?;
+    assert_eq!(result.data_type(), unordered.data_type());
+
+    // any other selection drops it
+    let non_monotonic = Int32Array::from(&[Some(3), Some(0)]);
+    let result = take(&ordered, &non_monotonic)?;
+    assert_eq!(result.data_type(), unordered.data_type());
+    let result = take(&unordered, &non_monotonic)?;
+    assert_eq!(result.data_type(), unordered.data_type());
+    Ok(())
+}
@@ -0,0 +1,1061 @@
+use std::collections::VecDeque;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_format::ipc::planus::{Builder, ReadAsRoot};
+use arrow_format::ipc::{
+    Buffer, BufferRef, DictionaryBatch, DictionaryBatchRef, FieldNodeRef, MetadataVersion,
+    RecordBatch, RecordBatchRef,
+};
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field, IntegerType};
+use arrow2::error::{ArrowError, Result};
+use arrow2::io::ipc::read::{
+    read_array, read_array_consumed, read_dictionary, read_list_rows, read_projected_array,
+    NestedProjection, ReadOptions,
+};
+use arrow2::io::ipc::write::{default_ipc_fields, write};
+use arrow2::io::ipc::{IpcField, IpcSchema};
+
+fn round_trip(array: &dyn Array) -> Result<Box<dyn Array>> {
+    round_trip_projected(array, None)
+}
+
+fn round_trip_projected(
+    array: &dyn Array,
+    projection: Option<&NestedProjection>,
+) -> Result<Box<dyn Array>> {
+    round_trip_corrupted(array, projection, &Default::default(), |_, _| {})
+}
+
+fn round_trip_corrupted<F: FnOnce(&mut Vec<Buffer>, &mut [u8])>(
+    array: &dyn Array,
+    projection: Option<&NestedProjection>,
+    options: &ReadOptions,
+    corrupt: F,
+) -> Result<Box<dyn Array>> {
+    write_and_read(
+        array,
+        projection,
+        options,
+        true,
+        MetadataVersion::V5,
+        corrupt,
+    )
+}
+
+/// Writes `array` with the endianness `is_little_endian` and calls `corrupt` on its buffers
+/// (which it may also remove) and body.
+/// Returns the serialized `RecordBatch` message and body.
+fn write_batch<F: FnOnce(&mut Vec<Buffer>, &mut [u8])>(
+    array: &dyn Array,
+    is_little_endian: bool,
+    corrupt: F,
+) -> (Vec<u8>, Vec<u8>) {
+    let mut buffers = vec![];
+    let mut arrow_data = vec![];
+    let mut nodes = vec![];
+    let mut offset = 0;
+    write(
+        array,
+        &mut buffers,
+        &mut arrow_data,
+        &mut nodes,
+        &mut offset,
+        is_little_endian,
+        None,
+    );
+    corrupt(&mut buffers, &mut arrow_data);
+
+    let batch = RecordBatch {
+        length: array.len() as i64,
+        nodes: Some(nodes),
+        buffers: Some(buffers),
+        compression: None,
+    };
+    let mut builder = Builder::new();
+    let batch = builder.finish(&batch, None).to_vec();
+    (batch, arrow_data)
+}
+
+/// A `RecordBatch` message and body with a single array, and the field to read it with.
+struct WrittenBatch {
+    message: Vec<u8>,
+    body: Vec<u8>,
+    field: Field,
+    ipc_field: IpcField,
+}
+
+impl WrittenBatch {
+    /// Writes `array` as [`write_batch`] does.
+    fn new<F: FnOnce(&mut Vec<Buffer>, &mut [u8])>(
+        array: &dyn Array,
+        is_little_endian: bool,
+        corrupt: F,
+    ) -> Self {
+        let (message, body) = write_batch(array, is_little_endian, corrupt);
+        Self::from_message(array, message, body)
+    }
+
+    /// Returns a [`WrittenBatch`] of the serialized `RecordBatch` `message` and `body` of `array`.
+    fn from_message(array: &dyn Array, message: Vec<u8>, body: Vec<u8>) -> Self {
+        let field = Field::new("a", array.data_type().clone(), true);
+        let ipc_field = default_ipc_fields(std::slice::from_ref(&field))
+            .pop()
+            .unwrap();
+        Self {
+            message,
+            body,
+            field,
+            ipc_field,
+        }
+    }
+
+    /// Returns the field nodes and buffers of the message, to be consumed by the readers of
+    /// arrays.
+    fn nodes_and_buffers(&self) -> Result<(VecDeque<FieldNodeRef<'_>>, VecDeque<BufferRef<'_>>)> {
+        let batch = RecordBatchRef::read_as_root(&self.message)?;
+        let field_nodes = batch.nodes()?.unwrap().iter().collect();
+        let buffers = batch.buffers()?.unwrap().iter().collect();
+        Ok((field_nodes, buffers))
+    }
+}
+
+/// Writes `array` with the endianness `is_little_endian`, calls `corrupt` on its buffers
+/// (which it may also remove) and body and reads it back as written in `version`
+fn write_and_read<F: FnOnce(&mut Vec<Buffer>, &mut [u8])>(
+    array: &dyn Array,
+    projection: Option<&NestedProjection>,
+    options: &ReadOptions,
+    is_little_endian: bool,
+    version: MetadataVersion,
+    corrupt: F,
+) -> Result<Box<dyn Array>> {
+    let batch = WrittenBatch::new(array, is_little_endian, corrupt);
+    let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+    let mut reader = Cursor::new(&batch.body);
+
+    let result = match projection {
+        None => read_array(
+            &mut field_nodes,
+            &batch.field,
+            &batch.ipc_field,
+            &mut buffers,
+            &mut reader,
+            &Default::default(),
+            0,
+            is_little_endian,
+            None,
+            version,
+            options,
+        )?,
+        Some(projection) => read_projected_array(
+            &mut field_nodes,
+            &batch.field,
+            &batch.ipc_field,
+            &mut buffers,
+            &mut reader,
+            &Default::default(),
+            0,
+            is_little_endian,
+            None,
+            version,
+            projection,
+            options,
+        )?,
+    };
+    assert!(field_nodes.is_empty());
+    assert!(buffers.is_empty());
+    Ok(result)
+}
+
+#[test]
+fn list() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+    ];
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+
+    let result = round_trip(&array)?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    Ok(())
+}
+
+fn struct_array() -> StructArray {
+    let c = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let d = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>;
+    let b = StructArray::try_from_fields(vec![
+        (Field::new("c", DataType::Int32, false), c),
+        (Field::new("d", DataType::Utf8, false), d),
+    ])
+    .unwrap();
+
+    let a = Arc::new(Int64Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", b.data_type().clone(), false),
+    ];
+    StructArray::from_data(
+        DataType::Struct(fields),
+        vec![a, Arc::new(b)],
+        Some(Bitmap::from([true, true, false])),
+    )
+}
+
+#[test]
+fn struct_projected() -> Result<()> {
+    let array = struct_array();
+
+    // {a, b: {d}}
+    let projection = NestedProjection::new(vec![
+        (0, None),
+        (1, Some(NestedProjection::new(vec![(1, None)]))),
+    ]);
+    let result = round_trip_projected(&array, Some(&projection))?;
+
+    let b = array.values()[1]
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .project(&["d"])?;
+    let expected = array
+        .without_field("b")?
+        .with_field(Field::new("b", b.data_type().clone(), false), Arc::new(b))?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // {b}
+    let projection = NestedProjection::new(vec![(1, None)]);
+    let result = round_trip_projected(&array, Some(&projection))?;
+    let expected = array.project(&["b"])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let projection = NestedProjection::new(vec![(2, None)]);
+    assert!(round_trip_projected(&array, Some(&projection)).is_err());
+    Ok(())
+}
+
+#[test]
+fn list_of_struct_projected() -> Result<()> {
+    let values = struct_array();
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        vec![0, 2, 2, 3].into(),
+        Arc::new(values.clone()),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let projection = NestedProjection::new(vec![(0, None)]);
+    let result = round_trip_projected(&array, Some(&projection))?;
+
+    let values = values.project(&["a"])?;
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let expected = ListArray::<i32>::from_data(
+        data_type,
+        array.offsets().clone(),
+        Arc::new(values),
+        array.validity().cloned(),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn list_of_struct_of_list() -> Result<()> {
+    // [{a: [int32], b: int32}]
+    let a = nested_list::<i32>().values().clone();
+    let b = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let values = StructArray::try_from_fields(vec![
+        (Field::new("a", a.data_type().clone(), true), a),
+        (Field::new("b", DataType::Int32, false), b),
+    ])?;
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        vec![0, 2, 2, 3].into(),
+        Arc::new(values.clone()),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let result = round_trip(&array)?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    // the skipped lists do not misalign the fields that follow them
+    let projection = NestedProjection::new(vec![(1, None)]);
+    let result = round_trip_projected(&array, Some(&projection))?;
+    let values = values.project(&["b"])?;
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let expected = ListArray::<i32>::from_data(
+        data_type,
+        array.offsets().clone(),
+        Arc::new(values),
+        array.validity().cloned(),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // a schema whose struct declares fewer IPC fields than fields errors
+    let mut batch = WrittenBatch::new(&array, true, |_, _| {});
+    batch.ipc_field.fields[0].fields.pop();
+    let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+    let mut reader = Cursor::new(&batch.body);
+
+    let result = read_array(
+        &mut field_nodes,
+        &batch.field,
+        &batch.ipc_field,
+        &mut buffers,
+        &mut reader,
+        &Default::default(),
+        0,
+        true,
+        None,
+        MetadataVersion::V5,
+        &Default::default(),
+    );
+    assert!(matches!(result, Err(ArrowError::OutOfSpec(_))));
+    Ok(())
+}
+
+#[test]
+fn fixed_size_list_of_struct_projected() -> Result<()> {
+    let values = struct_array();
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), 1);
+    let array = FixedSizeListArray::new(
+        data_type,
+        Arc::new(values.clone()),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let projection = NestedProjection::new(vec![(0, None)]);
+    let result = round_trip_projected(&array, Some(&projection))?;
+
+    let values = values.project(&["a"])?;
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), 1);
+    let expected = FixedSizeListArray::new(data_type, Arc::new(values), array.validity().cloned());
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn map_projected() -> Result<()> {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let entries = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>,
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.data_type().clone(), false)),
+        false,
+    );
+    let array = MapArray::new(data_type, vec![0, 2, 3].into(), Arc::new(entries), None);
+
+    // maps (as any type other than structs and lists) can not be projected
+    let projection = NestedProjection::new(vec![(0, None)]);
+    let result = round_trip_projected(&array, Some(&projection));
+    assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+
+    let array = Int32Array::from_slice([1, 2]);
+    let result = round_trip_projected(&array, Some(&projection));
+    assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+    Ok(())
+}
+
+#[test]
+fn list_skipped_truncated_buffers() -> Result<()> {
+    // {a: [int32], b: int32}, whose buffers are
+    // [validity, a's validity, a's offsets, a's values' validity, a's values, b's validity, b's values]
+    let list = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(DataType::Int32),
+        vec![0, 1, 3].into(),
+        Arc::new(Int32Array::from_slice([1, 2, 3])),
+        None,
+    );
+    let b = Int32Array::from_slice([1, 2]);
+    let array = StructArray::try_from_fields(vec![
+        (
+            Field::new("a", list.data_type().clone(), false),
+            Arc::new(list) as _,
+        ),
+        (Field::new("b", DataType::Int32, false), Arc::new(b) as _),
+    ])?;
+
+    // reads b, skipping a
+    let projection = NestedProjection::new(vec![(1, None)]);
+    let result = round_trip_projected(&array, Some(&projection))?;
+    assert_eq!(result.as_ref(), &array.project(&["b"])? as &dyn Array);
+
+    // a's offsets are missing
+    let result = round_trip_corrupted(
+        &array,
+        Some(&projection),
+        &Default::default(),
+        |buffers, _| buffers.truncate(2),
+    );
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn consumed() -> Result<()> {
+    let array = nested_list::<i32>();
+    let batch = WrittenBatch::new(&array, true, |_, _| {});
+    let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+    let mut reader = Cursor::new(&batch.body);
+    // the end of the last buffer of the array, i.e. the body written for it without its padding
+    let end = buffers
+        .iter()
+        .map(|buffer| buffer.offset() + buffer.length())
+        .max()
+        .unwrap();
+
+    // the position of the reader does not matter
+    reader.set_position(3);
+    let (result, consumed) = read_array_consumed(
+        &mut field_nodes,
+        &batch.field,
+        &batch.ipc_field,
+        &mut buffers,
+        &mut reader,
+        &Default::default(),
+        0,
+        true,
+        None,
+        MetadataVersion::V5,
+        &Default::default(),
+    )?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    assert!(buffers.is_empty());
+    assert_eq!(consumed, end as u64);
+    let padding = batch.body.len() - end as usize;
+    assert!(padding < 8);
+    Ok(())
+}
+
+#[test]
+fn large_list() -> Result<()> {
+    // more offsets than the ones read at a time
+    let length: usize = 200_000;
+    let values = Int32Array::from_vec((0..length as i32 * 2).collect());
+    let offsets = (0..=length as i64).map(|x| x * 2).collect::<Vec<_>>();
+    let data_type = ListArray::<i64>::default_datatype(DataType::Int32);
+    let array = ListArray::<i64>::from_data(data_type, offsets.into(), Arc::new(values), None);
+
+    let result = round_trip(&array)?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    Ok(())
+}
+
+fn list_array() -> ListArray<i64> {
+    let values = Int32Array::from_slice([1, 2, 3, 4]);
+    let data_type = ListArray::<i64>::default_datatype(DataType::Int32);
+    ListArray::<i64>::from_data(
+        data_type,
+        vec![0, 1, 3, 4].into(),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true])),
+    )
+}
+
+#[test]
+fn list_oversized_buffer() -> Result<()> {
+    // the offsets buffer declares more bytes than available
+    let result = round_trip_corrupted(&list_array(), None, &Default::default(), |buffers, _| {
+        buffers[1].length = i64::MAX / 2;
+    });
+    assert!(result.is_err());
+
+    // the validity buffer declares more bytes than available
+    let result = round_trip_corrupted(&list_array(), None, &Default::default(), |buffers, _| {
+        buffers[0].length = i64::MAX / 2;
+    });
+    assert!(result.is_err());
+
+    // the buffers are within the bytes available but exceed the maximum
+    let mut options = ReadOptions {
+        max_buffer_bytes: Some(16),
+        ..Default::default()
+    };
+    let result = round_trip_corrupted(&list_array(), None, &options, |_, _| {});
+    assert!(matches!(result, Err(ArrowError::OutOfSpec(_))));
+
+    options.max_buffer_bytes = Some(1024);
+    let result = round_trip_corrupted(&list_array(), None, &options, |_, _| {})?;
+    assert_eq!(result.as_ref(), &list_array() as &dyn Array);
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc_compression")]
+#[test]
+fn list_compressed_oversized_length() -> Result<()> {
+    use arrow2::io::ipc::write::Compression;
+    use arrow_format::ipc::{BodyCompression, BodyCompressionMethod, CompressionType};
+
+    // without nulls, so that the offsets are the first buffer to decompress
+    let array = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 2].into(),
+        Arc::new(Int32Array::from_slice([1, 2])),
+        None,
+    );
+    let mut buffers = vec![];
+    let mut arrow_data = vec![];
+    let mut nodes = vec![];
+    write(
+        &array,
+        &mut buffers,
+        &mut arrow_data,
+        &mut nodes,
+        &mut 0,
+        true,
+        Some(Compression::ZSTD),
+    );
+    // the list claims far more slots than its (small) compressed offsets decompress to
+    nodes[0].length = i64::MAX / 16;
+
+    let batch = RecordBatch {
+        length: array.len() as i64,
+        nodes: Some(nodes),
+        buffers: Some(buffers),
+        compression: Some(Box::new(BodyCompression {
+            codec: CompressionType::Zstd,
+            method: BodyCompressionMethod::Buffer,
+        })),
+    };
+    let mut builder = Builder::new();
+    let message = builder.finish(&batch, None).to_vec();
+    let batch = WrittenBatch::from_message(&array, message, arrow_data);
+    let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+    let mut reader = Cursor::new(&batch.body);
+    let compression = RecordBatchRef::read_as_root(&batch.message)?.compression()?;
+
+    let options = ReadOptions {
+        max_buffer_bytes: Some(1024),
+        ..Default::default()
+    };
+    let result = read_array(
+        &mut field_nodes,
+        &batch.field,
+        &batch.ipc_field,
+        &mut buffers,
+        &mut reader,
+        &Default::default(),
+        0,
+        true,
+        compression,
+        MetadataVersion::V5,
+        &options,
+    );
+    assert!(matches!(result, Err(ArrowError::OutOfSpec(_))));
+    Ok(())
+}
+
+#[test]
+fn list_non_monotonic_offsets() {
+    let result = round_trip_corrupted(&list_array(), None, &Default::default(), |buffers, data| {
+        // set the second offset to 5 (> 3, the third)
+        let start = buffers[1].offset as usize + 8;
+        data[start..start + 8].copy_from_slice(&5i64.to_le_bytes());
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn list_verify_offsets() -> Result<()> {
+    let options = ReadOptions {
+        verify_offsets: true,
+        ..Default::default()
+    };
+    let array = list_array();
+    let result = round_trip_corrupted(&array, None, &options, |_, _| {})?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    // offsets `[1, 1, 3, 4]`: valid, but do not start at zero
+    let set_offset = |index: usize, value: i64| {
+        move |buffers: &mut Vec<Buffer>, data: &mut [u8]| {
+            let start = buffers[1].offset as usize + index * 8;
+            data[start..start + 8].copy_from_slice(&value.to_le_bytes());
+        }
+    };
+    assert!(round_trip_corrupted(&array, None, &Default::default(), set_offset(0, 1)).is_ok());
+    assert!(round_trip_corrupted(&array, None, &options, set_offset(0, 1)).is_err());
+
+    // offsets `[0, 1, 3, 3]`: valid, but do not end at the length of the values
+    assert!(round_trip_corrupted(&array, None, &Default::default(), set_offset(3, 3)).is_ok());
+    assert!(round_trip_corrupted(&array, None, &options, set_offset(3, 3)).is_err());
+    Ok(())
+}
+
+#[test]
+fn list_alignment() -> Result<()> {
+    // this crate pads the buffers it writes to 8 bytes
+    let options = |alignment| ReadOptions {
+        alignment: Some(alignment),
+        ..Default::default()
+    };
+    let array = nested_list::<i32>();
+    let result = round_trip_corrupted(&array, None, &options(8), |_, _| {})?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    // the validity is 8 bytes long, so that the offsets do not start at a multiple of 64
+    assert!(round_trip_corrupted(&array, None, &Default::default(), |_, _| {}).is_ok());
+    assert!(round_trip_corrupted(&array, None, &options(64), |_, _| {}).is_err());
+
+    // the offsets buffer (of the inner list) declared at an unaligned offset
+    let misalign = |buffers: &mut Vec<Buffer>, _: &mut [u8]| buffers[3].offset += 4;
+    assert!(round_trip_corrupted(&array, None, &options(8), misalign).is_err());
+
+    // the values buffer (read by the reader of the inner values) declared at an unaligned offset
+    let misalign = |buffers: &mut Vec<Buffer>, _: &mut [u8]| buffers[5].offset += 4;
+    let result = round_trip_corrupted(&array, None, &options(8), misalign);
+    assert!(matches!(result, Err(ArrowError::OutOfSpec(_))));
+    Ok(())
+}
+
+fn nested_list<O: Offset>() -> ListArray<O> {
+    let offset = |x: usize| O::from_usize(x).unwrap();
+
+    let values = Int32Array::from([Some(1), Some(2), None, Some(4), Some(5)]);
+    let data_type = ListArray::<O>::default_datatype(DataType::Int32);
+    let inner = ListArray::<O>::from_data(
+        data_type,
+        vec![offset(0), offset(2), offset(2), offset(5)].into(),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let data_type = ListArray::<O>::default_datatype(inner.data_type().clone());
+    ListArray::<O>::from_data(
+        data_type,
+        vec![offset(0), offset(1), offset(1), offset(3)].into(),
+        Arc::new(inner),
+        Some(Bitmap::from([true, false, true])),
+    )
+}
+
+fn list_big_endian<O: Offset>() -> Result<()> {
+    let array = nested_list::<O>();
+
+    let result = write_and_read(
+        &array,
+        None,
+        &Default::default(),
+        false,
+        MetadataVersion::V5,
+        |buffers, data| {
+            // the offsets are written in big endian
+            let size = std::mem::size_of::<O>();
+            let start = buffers[1].offset as usize + 3 * size;
+            assert_eq!(&data[start..start + size], &3u64.to_be_bytes()[8 - size..]);
+        },
+    )?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    let little_endian = round_trip(&array)?;
+    assert_eq!(result, little_endian);
+    Ok(())
+}
+
+#[test]
+fn list_big_endian_i32() -> Result<()> {
+    list_big_endian::<i32>()
+}
+
+#[test]
+fn list_big_endian_i64() -> Result<()> {
+    list_big_endian::<i64>()
+}
+
+/// Writes `array` and reads the `rows` of it back.
+fn read_rows(array: &dyn Array, rows: (usize, usize)) -> Result<Box<dyn Array>> {
+    let batch = WrittenBatch::new(array, true, |_, _| {});
+    let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+    let mut reader = Cursor::new(&batch.body);
+
+    let options = ReadOptions {
+        verify_offsets: true,
+        ..Default::default()
+    };
+    let result = read_list_rows(
+        &mut field_nodes,
+        &batch.field,
+        &batch.ipc_field,
+        &mut buffers,
+        &mut reader,
+        &Default::default(),
+        0,
+        true,
+        None,
+        MetadataVersion::V5,
+        &options,
+        rows,
+    )?;
+    assert!(field_nodes.is_empty());
+    assert!(buffers.is_empty());
+    Ok(result)
+}
+
+#[test]
+fn list_rows() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3), None, Some(5)]),
+        Some(vec![]),
+        Some(vec![Some(6)]),
+        Some(vec![Some(7), Some(8)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+
+    for (skip, take) in [(2, 3), (1, 1), (0, 6), (5, 1), (6, 0)] {
+        let result = read_rows(&array, (skip, take))?;
+        assert_eq!(result.as_ref(), &array.slice(skip, take) as &dyn Array);
+        // the offsets of the window start at zero
+        let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+        assert_eq!(result.offsets()[0], 0);
+        assert_eq!(result.values().len(), result.offsets()[take] as usize);
+    }
+    Ok(())
+}
+
+#[test]
+fn nested_list_rows() -> Result<()> {
+    let array = nested_list::<i32>();
+    for (skip, take) in [(1, 2), (2, 1), (0, 1)] {
+        let result = read_rows(&array, (skip, take))?;
+        assert_eq!(result.as_ref(), &array.slice(skip, take) as &dyn Array);
+    }
+
+    let array = nested_list::<i64>();
+    let result = read_rows(&array, (1, 2))?;
+    assert_eq!(result.as_ref(), &array.slice(1, 2) as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn list_of_utf8_rows() -> Result<()> {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d")]);
+    let array = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Utf8),
+        vec![0, 1, 3, 4].into(),
+        Arc::new(values),
+        None,
+    );
+
+    let result = read_rows(&array, (1, 2))?;
+    assert_eq!(result.as_ref(), &array.slice(1, 2) as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn list_rows_errors() {
+    let array = nested_list::<i32>();
+    assert!(matches!(
+        read_rows(&array, (2, 2)),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let array = Int32Array::from_slice([1, 2]);
+    assert!(matches!(
+        read_rows(&array, (0, 1)),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+}
+
+#[test]
+fn list_missing_offsets() -> Result<()> {
+    let omit_offsets = |buffers: &mut Vec<Buffer>, _: &mut [u8]| buffers[1].length = 0;
+    let array = ListArray::<i32>::new_empty(ListArray::<i32>::default_datatype(DataType::Int32));
+
+    // legacy versions may omit the offsets of empty lists
+    let result = write_and_read(
+        &array,
+        None,
+        &Default::default(),
+        true,
+        MetadataVersion::V4,
+        omit_offsets,
+    )?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    // ... but current versions must report them
+    let result = write_and_read(
+        &array,
+        None,
+        &Default::default(),
+        true,
+        MetadataVersion::V5,
+        omit_offsets,
+    );
+    assert!(result.is_err());
+
+    // ... and non-empty lists must always report them
+    let result = write_and_read(
+        &list_array(),
+        None,
+        &Default::default(),
+        true,
+        MetadataVersion::V4,
+        omit_offsets,
+    );
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc_read_async")]
+async fn read_list_async<O: Offset>(
+    array: &ListArray<O>,
+    corrupt: impl FnOnce(&mut Vec<Buffer>, &mut [u8]),
+) -> Result<ListArray<O>>
+where
+    Vec<u8>: TryInto<O::Bytes>,
+{
+    let batch = WrittenBatch::new(array, true, corrupt);
+    let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+
+    // the body starts after other bytes, e.g. the ones of other messages
+    let block_offset = 7;
+    let mut data = vec![0; block_offset];
+    data.extend(&batch.body);
+
+    let mut reader = futures::io::Cursor::new(data);
+    let result = arrow2::io::ipc::read::read_list_async::<O, _>(
+        &mut field_nodes,
+        array.data_type().clone(),
+        &batch.ipc_field,
+        &mut buffers,
+        &mut reader,
+        &Default::default(),
+        block_offset as u64,
+        true,
+        None,
+        MetadataVersion::V5,
+        None,
+        &Default::default(),
+    )
+    .await?;
+    assert!(field_nodes.is_empty());
+    assert!(buffers.is_empty());
+    Ok(result)
+}
+
+#[cfg(feature = "io_ipc_read_async")]
+#[tokio::test]
+async fn list_async() -> Result<()> {
+    let array = nested_list::<i32>();
+    let result = read_list_async(&array, |_, _| {}).await?;
+    assert_eq!(result, array);
+
+    let array = nested_list::<i64>();
+    let result = read_list_async(&array, |_, _| {}).await?;
+    assert_eq!(result, array);
+
+    // the offsets buffer declares more bytes than available
+    let result = read_list_async(&list_array(), |buffers, _| {
+        buffers[1].length = i64::MAX / 2;
+    })
+    .await;
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[cfg(not(feature = "io_ipc_compression"))]
+#[test]
+fn list_compression_disabled() -> Result<()> {
+    use arrow_format::ipc::{
+        BodyCompression, BodyCompressionMethod, BodyCompressionRef, CompressionType,
+    };
+
+    let mut builder = Builder::new();
+    let compression = builder
+        .finish(
+            &BodyCompression {
+                codec: CompressionType::Zstd,
+                method: BodyCompressionMethod::Buffer,
+            },
+            None,
+        )
+        .to_vec();
+    let compression = BodyCompressionRef::read_as_root(&compression)?;
+
+    let read = |array: &dyn Array| {
+        let batch = WrittenBatch::new(array, true, |_, _| {});
+        let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+        let mut reader = Cursor::new(&batch.body);
+        read_array(
+            &mut field_nodes,
+            &batch.field,
+            &batch.ipc_field,
+            &mut buffers,
+            &mut reader,
+            &Default::default(),
+            0,
+            true,
+            Some(compression),
+            MetadataVersion::V5,
+            &Default::default(),
+        )
+    };
+
+    // the list has nulls: its validity is the first buffer to decompress
+    match read(&list_array()) {
+        Err(ArrowError::NotYetImplemented(msg)) => assert_eq!(
+            msg,
+            "IPC validity buffer uses ZSTD compression but the 'io_ipc_compression' feature is disabled"
+        ),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    // without nulls, it is its offsets
+    let array = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 2].into(),
+        Arc::new(Int32Array::from_slice([1, 2])),
+        None,
+    );
+    match read(&array) {
+        Err(ArrowError::NotYetImplemented(msg)) => assert_eq!(
+            msg,
+            "IPC offsets buffer uses ZSTD compression but the 'io_ipc_compression' feature is disabled"
+        ),
+        other => panic!("unexpected result {:?}", other),
+    }
+    Ok(())
+}
+
+/// Serializes `values` as a dictionary batch of `id`.
+/// Returns the serialized `DictionaryBatch` message and body.
+fn write_dictionary_batch(id: i64, values: &dyn Array, is_delta: bool) -> (Vec<u8>, Vec<u8>) {
+    let mut buffers = vec![];
+    let mut arrow_data = vec![];
+    let mut nodes = vec![];
+    let mut offset = 0;
+    write(
+        values,
+        &mut buffers,
+        &mut arrow_data,
+        &mut nodes,
+        &mut offset,
+        true,
+        None,
+    );
+
+    let batch = DictionaryBatch {
+        id,
+        data: Some(Box::new(RecordBatch {
+            length: values.len() as i64,
+            nodes: Some(nodes),
+            buffers: Some(buffers),
+            compression: None,
+        })),
+        is_delta,
+    };
+    let mut builder = Builder::new();
+    let batch = builder.finish(&batch, None).to_vec();
+    (batch, arrow_data)
+}
+
+#[test]
+fn list_of_dictionary_delta() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    let keys = Int32Array::from_slice([0, 2, 1, 2]);
+    let dictionary = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    let data_type = ListArray::<i32>::default_datatype(dictionary.data_type().clone());
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        vec![0, 1, 1, 4].into(),
+        Arc::new(dictionary),
+        None,
+    );
+
+    let field = Field::new("a", array.data_type().clone(), true);
+    let ipc_schema = IpcSchema {
+        fields: default_ipc_fields(std::slice::from_ref(&field)),
+        is_little_endian: true,
+    };
+    let id = ipc_schema.fields[0].fields[0].dictionary_id.unwrap();
+
+    // the second message extends the dictionary of the first one
+    let mut dictionaries = Default::default();
+    let messages = [
+        (Utf8Array::<i32>::from_slice(["a", "b"]), false),
+        (Utf8Array::<i32>::from_slice(["c"]), true),
+    ];
+    for (values, is_delta) in messages {
+        let (batch, arrow_data) = write_dictionary_batch(id, &values, is_delta);
+        read_dictionary(
+            DictionaryBatchRef::read_as_root(&batch)?,
+            std::slice::from_ref(&field),
+            &ipc_schema,
+            &mut dictionaries,
+            &mut Cursor::new(arrow_data),
+            0,
+        )?;
+    }
+    assert_eq!(
+        dictionaries[&id].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+
+    // keys of the record batch refer to values of both messages
+    let batch = WrittenBatch::new(&array, true, |_, _| {});
+    let (mut field_nodes, mut buffers) = batch.nodes_and_buffers()?;
+    let mut reader = Cursor::new(&batch.body);
+    let result = read_array(
+        &mut field_nodes,
+        &field,
+        &ipc_schema.fields[0],
+        &mut buffers,
+        &mut reader,
+        &dictionaries,
+        0,
+        true,
+        None,
+        MetadataVersion::V5,
+        &Default::default(),
+    )?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn dictionary_delta_without_dictionary() -> Result<()> {
+    let field = Field::new(
+        "a",
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        true,
+    );
+    let ipc_schema = IpcSchema {
+        fields: default_ipc_fields(std::slice::from_ref(&field)),
+        is_little_endian: true,
+    };
+    let id = ipc_schema.fields[0].dictionary_id.unwrap();
+
+    let values = Utf8Array::<i32>::from_slice(["c"]);
+    let (batch, arrow_data) = write_dictionary_batch(id, &values, true);
+    let result = read_dictionary(
+        DictionaryBatchRef::read_as_root(&batch)?,
+        std::slice::from_ref(&field),
+        &ipc_schema,
+        &mut Default::default(),
+        &mut Cursor::new(arrow_data),
+        0,
+    );
+    assert!(result.is_err());
+    Ok(())
+}
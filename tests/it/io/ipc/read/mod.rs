@@ -1,2 +1,3 @@
+mod array;
 mod file;
 mod stream;
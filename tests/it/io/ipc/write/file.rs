@@ -3,9 +3,9 @@ use std::sync::Arc;
 
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
-use arrow2::error::Result;
-use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::{ArrowError, Result};
+use arrow2::io::ipc::read::{read_file_metadata, FileReader, NestedProjection};
 use arrow2::io::ipc::{write::*, IpcField};
 
 use crate::io::ipc::common::read_gzip_json;
@@ -365,3 +365,136 @@ fn write_sliced_list() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+fn compressed_lists() -> Result<(Chunk<Arc<dyn Array>>, Schema)> {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let list: Arc<dyn Array> = array.into_arc();
+
+    // a list without nulls (no validity) of lists
+    let data = vec![
+        Some(vec![Some(vec![Some(1i32)]), None]),
+        Some(vec![]),
+        Some(vec![Some(vec![]), Some(vec![Some(2), None])]),
+    ];
+    let mut array =
+        MutableListArray::<i64, MutableListArray<i32, MutablePrimitiveArray<i32>>>::new();
+    array.try_extend(data).unwrap();
+    let nested: Arc<dyn Array> = array.into_arc();
+
+    let utf8 = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>;
+
+    let schema = Schema::from(vec![
+        Field::new("a", list.data_type().clone(), true),
+        Field::new("b", nested.data_type().clone(), true),
+        Field::new("c", utf8.data_type().clone(), true),
+    ]);
+    Ok((Chunk::try_new(vec![list, nested, utf8])?, schema))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_compressed_list() -> Result<()> {
+    let (columns, schema) = compressed_lists()?;
+    round_trip(
+        columns.clone(),
+        schema.clone(),
+        None,
+        Some(Compression::LZ4),
+    )?;
+    round_trip(columns, schema, None, Some(Compression::ZSTD))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_compressed_list_projected() -> Result<()> {
+    let (columns, schema) = compressed_lists()?;
+    let expected = columns.columns()[2].clone();
+
+    for compression in [Compression::LZ4, Compression::ZSTD] {
+        let result = write(
+            std::slice::from_ref(&columns),
+            &schema,
+            None,
+            Some(compression),
+        )?;
+        let mut reader = Cursor::new(result);
+        let metadata = read_file_metadata(&mut reader)?;
+        // the lists are skipped
+        let reader = FileReader::new(reader, metadata, Some(vec![2]));
+
+        let batches = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].columns()[0], expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn write_nested_projected() -> Result<()> {
+    // a: int32, b: {c: int32, d: utf8}, e: [{c: int32, d: utf8}; 1]
+    let a = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let b = StructArray::try_from_fields(vec![
+        (
+            Field::new("c", DataType::Int32, false),
+            Arc::new(Int32Array::from_slice([3, 4])) as Arc<dyn Array>,
+        ),
+        (
+            Field::new("d", DataType::Utf8, false),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+        ),
+    ])?;
+    let e = FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(b.data_type().clone(), 1),
+        Arc::new(b.clone()),
+        None,
+    );
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), false),
+        Field::new("b", b.data_type().clone(), false),
+        Field::new("e", e.data_type().clone(), false),
+    ]);
+    let columns = Chunk::try_new(vec![a, Arc::new(b.clone()), Arc::new(e)])?;
+    let result = write(&[columns], &schema, None, None)?;
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_file_metadata(&mut reader)?;
+    // {e: [{c}; 1], b: {d}}, read in the order of the columns
+    let projection = NestedProjection::new(vec![
+        (2, Some(NestedProjection::new(vec![(0, None)]))),
+        (1, Some(NestedProjection::new(vec![(1, None)]))),
+    ]);
+    let reader = FileReader::try_new_nested(reader, metadata.clone(), projection)?;
+
+    let d = b.project(&["d"])?;
+    let c = b.project(&["c"])?;
+    let e = FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(c.data_type().clone(), 1),
+        Arc::new(c),
+        None,
+    );
+    let expected_schema = Schema::from(vec![
+        Field::new("b", d.data_type().clone(), false),
+        Field::new("e", e.data_type().clone(), false),
+    ]);
+    assert_eq!(reader.schema(), &expected_schema);
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let expected = Chunk::try_new(vec![Arc::new(d) as Arc<dyn Array>, Arc::new(e)])?;
+    assert_eq!(batches, vec![expected]);
+
+    // the column `a` can not be projected, and there is no column `3`
+    for projection in [
+        NestedProjection::new(vec![(0, Some(NestedProjection::new(vec![(0, None)])))]),
+        NestedProjection::new(vec![(3, None)]),
+    ] {
+        let result = FileReader::try_new_nested(Cursor::new(vec![]), metadata.clone(), projection);
+        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+    }
+    Ok(())
+}